@@ -15,5 +15,15 @@ fn main() {
         wl_tablet: { all(feature = "wayland-tablet-unstable-v2", any(docsrs, all(unix, not(any(target_os = "redox", target_family = "wasm", target_os = "android", target_os = "ios", target_os = "macos"))))) },
         // Ink RealTimeStylus is requested and available
         ink_rts: { all(feature = "windows-ink", any(docsrs, target_os = "windows")) },
+        // User-provided backends are requested. Available everywhere!
+        custom_backend: { feature = "custom-backend" },
+        // The mock backend for testing is requested. Built atop custom backends, so also available everywhere!
+        mock_backend: { feature = "mock" },
+        // Recording and replay are requested. Replay is a custom backend, so available everywhere too!
+        record_replay: { feature = "replay" },
+        // `winit` helpers are requested. Available wherever winit is!
+        winit_helpers: { feature = "winit" },
+        // `egui` helpers are requested. Available wherever egui is!
+        egui_helpers: { feature = "egui" },
     }
 }
@@ -47,24 +47,27 @@ impl EventFilter {
         use octotablet::events::{Event, PadEvent, PadGroupEvent, ToolEvent, TouchStripEvent};
         match event {
             Event::Tool { event, .. } => match event {
-                ToolEvent::Pose(..) => self.poses,
+                ToolEvent::Pose(..)
+                | ToolEvent::Tracking(..)
+                | ToolEvent::Extras(..)
+                | ToolEvent::RawPose(..) => self.poses,
                 ToolEvent::Frame(..) => self.frames,
                 _ => true,
             },
-            Event::Tablet { .. } => true,
-            Event::Pad { event, .. } => match event {
-                PadEvent::Group { event, .. } => match event {
-                    PadGroupEvent::Ring { event, .. } | PadGroupEvent::Strip { event, .. } => {
-                        match event {
-                            TouchStripEvent::Frame(..) => self.frames,
-                            TouchStripEvent::Pose(..) => self.poses,
-                            _ => true,
-                        }
-                    }
-                    _ => true,
-                },
+            Event::Pad {
+                event:
+                    PadEvent::Group {
+                        event:
+                            PadGroupEvent::Ring { event, .. } | PadGroupEvent::Strip { event, .. },
+                        ..
+                    },
+                ..
+            } => match event {
+                TouchStripEvent::Frame(..) => self.frames,
+                TouchStripEvent::Pose(..) => self.poses,
                 _ => true,
             },
+            _ => true,
         }
     }
 }
@@ -134,7 +137,7 @@ impl eframe::App for Viewer {
         };
         let events = manager.pump().unwrap();
         // If at least one event...
-        let has_events = events.into_iter().next().is_some();
+        let has_events = !events.is_empty();
         // Poll more often for a while!
         let poll = if has_events {
             // Set up to poll for an additional 500ms afterwards, to catch future events.
@@ -263,6 +266,10 @@ impl eframe::App for Viewer {
                                             "Mode count: {:?}",
                                             group.mode_count.map(std::num::NonZeroU32::get)
                                         ));
+                                        ui.label(format!(
+                                            "Mode indicators: {:?}",
+                                            group.mode_indicators
+                                        ));
                                         ui.label(format!(
                                             "Associated button indices: {:?}",
                                             &group.buttons
@@ -291,6 +298,18 @@ impl eframe::App for Viewer {
                                                 ui.label(format!("{strip:#?}"));
                                             }
                                         });
+                                        // Show dials
+                                        egui::CollapsingHeader::new(format!(
+                                            "Dials ({})",
+                                            group.dials.len()
+                                        ))
+                                        .default_open(true)
+                                        .enabled(!group.dials.is_empty())
+                                        .show(ui, |ui| {
+                                            for dial in &group.dials {
+                                                ui.label(format!("{dial:#?}"));
+                                            }
+                                        });
                                     });
                             }
                         });
@@ -307,6 +326,9 @@ impl eframe::App for Viewer {
                             .id_source((tool.hardware_id, tool.wacom_id, idx))
                             .show(ui, |ui| {
                                 ui.label(format!("Wacom ID: {:08X?}", tool.wacom_id,));
+                                if let Some(paired) = manager.paired_tool(&tool.id()) {
+                                    ui.label(format!("Paired with {}", pretty::name_tool(paired)));
+                                }
                                 ui.label(format!(" ✅ Position: {:?}", tool.axes.position));
                                 for axis in
                                     <octotablet::axis::Axis as strum::IntoEnumIterator>::iter()
@@ -340,6 +362,16 @@ impl eframe::App for Viewer {
                                         );
                                     }
                                 }
+                                if let Some(count) = tool.button_count {
+                                    ui.label(format!("{count} buttons"));
+                                }
+                                for button in &tool.buttons {
+                                    ui.label(format!(
+                                        "Button {:08X?}: {}",
+                                        button.id,
+                                        button.name.as_deref().unwrap_or("Unnamed")
+                                    ));
+                                }
                             });
                     }
                     if manager.tools().is_empty() {
@@ -2,7 +2,10 @@
 //!
 use std::fmt::Debug;
 
-use octotablet::events::{Event, PadEvent, PadGroupEvent, TabletEvent, ToolEvent, TouchStripEvent};
+use octotablet::{
+    events::{DialEvent, Event, PadEvent, PadGroupEvent, TabletEvent, ToolEvent, TouchStripEvent},
+    touch::TouchEvent,
+};
 
 pub mod colors {
     use eframe::egui::Color32;
@@ -20,6 +23,9 @@ pub mod colors {
     pub const TIME: Color32 = Color32::GRAY;
 
     pub const MODE: Color32 = Color32::LIGHT_BLUE;
+
+    /// Events this viewer doesn't know how to pretty-print.
+    pub const OTHER: Color32 = Color32::WHITE;
 }
 /// Wrapper to make "Some(<formattable>)" and "None" look nicer.
 /// Displays instead as the value, otherwise "Unknown" followed by a noun.
@@ -135,6 +141,7 @@ fn format_touch_event(
         ),
         TouchStripEvent::Source(src) => (format!("{name} interacted by {src:?}"), colors::SOURCE),
         TouchStripEvent::Up => (format!("{name} up"), colors::RELEASED),
+        other => (format!("{name} {other:?}"), colors::OTHER),
     }
 }
 
@@ -147,6 +154,7 @@ pub fn format_event(event: Event) -> (String, eframe::egui::Color32) {
             match event {
                 TabletEvent::Added => (format!("{} added", name), colors::ADDED),
                 TabletEvent::Removed => (format!("{} removed", name), colors::REMOVED),
+                other => (format!("{name} {other:?}"), colors::OTHER),
             }
         }
         Event::Pad { pad, event } => {
@@ -198,23 +206,59 @@ pub fn format_event(event: Event) -> (String, eframe::egui::Color32) {
                                 format!("{:.01}%", pose * 100.0)
                             })
                         }
+                        PadGroupEvent::Dial {
+                            dial,
+                            event: DialEvent::Delta(delta),
+                        } => (
+                            format!(
+                                "Dial {:08X?} turned {:.02}",
+                                dial.id(),
+                                delta as f32 / 120.0
+                            ),
+                            colors::POSE,
+                        ),
+                        other => (format!("{name} {other:?}"), colors::OTHER),
                     }
                 }
+                other => (format!("{name} {other:?}"), colors::OTHER),
             }
         }
+        Event::EnumerationComplete => ("Enumeration complete".to_owned(), colors::ADDED),
+        Event::WindowFocus { focused: true } => ("Window focused".to_owned(), colors::ENTER),
+        Event::WindowFocus { focused: false } => ("Window unfocused".to_owned(), colors::EXIT),
+        Event::PointerLock { locked: true } => ("Pointer locked".to_owned(), colors::EXIT),
+        Event::PointerLock { locked: false } => ("Pointer unlocked".to_owned(), colors::ENTER),
+        Event::System(event) => (format!("System {event:?}"), colors::OTHER),
+        Event::Touch(event) => match event {
+            TouchEvent::Down(finger) => (
+                format!("Finger {:?} down at {:?}", finger.contact, finger.position),
+                colors::PRESSED,
+            ),
+            TouchEvent::Move(finger) => (
+                format!("Finger {:?} moved to {:?}", finger.contact, finger.position),
+                colors::POSE,
+            ),
+            TouchEvent::Up(contact) => (format!("Finger {contact:?} up"), colors::RELEASED),
+            TouchEvent::Cancel => ("Touch cancelled".to_owned(), colors::REMOVED),
+            other => (format!("Touch {other:?}"), colors::TIME),
+        },
         Event::Tool { tool, event } => {
             let name = name_tool(tool);
             match event {
                 ToolEvent::Added => (format!("{name} added"), colors::ADDED),
                 ToolEvent::Removed => (format!("{name} removed"), colors::REMOVED),
-                ToolEvent::In { tablet } => {
+                ToolEvent::In { tablet, .. } => {
                     let tablet = name_tablet(tablet);
                     (format!("{name} in over {tablet}"), colors::ENTER)
                 }
                 ToolEvent::Out => (format!("{name} out"), colors::EXIT),
+                ToolEvent::TimedOut => (format!("{name} timed out"), colors::EXIT),
                 ToolEvent::Down => (format!("{name} down"), colors::PRESSED),
                 ToolEvent::Up => (format!("{name} up"), colors::RELEASED),
                 ToolEvent::Pose(pose) => (format!("{name} {pose:#?}"), colors::POSE),
+                ToolEvent::Tracking(pose) => (format!("{name} tracking {pose:#?}"), colors::POSE),
+                ToolEvent::Extras(extras) => (format!("{name} {extras:#?}"), colors::POSE),
+                ToolEvent::RawPose(raw) => (format!("{name} {raw:#?}"), colors::POSE),
                 ToolEvent::Button { button_id, pressed } => (
                     format!(
                         "{name} button {button_id:08X?} {}",
@@ -234,7 +278,9 @@ pub fn format_event(event: Event) -> (String, eframe::egui::Color32) {
                     },
                     colors::TIME,
                 ),
+                other => (format!("{name} {other:?}"), colors::OTHER),
             }
         }
+        other => (format!("{other:?}"), colors::OTHER),
     }
 }
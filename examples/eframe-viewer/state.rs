@@ -4,7 +4,7 @@
 use eframe::egui::{self, Align2, Color32, FontId, Pos2, Shape, Stroke, Vec2};
 use octotablet::{
     axis::{AvailableAxes, Pose},
-    events::{Event, PadEvent, PadGroupEvent, TabletEvent, ToolEvent, TouchStripEvent},
+    events::{DialEvent, Event, PadEvent, PadGroupEvent, TabletEvent, ToolEvent, TouchStripEvent},
     pad, tablet, tool,
 };
 use std::collections;
@@ -56,7 +56,7 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
         for event in iter {
             match event {
                 Event::Tool { tool, event } => match event {
-                    ToolEvent::In { tablet } => {
+                    ToolEvent::In { tablet, .. } => {
                         // Insert if not found. Then set tablet ID.
                         self.tools
                             .entry(tool.id())
@@ -97,7 +97,7 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
                     ToolEvent::Removed | ToolEvent::Out => {
                         self.tools.remove(&tool.id());
                     }
-                    ToolEvent::Added | ToolEvent::Button { .. } | ToolEvent::Frame(..) => (),
+                    _ => (),
                 },
                 Event::Pad { pad, event } => match event {
                     PadEvent::Group { event, .. } => match event {
@@ -121,7 +121,7 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
                                     v.insert(p);
                                 }
                             },
-                            TouchStripEvent::Source(_) | TouchStripEvent::Frame(_) => (),
+                            _ => (),
                         },
                         // Very similar logic to Ring, but the deltas must be interpreted differently.
                         PadGroupEvent::Strip { strip, event } => match event {
@@ -142,9 +142,17 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
                                     v.insert(p);
                                 }
                             },
-                            TouchStripEvent::Source(_) | TouchStripEvent::Frame(_) => (),
+                            _ => (),
                         },
-                        PadGroupEvent::Mode(_) => (),
+                        // Already relative, no state to keep. A detent turns the knob a notch.
+                        PadGroupEvent::Dial {
+                            event: DialEvent::Delta(delta),
+                            ..
+                        } => {
+                            self.knob_pos += delta as f32 / 120.0 * std::f32::consts::TAU / 24.0;
+                            self.knob_pos = self.knob_pos.rem_euclid(std::f32::consts::TAU);
+                        }
+                        _ => (),
                     },
                     PadEvent::Exit | PadEvent::Removed => {
                         // Remove all relevant state
@@ -157,13 +165,15 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
                             }
                         }
                     }
-                    PadEvent::Added | PadEvent::Enter { .. } | PadEvent::Button { .. } => (),
+                    _ => (),
                 },
                 Event::Tablet { tablet, event } => match event {
                     TabletEvent::Added => (),
                     // On removal, delete all states referencing it.
                     TabletEvent::Removed => self.tools.retain(|_, state| state.over != tablet.id()),
+                    _ => (),
                 },
+                _ => (),
             }
         }
     }
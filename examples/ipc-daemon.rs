@@ -0,0 +1,150 @@
+//! Republishes devices and events over a UNIX socket, as a starting point for system-wide tools (pad-mapping
+//! daemons and the like) built on this crate.
+//!
+//! The protocol is one line of text per message, for easy poking at with e.g. `socat - UNIX-CONNECT:<path>`:
+//! * `tablet <id> <name>`, `tool <id> <type> <hardware id>`, and `pad <id> <buttons>` for every device, followed
+//!   by `devices-end`. Sent upon connecting, and again whenever the set of devices changes.
+//! * `event <event>` for every event, in the order they happen.
+//!
+//! Values are formatted with `Debug`, which is fine for poking at but isn't stable - a real daemon would pick a
+//! proper encoding. Note that the platform only reports tablet activity to focused windows, so this still needs a
+//! window of its own to receive anything at all.
+#[cfg(unix)]
+use std::{io::Write, os::unix::net};
+
+/// Where to listen, unless given as the first argument.
+#[cfg(unix)]
+fn default_path() -> std::path::PathBuf {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map_or_else(std::env::temp_dir, std::path::PathBuf::from)
+        .join("octotablet.sock")
+}
+
+/// Describe every device, as sent upon connecting and whenever the devices change.
+#[cfg(unix)]
+fn devices(manager: &octotablet::Manager) -> String {
+    use std::fmt::Write;
+    let mut out = String::new();
+    for tablet in manager.tablets() {
+        let _ = writeln!(out, "tablet {:?} {:?}", tablet.id(), tablet.name);
+    }
+    for tool in manager.tools() {
+        let _ = writeln!(
+            out,
+            "tool {:?} {:?} {:?}",
+            tool.id(),
+            tool.tool_type,
+            tool.hardware_id
+        );
+    }
+    for pad in manager.pads() {
+        let _ = writeln!(out, "pad {:?} {}", pad.id(), pad.total_buttons);
+    }
+    out.push_str("devices-end\n");
+    out
+}
+
+/// Send to every client, forgetting those that have gone away.
+#[cfg(unix)]
+fn broadcast(clients: &mut Vec<net::UnixStream>, message: &str) {
+    clients.retain_mut(|client| client.write_all(message.as_bytes()).is_ok());
+}
+
+#[cfg(unix)]
+fn main() {
+    let path = std::env::args_os()
+        .nth(1)
+        .map_or_else(default_path, std::path::PathBuf::from);
+    // Left behind by a previous run, most likely.
+    let _ = std::fs::remove_file(&path);
+    let listener = net::UnixListener::bind(&path).expect("bind socket");
+    listener
+        .set_nonblocking(true)
+        .expect("make socket non-blocking");
+    println!("listening on {}", path.display());
+
+    let event_loop = winit::event_loop::EventLoopBuilder::<()>::default()
+        .build()
+        .expect("start event loop");
+    let window = std::sync::Arc::new(
+        winit::window::WindowBuilder::default()
+            .with_inner_size(winit::dpi::PhysicalSize::new(256u32, 256u32))
+            .with_title("octotablet ipc daemon")
+            .build(&event_loop)
+            .expect("create window"),
+    );
+    // Wayland windows only show up, and so only get focus, once something is drawn to them.
+    let softbuffer = softbuffer::Context::new(window.as_ref()).expect("init softbuffer");
+    let mut surface =
+        softbuffer::Surface::new(&softbuffer, &window).expect("make presentation surface");
+
+    let mut manager = octotablet::Builder::default()
+        .build_shared(&window)
+        .expect("connect to stylus server");
+
+    let mut clients = Vec::<net::UnixStream>::new();
+    let mut epoch = manager.devices_epoch();
+
+    event_loop
+        .run(|e, target| {
+            use winit::event::{Event, WindowEvent};
+            // Nothing wakes winit up for tablet events or new clients, so poll.
+            target.set_control_flow(winit::event_loop::ControlFlow::wait_duration(
+                std::time::Duration::from_millis(10),
+            ));
+            match e {
+                Event::WindowEvent {
+                    event: WindowEvent::CloseRequested,
+                    ..
+                } => target.exit(),
+                Event::WindowEvent {
+                    event: WindowEvent::RedrawRequested,
+                    ..
+                } => {
+                    let size = window.inner_size();
+                    if let (Some(width), Some(height)) = (
+                        std::num::NonZeroU32::new(size.width),
+                        std::num::NonZeroU32::new(size.height),
+                    ) {
+                        surface.resize(width, height).expect("resize surface");
+                        let mut buffer = surface.buffer_mut().expect("fetch draw buffer");
+                        buffer.fill(0x0020_2020);
+                        window.pre_present_notify();
+                        buffer.present().expect("present");
+                    }
+                }
+                Event::AboutToWait => {
+                    let events = manager.pump().expect("octotablet event pump");
+                    let mut message = String::new();
+                    for event in events {
+                        message.push_str(&format!("event {:?}\n", event.to_owned()));
+                    }
+                    // After the events, so clients can tell which devices they referred to.
+                    if manager.devices_epoch() != epoch {
+                        epoch = manager.devices_epoch();
+                        message.push_str(&devices(&manager));
+                    }
+                    if !message.is_empty() {
+                        broadcast(&mut clients, &message);
+                    }
+                    // Newcomers start off knowing what's around.
+                    while let Ok((mut client, _)) = listener.accept() {
+                        // Better to lose a client that can't keep up than to stall everyone else.
+                        if client.set_nonblocking(true).is_ok()
+                            && client.write_all(devices(&manager).as_bytes()).is_ok()
+                        {
+                            clients.push(client);
+                        }
+                    }
+                }
+                _ => (),
+            }
+        })
+        .expect("winit event loop");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(not(unix))]
+fn main() {
+    eprintln!("this example uses UNIX sockets, which this platform lacks");
+}
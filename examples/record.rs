@@ -0,0 +1,83 @@
+//! Records a session with whatever tablet is at hand, for bug reports and for replaying later with
+//! [`octotablet::replay::Player`]. Draw, press buttons, unplug things - whatever it is that misbehaves - then close the
+//! window to save.
+//!
+//! The recording is written to the first argument, or `session.octorec` otherwise. Note that the platform only
+//! reports tablet activity to focused windows, so draw over the window!
+
+use octotablet::replay::Recorder;
+
+fn main() {
+    let path = std::env::args_os()
+        .nth(1)
+        .map_or_else(|| "session.octorec".into(), std::path::PathBuf::from);
+
+    let event_loop = winit::event_loop::EventLoopBuilder::<()>::default()
+        .build()
+        .expect("start event loop");
+    let window = std::sync::Arc::new(
+        winit::window::WindowBuilder::default()
+            .with_inner_size(winit::dpi::PhysicalSize::new(512u32, 512u32))
+            .with_title("octotablet recorder - close to save")
+            .build(&event_loop)
+            .expect("create window"),
+    );
+    // Wayland windows only show up, and so only get focus, once something is drawn to them.
+    let softbuffer = softbuffer::Context::new(window.as_ref()).expect("init softbuffer");
+    let mut surface =
+        softbuffer::Surface::new(&softbuffer, &window).expect("make presentation surface");
+
+    // Defaults all around, so the recording is of what the hardware does and not of our processing.
+    let mut manager = octotablet::Builder::default()
+        .build_shared(&window)
+        .expect("connect to stylus server");
+    let mut recorder = Recorder::new();
+
+    event_loop
+        .run(|e, target| {
+            use winit::event::{Event, WindowEvent};
+            // Nothing wakes winit up for tablet events, so poll.
+            target.set_control_flow(winit::event_loop::ControlFlow::wait_duration(
+                std::time::Duration::from_millis(5),
+            ));
+            match e {
+                Event::WindowEvent {
+                    event: WindowEvent::CloseRequested,
+                    ..
+                } => target.exit(),
+                Event::WindowEvent {
+                    event: WindowEvent::RedrawRequested,
+                    ..
+                } => {
+                    let size = window.inner_size();
+                    if let (Some(width), Some(height)) = (
+                        std::num::NonZeroU32::new(size.width),
+                        std::num::NonZeroU32::new(size.height),
+                    ) {
+                        surface.resize(width, height).expect("resize surface");
+                        let mut buffer = surface.buffer_mut().expect("fetch draw buffer");
+                        buffer.fill(0x0020_2020);
+                        window.pre_present_notify();
+                        buffer.present().expect("present");
+                    }
+                }
+                Event::AboutToWait => {
+                    manager.pump().expect("octotablet event pump");
+                    recorder.record(&manager);
+                }
+                _ => (),
+            }
+        })
+        .expect("winit event loop");
+
+    let recording = recorder.finish();
+    let file = std::fs::File::create(&path).expect("create recording file");
+    recording
+        .write_to(std::io::BufWriter::new(file))
+        .expect("write recording");
+    println!(
+        "saved {:?} of tablet activity to {}",
+        recording.duration(),
+        path.display()
+    );
+}
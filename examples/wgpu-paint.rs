@@ -0,0 +1,580 @@
+//! A more complete paint app, using `wgpu` for drawing.
+//!
+//! Shows the intended pipeline for a latency-sensitive drawing app end-to-end:
+//! * Pumping with a time budget, so that an event flood can't blow a frame deadline.
+//! * Grouping axis events into whole frames, only acting once a frame is complete.
+//! * Stabilizing the stroke with [`Builder::smoothing`], to smooth out hand jitter.
+//! * Predicting where the stroke is headed with a [`Predictor`], to hide some of the latency introduced by the above.
+//! * Reshaping pressure with [`Builder::pressure_curve`], after auto-ranging it to the hardware's actual range.
+//! * Pad buttons as shortcuts - the first button clears the canvas and the rest cycle through colors.
+
+use octotablet::{
+    axis::{Filter, Pose, SmoothingConfig},
+    builder::Builder,
+    mapping::PressureCurve,
+    predict::Predictor,
+};
+use winit::dpi::PhysicalSize;
+
+/// Radius of the brush at full pressure, in logical pixels.
+const BRUSH_RADIUS: f32 = 8.0;
+/// Response of the brush to pressure. Less than one makes light strokes heavier.
+const PRESSURE_GAMMA: f32 = 0.8;
+/// How long the stabilized position takes to catch up with the pen. Longer is smoother, at the expense of lag.
+const STABILIZATION: std::time::Duration = std::time::Duration::from_millis(8);
+/// How far ahead in time to predict the stroke.
+const PREDICTION: std::time::Duration = std::time::Duration::from_millis(12);
+/// Predictions are wildly wrong at sudden direction changes, so keep them short.
+const MAX_PREDICTION_PX: f32 = 40.0;
+/// Time budget for ingesting events each loop.
+const PUMP_BUDGET: std::time::Duration = std::time::Duration::from_millis(2);
+
+const BACKGROUND: [f32; 4] = [0.05, 0.05, 0.07, 1.0];
+const PALETTE: [[f32; 4]; 4] = [
+    [1.0, 1.0, 1.0, 1.0],
+    [1.0, 0.35, 0.3, 1.0],
+    [0.3, 0.8, 0.45, 1.0],
+    [0.35, 0.55, 1.0, 1.0],
+];
+
+const SHADER: &str = r"
+// xy is the size of the viewport in physical pixels.
+@group(0) @binding(0) var<uniform> viewport: vec4<f32>;
+
+struct VertexOut {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>, @location(1) color: vec4<f32>) -> VertexOut {
+    // Pixels from top-left to NDC.
+    let ndc = position / viewport.xy * 2.0 - 1.0;
+    var out: VertexOut;
+    out.position = vec4<f32>(ndc.x, -ndc.y, 0.0, 1.0);
+    out.color = color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4<f32> {
+    return in.color;
+}
+";
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Vertex {
+    /// Physical pixels from the top left.
+    position: [f32; 2],
+    color: [f32; 4],
+}
+// Safety: `repr(C)` and made entirely of `f32`s, with no padding.
+unsafe impl bytemuck::Zeroable for Vertex {}
+unsafe impl bytemuck::Pod for Vertex {}
+
+/// A point along a stroke, in physical pixels.
+#[derive(Clone, Copy)]
+struct Sample {
+    position: [f32; 2],
+    radius: f32,
+}
+
+/// Triangulate a filled circle.
+fn push_disc(into: &mut Vec<Vertex>, at: Sample, color: [f32; 4]) {
+    const SEGMENTS: usize = 16;
+    let point = |idx: usize| {
+        let angle = idx as f32 / SEGMENTS as f32 * std::f32::consts::TAU;
+        Vertex {
+            position: [
+                at.position[0] + angle.cos() * at.radius,
+                at.position[1] + angle.sin() * at.radius,
+            ],
+            color,
+        }
+    };
+    let center = Vertex {
+        position: at.position,
+        color,
+    };
+    for idx in 0..SEGMENTS {
+        into.extend_from_slice(&[center, point(idx), point(idx + 1)]);
+    }
+}
+/// Triangulate a tapered line between two samples, with a round cap at the end.
+fn push_segment(into: &mut Vec<Vertex>, from: Sample, to: Sample, color: [f32; 4]) {
+    let delta = [
+        to.position[0] - from.position[0],
+        to.position[1] - from.position[1],
+    ];
+    let length = (delta[0] * delta[0] + delta[1] * delta[1]).sqrt();
+    if length > f32::EPSILON {
+        let normal = [-delta[1] / length, delta[0] / length];
+        let offset = |sample: Sample, sign: f32| Vertex {
+            position: [
+                sample.position[0] + normal[0] * sample.radius * sign,
+                sample.position[1] + normal[1] * sample.radius * sign,
+            ],
+            color,
+        };
+        let (a, b) = (offset(from, 1.0), offset(from, -1.0));
+        let (c, d) = (offset(to, 1.0), offset(to, -1.0));
+        into.extend_from_slice(&[a, b, c, b, d, c]);
+    }
+    push_disc(into, to, color);
+}
+
+/// State of a single tool's stroke, from `Down` to `Up`.
+struct Stroke {
+    color: [f32; 4],
+    /// The latest pose of the frame in progress. Axis changes only make sense once the whole frame is in.
+    pending: Option<Pose>,
+    /// Last sample, which the stroke has been drawn up to.
+    last: Option<Sample>,
+}
+impl Stroke {
+    fn new(color: [f32; 4]) -> Self {
+        Self {
+            color,
+            pending: None,
+            last: None,
+        }
+    }
+    /// Where a pose puts the brush, in physical pixels. Already smoothed and curved by the manager.
+    fn sample(pose: &Pose, scale_factor: f32) -> Sample {
+        // No pressure support? Assume full pressure.
+        let pressure = pose.pressure.get().unwrap_or(1.0);
+        Sample {
+            position: pose.position.map(|coord| coord * scale_factor),
+            radius: (pressure * BRUSH_RADIUS * scale_factor).max(0.5),
+        }
+    }
+    /// The frame is complete, commit the pending pose to the canvas.
+    fn frame(&mut self, scale_factor: f32, into: &mut Vec<Vertex>) {
+        let Some(pose) = self.pending.take() else {
+            return;
+        };
+        let sample = Self::sample(&pose, scale_factor);
+        if let Some(last) = self.last {
+            push_segment(into, last, sample, self.color);
+        } else {
+            // First sample, nothing to connect to.
+            push_disc(into, sample, self.color);
+        }
+        self.last = Some(sample);
+    }
+    /// Draw a throwaway continuation of the stroke to where the pen is estimated to be by the time it's seen.
+    fn predict(&self, predicted: &Pose, scale_factor: f32, into: &mut Vec<Vertex>) {
+        let Some(last) = self.last else {
+            return;
+        };
+        let mut predicted = Self::sample(predicted, scale_factor);
+        let offset = [
+            predicted.position[0] - last.position[0],
+            predicted.position[1] - last.position[1],
+        ];
+        let length = (offset[0] * offset[0] + offset[1] * offset[1]).sqrt();
+        if length > MAX_PREDICTION_PX {
+            predicted.position = [
+                last.position[0] + offset[0] / length * MAX_PREDICTION_PX,
+                last.position[1] + offset[1] / length * MAX_PREDICTION_PX,
+            ];
+        }
+        push_segment(into, last, predicted, self.color);
+    }
+}
+
+/// Turns octotablet events into triangles.
+struct Canvas {
+    scale_factor: f32,
+    strokes: std::collections::HashMap<octotablet::tool::ID, Stroke>,
+    /// Follows every pump, to extrapolate the strokes with.
+    predictor: Predictor,
+    palette_idx: usize,
+    /// Geometry which is permanently part of the drawing.
+    committed: Vec<Vertex>,
+    /// Set when `committed` was cleared, and must be re-uploaded from the start.
+    cleared: bool,
+}
+impl Canvas {
+    /// Consume events. Returns true if anything changed.
+    fn paint<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = octotablet::events::Event<'a>>,
+    ) -> bool {
+        use octotablet::events::{Event, PadEvent, ToolEvent};
+        let mut changed = false;
+        for event in events {
+            changed = true;
+            match event {
+                Event::Tool { tool, event } => match event {
+                    ToolEvent::Down => {
+                        let color =
+                            if matches!(tool.tool_type, Some(octotablet::tool::Type::Eraser)) {
+                                // Erase by painting the background. Cheating, but it works!
+                                BACKGROUND
+                            } else {
+                                PALETTE[self.palette_idx]
+                            };
+                        self.strokes.insert(tool.id(), Stroke::new(color));
+                    }
+                    ToolEvent::Pose(pose) => {
+                        if let Some(stroke) = self.strokes.get_mut(&tool.id()) {
+                            stroke.pending = Some(pose);
+                        }
+                    }
+                    ToolEvent::Frame(_) => {
+                        if let Some(stroke) = self.strokes.get_mut(&tool.id()) {
+                            stroke.frame(self.scale_factor, &mut self.committed);
+                        }
+                    }
+                    ToolEvent::Up | ToolEvent::Out | ToolEvent::Removed => {
+                        self.strokes.remove(&tool.id());
+                    }
+                    _ => (),
+                },
+                Event::Pad {
+                    event:
+                        PadEvent::Button {
+                            button_idx,
+                            pressed: true,
+                            ..
+                        },
+                    ..
+                } => {
+                    if button_idx == 0 {
+                        self.committed.clear();
+                        self.cleared = true;
+                    } else {
+                        self.palette_idx = (self.palette_idx + 1) % PALETTE.len();
+                    }
+                }
+                _ => (),
+            }
+        }
+        changed
+    }
+}
+
+/// All the GPU bits.
+struct Renderer {
+    surface: wgpu::Surface<'static>,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    config: wgpu::SurfaceConfiguration,
+    pipeline: wgpu::RenderPipeline,
+    viewport: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    vertices: wgpu::Buffer,
+    /// Number of vertices `vertices` can hold.
+    capacity: usize,
+    /// Number of committed vertices already uploaded.
+    uploaded: usize,
+    /// Scratch space for predicted geometry, rebuilt every frame.
+    predicted: Vec<Vertex>,
+}
+impl Renderer {
+    fn new(window: std::sync::Arc<winit::window::Window>) -> Self {
+        let size = window.inner_size();
+        let instance = wgpu::Instance::default();
+        let surface = instance.create_surface(window).expect("create surface");
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            compatible_surface: Some(&surface),
+            ..Default::default()
+        }))
+        .expect("find a graphics adapter");
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                required_features: wgpu::Features::empty(),
+                required_limits:
+                    wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits()),
+            },
+            None,
+        ))
+        .expect("open graphics device");
+
+        let mut config = surface
+            .get_default_config(&adapter, size.width.max(1), size.height.max(1))
+            .expect("surface is compatible with adapter");
+        // Every frame of queueing is another frame of latency between the pen and the ink.
+        config.desired_maximum_frame_latency = 1;
+        config.present_mode = wgpu::PresentMode::AutoNoVsync;
+        surface.configure(&device, &config);
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let viewport = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: std::mem::size_of::<[f32; 4]>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: viewport.as_entire_binding(),
+            }],
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<Vertex>() as u64,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x4],
+                }],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        });
+
+        let capacity = 1 << 16;
+        let vertices = Self::make_vertices(&device, capacity);
+        Self {
+            surface,
+            device,
+            queue,
+            config,
+            pipeline,
+            viewport,
+            bind_group,
+            vertices,
+            capacity,
+            uploaded: 0,
+            predicted: Vec::new(),
+        }
+    }
+    fn make_vertices(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (capacity * std::mem::size_of::<Vertex>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+    fn resize(&mut self, size: PhysicalSize<u32>) {
+        self.config.width = size.width.max(1);
+        self.config.height = size.height.max(1);
+        self.surface.configure(&self.device, &self.config);
+    }
+    fn render(&mut self, canvas: &mut Canvas) {
+        // Prediction is redone from scratch every frame, and never committed.
+        self.predicted.clear();
+        for (tool, stroke) in &canvas.strokes {
+            if let Some(pose) = canvas.predictor.predict(tool) {
+                stroke.predict(&pose, canvas.scale_factor, &mut self.predicted);
+            }
+        }
+
+        // Make room for everything, re-uploading if the buffer had to grow.
+        let total = canvas.committed.len() + self.predicted.len();
+        if total > self.capacity {
+            self.capacity = total.next_power_of_two();
+            self.vertices = Self::make_vertices(&self.device, self.capacity);
+            self.uploaded = 0;
+        }
+        if std::mem::take(&mut canvas.cleared) {
+            self.uploaded = 0;
+        }
+        // Only upload what's new since last frame, followed by the predictions.
+        let stride = std::mem::size_of::<Vertex>() as u64;
+        self.queue.write_buffer(
+            &self.vertices,
+            self.uploaded as u64 * stride,
+            bytemuck::cast_slice(&canvas.committed[self.uploaded..]),
+        );
+        self.uploaded = canvas.committed.len();
+        self.queue.write_buffer(
+            &self.vertices,
+            self.uploaded as u64 * stride,
+            bytemuck::cast_slice(&self.predicted),
+        );
+        self.queue.write_buffer(
+            &self.viewport,
+            0,
+            bytemuck::cast_slice(&[
+                self.config.width as f32,
+                self.config.height as f32,
+                0.0,
+                0.0,
+            ]),
+        );
+
+        let frame = match self.surface.get_current_texture() {
+            Ok(frame) => frame,
+            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                // Try again next time.
+                self.surface.configure(&self.device, &self.config);
+                return;
+            }
+            Err(e) => panic!("failed to acquire frame: {e}"),
+        };
+        let view = frame
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let [r, g, b, a] = BACKGROUND.map(f64::from);
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color { r, g, b, a }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            if total != 0 {
+                pass.set_pipeline(&self.pipeline);
+                pass.set_bind_group(0, &self.bind_group, &[]);
+                pass.set_vertex_buffer(0, self.vertices.slice(..));
+                #[allow(clippy::cast_possible_truncation)]
+                pass.draw(0..total as u32, 0..1);
+            }
+        }
+        self.queue.submit([encoder.finish()]);
+        frame.present();
+    }
+}
+
+fn main() {
+    let event_loop = winit::event_loop::EventLoopBuilder::<()>::default()
+        .build()
+        .expect("start event loop");
+    let window = std::sync::Arc::new(
+        winit::window::WindowBuilder::default()
+            .with_inner_size(PhysicalSize::new(800u32, 600u32))
+            .with_title("octotablet wgpu paint demo")
+            .build(&event_loop)
+            .expect("create window"),
+    );
+
+    let mut renderer = Renderer::new(window.clone());
+
+    let mut manager = Builder::default()
+        // Hardware often never reaches full pressure, fix that before applying our own curve.
+        .auto_range_pressure(true)
+        .pressure_curve(PressureCurve::new().gamma(PRESSURE_GAMMA))
+        .smoothing(SmoothingConfig {
+            position: Some(Filter::Ema {
+                time_constant: STABILIZATION,
+            }),
+            ..Default::default()
+        })
+        // Smoothing and prediction go by timestamps, and not all hardware provides them.
+        .synthesize_timestamps(true)
+        .build_shared(&window)
+        .expect("connect to stylus server");
+
+    let mut canvas = Canvas {
+        // Winit doesn't notify for the initial scale factor, query directly!
+        scale_factor: window.scale_factor() as f32,
+        strokes: std::collections::HashMap::new(),
+        predictor: Predictor::new(PREDICTION),
+        palette_idx: 0,
+        committed: Vec::new(),
+        cleared: false,
+    };
+
+    event_loop
+        .run(|e, target| {
+            use winit::event::*;
+
+            match e {
+                Event::WindowEvent { event, .. } => match event {
+                    WindowEvent::KeyboardInput {
+                        event:
+                            winit::event::KeyEvent {
+                                physical_key:
+                                    winit::keyboard::PhysicalKey::Code(
+                                        winit::keyboard::KeyCode::Escape,
+                                    ),
+                                state: winit::event::ElementState::Pressed,
+                                ..
+                            },
+                        ..
+                    }
+                    | WindowEvent::CloseRequested => target.exit(),
+                    WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                        canvas.scale_factor = scale_factor as f32;
+                    }
+                    WindowEvent::Resized(size) => {
+                        renderer.resize(size);
+                        window.request_redraw();
+                    }
+                    WindowEvent::RedrawRequested => {
+                        window.pre_present_notify();
+                        renderer.render(&mut canvas);
+                    }
+                    _ => (),
+                },
+                Event::AboutToWait => {
+                    let (events, exhausted) = manager
+                        .pump_budgeted(PUMP_BUDGET)
+                        .expect("octotablet event pump");
+                    // `Events` is `Copy`, so both get to see every event.
+                    canvas.predictor.observe(events);
+                    let changed = canvas.paint(events);
+                    if changed || exhausted {
+                        window.request_redraw();
+                    }
+
+                    // Octotablet doesn't wake winit, so spin during a stroke or while there's a backlog
+                    // and check back occasionally otherwise.
+                    target.set_control_flow(if exhausted || !canvas.strokes.is_empty() {
+                        winit::event_loop::ControlFlow::Poll
+                    } else {
+                        winit::event_loop::ControlFlow::wait_duration(
+                            std::time::Duration::from_millis(10),
+                        )
+                    });
+                }
+                _ => (),
+            }
+        })
+        .expect("winit event loop");
+}
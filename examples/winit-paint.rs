@@ -321,7 +321,7 @@ fn main() {
                     let events = manager.pump().expect("octotablet event pump");
 
                     // Has events - mark to pull more often if so!
-                    should_poll = events.into_iter().next().is_some();
+                    should_poll = !events.is_empty();
 
                     // Perform painting...
                     let needs_present = painter.paint(events, &mut pixmap);
@@ -0,0 +1,58 @@
+//! Making sure every tool is [`Added`](crate::events::ToolEvent::Added) before it's used, applied by the
+//! [`Manager`](crate::Manager) as the platform is pumped.
+//!
+//! Platforms are free to announce tools lazily, just before first use, and custom backends may forget to announce
+//! them at all - this fills in an `Added` wherever one is missing.
+
+use crate::{
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+};
+
+#[derive(Default)]
+pub(crate) struct Announcer {
+    /// Tools that have been added and not since removed.
+    announced: std::collections::HashSet<InternalID>,
+}
+impl Announcer {
+    /// Observe freshly pumped events, inserting an `Added` before the first event of any tool not yet announced.
+    pub(crate) fn process(
+        &mut self,
+        events: &mut std::collections::VecDeque<RawEvent<InternalID>>,
+    ) {
+        // Usually nothing's missing, so only take the events apart if there's something to fill in.
+        let Some(first_missing) = events.iter().position(|event| !self.observe(event)) else {
+            return;
+        };
+        let rest = events.split_off(first_missing);
+        for event in rest {
+            if !self.observe(&event) {
+                if let RawEvent::Tool { tool, .. } = &event {
+                    self.announced.insert(tool.clone());
+                    events.push_back(RawEvent::Tool {
+                        tool: tool.clone(),
+                        event: RawTool::Added,
+                    });
+                }
+            }
+            events.push_back(event);
+        }
+    }
+    /// Note the event's effect on which tools are announced. False if it uses a tool that isn't.
+    fn observe(&mut self, event: &RawEvent<InternalID>) -> bool {
+        let RawEvent::Tool { tool, event } = event else {
+            return true;
+        };
+        match event {
+            RawTool::Added => {
+                self.announced.insert(tool.clone());
+                true
+            }
+            RawTool::Removed => {
+                self.announced.remove(tool);
+                true
+            }
+            _ => self.announced.contains(tool),
+        }
+    }
+}
@@ -21,6 +21,63 @@ bitflags::bitflags! {
 }
 
 impl AvailableAxes {
+    /// Clear the fields of the pose for axes missing from this set. Position is always kept.
+    pub(crate) fn retain_in(self, pose: &mut Pose) {
+        let keep = |axis| self.contains(axis);
+        if !keep(Self::PRESSURE) {
+            pose.pressure = NicheF32::NONE;
+        }
+        if !keep(Self::TILT) {
+            pose.tilt = None;
+        }
+        if !keep(Self::DISTANCE) {
+            pose.distance = NicheF32::NONE;
+            pose.raw_distance = NicheF32::NONE;
+        }
+        if !keep(Self::ROLL) {
+            pose.roll = NicheF32::NONE;
+        }
+        if !keep(Self::WHEEL) {
+            pose.wheel = None;
+        }
+        if !keep(Self::SLIDER) {
+            pose.slider = NicheF32::NONE;
+        }
+        if !keep(Self::BUTTON_PRESSURE) {
+            pose.button_pressure = NicheF32::NONE;
+        }
+        if !keep(Self::CONTACT_SIZE) {
+            pose.contact_size = None;
+        }
+    }
+    /// Like [`AvailableAxes::retain_in`], for the raw values.
+    pub(crate) fn retain_in_raw(self, raw: &mut RawPose) {
+        let keep = |axis| self.contains(axis);
+        if !keep(Self::PRESSURE) {
+            raw.pressure = None;
+        }
+        if !keep(Self::TILT) {
+            raw.tilt = [None; 2];
+        }
+        if !keep(Self::DISTANCE) {
+            raw.distance = None;
+        }
+        if !keep(Self::ROLL) {
+            raw.roll = None;
+        }
+        if !keep(Self::WHEEL) {
+            raw.wheel = None;
+        }
+        if !keep(Self::SLIDER) {
+            raw.slider = None;
+        }
+        if !keep(Self::BUTTON_PRESSURE) {
+            raw.button_pressure = None;
+        }
+        if !keep(Self::CONTACT_SIZE) {
+            raw.contact_size = [None; 2];
+        }
+    }
     pub fn iter_axes(&self) -> impl Iterator<Item = Axis> {
         self.intersection(Self::all())
             .iter()
@@ -163,6 +220,115 @@ impl Union for Limits {
     }
 }
 
+/// A response curve for reshaping an axis, mapping `[0, 1]` onto `[0, 1]`. Axes with other ranges are
+/// normalized according to their [`Limits`] first.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Curve {
+    /// Leave values as reported.
+    #[default]
+    Linear,
+    /// Raise values to the given power. Less than one boosts low values, greater than one suppresses them.
+    /// Non-positive or NaN powers are treated as [`Curve::Linear`].
+    Power(f32),
+}
+impl Curve {
+    /// Reshape a value in `[0, 1]`. Values outside that range are clamped first.
+    #[must_use]
+    pub fn apply(self, value: f32) -> f32 {
+        let value = value.clamp(0.0, 1.0);
+        match self {
+            Self::Power(power) if power > 0.0 => value.powf(power),
+            Self::Linear | Self::Power(_) => value,
+        }
+    }
+    /// Reshape a value within the given limits, treating it as a fraction of the way between them.
+    #[must_use]
+    pub fn apply_within(self, value: f32, limits: Limits) -> f32 {
+        let span = limits.max - limits.min;
+        // Degenerate limits, nothing sensible to do.
+        if span <= 0.0 || !span.is_finite() {
+            return value;
+        }
+        limits.min + self.apply((value - limits.min) / span) * span
+    }
+}
+
+/// Per-axis strength of the easing applied to coarsely quantized axes, each in `[0, 1)`. Zero leaves the axis be,
+/// and higher values ease more heavily at the cost of responsiveness. See [`Builder::quantize`](crate::builder::Builder::quantize).
+///
+/// Easing only kicks in when the tool's reported [`Granularity`] is coarse enough to be visible, and never lets a value
+/// stray further than one quantization step from what the hardware reported.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Quantization {
+    /// See [`Pose::tilt`].
+    pub tilt: f32,
+    /// See [`Pose::roll`].
+    pub roll: f32,
+    /// See [`Pose::slider`].
+    pub slider: f32,
+}
+impl Quantization {
+    /// Whether any axis is eased at all.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        [self.tilt, self.roll, self.slider]
+            .into_iter()
+            .any(|strength| strength > 0.0)
+    }
+}
+
+/// A noise filter for a single axis, see [`SmoothingConfig`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Filter {
+    /// Exponential moving average, easing towards each new value. Simple and predictable, but lags behind
+    /// equally at every speed.
+    Ema {
+        /// How long it takes to get most (63%) of the way to a new value. Longer is smoother and laggier.
+        time_constant: std::time::Duration,
+    },
+    /// The [1€ filter](https://gery.casiez.net/1euro/), which smooths heavily while the value is near still and
+    /// hardly at all while it moves quickly, trading jitter at rest for lag in motion.
+    OneEuro {
+        /// Cutoff frequency at rest in Hz. Lower is smoother when still.
+        min_cutoff: f32,
+        /// How quickly the cutoff rises with speed. Higher reduces lag in fast motion. Scales with the units of the
+        /// axis, so position (in pixels) wants a far smaller value than pressure.
+        beta: f32,
+        /// Cutoff frequency in Hz for the speed estimate the cutoff is based on.
+        derivative_cutoff: f32,
+    },
+}
+impl Filter {
+    /// A 1€ filter with the given `beta`, and common defaults otherwise.
+    #[must_use]
+    pub fn one_euro(beta: f32) -> Self {
+        Self::OneEuro {
+            min_cutoff: 1.0,
+            beta,
+            derivative_cutoff: 1.0,
+        }
+    }
+}
+
+/// Which axes to filter for noise and how, see [`Builder::smoothing`](crate::builder::Builder::smoothing). `None`
+/// leaves the axis as reported.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct SmoothingConfig {
+    /// See [`Pose::position`].
+    pub position: Option<Filter>,
+    /// See [`Pose::pressure`].
+    pub pressure: Option<Filter>,
+    /// See [`Pose::tilt`].
+    pub tilt: Option<Filter>,
+}
+impl SmoothingConfig {
+    /// Whether any axis is filtered at all.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.position.is_some() || self.pressure.is_some() || self.tilt.is_some()
+    }
+}
+
 /// Represents a normalized axis, always in the range `[0, 1]`
 /// Since the min and max are fixed, only the granularity is given, if known.
 #[derive(Clone, Copy, Debug, Default)]
@@ -228,6 +394,53 @@ impl Default for LengthInfo {
     }
 }
 
+/// A unit to read lengths in regardless of how they're reported, see [`Tool::distance_in`](crate::tool::Tool::distance_in).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LengthUnit {
+    /// Physical millimeters.
+    Millimeters,
+    /// `[0, 1]` across the axis' [limits](LengthInfo::limits).
+    Normalized,
+}
+
+/// A length couldn't be converted to the requested [`LengthUnit`].
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LengthConversionError {
+    /// The axis isn't reported at all.
+    #[error(transparent)]
+    Unsupported(#[from] UnsupportedAxisError),
+    /// The axis is normalized, with no known relation to physical units.
+    #[error("axis has no physical unit")]
+    NoPhysicalUnit,
+    /// The axis is physical, but its range is unknown and so it can't be normalized.
+    #[error("axis range unknown")]
+    UnknownRange,
+}
+
+impl LengthInfo {
+    /// Convert a value of this axis into the given unit.
+    /// # Errors
+    /// If the conversion isn't possible with what's known of the axis.
+    pub fn convert(self, value: f32, unit: LengthUnit) -> Result<f32, LengthConversionError> {
+        match (self, unit) {
+            (Self::Normalized(_), LengthUnit::Normalized) => Ok(value),
+            (Self::Normalized(_), LengthUnit::Millimeters) => {
+                Err(LengthConversionError::NoPhysicalUnit)
+            }
+            (Self::Centimeters(_), LengthUnit::Millimeters) => Ok(value * 10.0),
+            (Self::Centimeters(info), LengthUnit::Normalized) => {
+                let limits = info
+                    .limits
+                    .filter(|limits| limits.max > limits.min)
+                    .ok_or(LengthConversionError::UnknownRange)?;
+                Ok((value - limits.min) / (limits.max - limits.min))
+            }
+        }
+    }
+}
+
 /// Generic information about an axis with hardware-defined limits.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Info {
@@ -430,9 +643,111 @@ impl FullInfo {
     }
 }
 
+/// Identifies an axis not modeled by [`Pose`], by the platform's own name for it. See [`ExtraAxes`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExtraAxisKey {
+    /// A packet property GUID, as used by Windows Ink.
+    Guid(u128),
+    /// A textual label, such as an X11 valuator's atom name.
+    Name(std::borrow::Cow<'static, str>),
+}
+/// Values of platform axes this crate doesn't (yet) model, as an escape hatch for exotic hardware.
+///
+/// Values are exactly as reported by the platform with no normalization whatsoever - their meaning and range
+/// are entirely up to the platform's interpretation of the key. Don't expect these to remain here forever, either;
+/// axes that gain first-class support in [`Pose`] may stop being reported here.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExtraAxes {
+    // Trivially tiny, linear scan is fine.
+    values: Vec<(ExtraAxisKey, f32)>,
+}
+impl ExtraAxes {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the value of an axis, replacing any previous value.
+    pub fn insert(&mut self, key: ExtraAxisKey, value: f32) {
+        if let Some((_, old)) = self.values.iter_mut().find(|(k, _)| *k == key) {
+            *old = value;
+        } else {
+            self.values.push((key, value));
+        }
+    }
+    /// Get the value of an axis, if reported.
+    #[must_use]
+    pub fn get(&self, key: &ExtraAxisKey) -> Option<f32> {
+        self.values
+            .iter()
+            .find_map(|(k, v)| (k == key).then_some(*v))
+    }
+    /// Iterate over every reported axis, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ExtraAxisKey, f32)> + '_ {
+        self.values.iter().map(|(k, v)| (k, *v))
+    }
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// An axis value exactly as the platform reported it, alongside the transform this crate used to make the
+/// corresponding [`Pose`] value of it: `(value + bias) * scale`. See [`RawPose`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RawValue {
+    pub value: i32,
+    pub bias: i32,
+    pub scale: f64,
+}
+impl RawValue {
+    /// A value that's used as-is, save for scaling.
+    #[must_use]
+    pub fn scaled(value: i32, scale: f64) -> Self {
+        Self {
+            value,
+            bias: 0,
+            scale,
+        }
+    }
+    /// Apply the transform, giving the value as it first entered this crate's processing.
+    #[must_use]
+    pub fn transformed(self) -> f64 {
+        (f64::from(self.value) + f64::from(self.bias)) * self.scale
+    }
+}
+
+/// The unprocessed platform values behind a [`Pose`], for archival, replay, and debugging. Reported as
+/// [`ToolEvent::RawPose`](crate::events::ToolEvent::RawPose) if enabled by
+/// [`Builder::report_raw_axes`](crate::Builder::report_raw_axes).
+///
+/// The transforms give values as they were *before* any of this crate's processing - curves, smoothing, and the
+/// like are applied afterwards, and aren't reflected here. Fields are `None` where the platform reported nothing.
+///
+/// # Platform support
+/// * Wayland - Position, tilt, and roll are in `wl_fixed` format, such that the scale includes the `1/256`. Others
+///   are as sent by the compositor, which has likely already rescaled them from the hardware's range.
+/// * Windows Ink - The packet properties, as reported by the driver.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct RawPose {
+    pub position: [Option<RawValue>; 2],
+    pub distance: Option<RawValue>,
+    pub pressure: Option<RawValue>,
+    pub button_pressure: Option<RawValue>,
+    pub tilt: [Option<RawValue>; 2],
+    pub roll: Option<RawValue>,
+    pub wheel: Option<RawValue>,
+    pub slider: Option<RawValue>,
+    pub contact_size: [Option<RawValue>; 2],
+}
+
 /// Represents the state of all axes of a tool at some snapshot in time.
 ///
 /// Interpretations, units, and minimas/maximas of some axes require querying the [`Tool`](crate::tool::Tool) that generated this pose's [`FullInfo`].
+/// Platform axes not modeled here may be reported alongside, see [`ToolEvent::Extras`](crate::events::ToolEvent::Extras).
 ///
 /// # Quirks
 /// There may be axis values reported that the tool does *not* advertise as available,
@@ -448,6 +763,7 @@ pub struct Pose {
     /// correct interpretation of these values.
     ///
     /// This may have sub-pixel precision, and may exceed your window size in the negative or positive directions.
+    /// See [`Builder::coordinate_space`](crate::Builder::coordinate_space) for other spaces.
     pub position: [f32; 2],
     /// Perpendicular distance from the surface of the tablet. This may be an arbitrary, unitless `[0, 1]` value, or
     /// reported in physical centimeters, see the [`FullInfo::distance`] of the reporting [`Tool`](crate::tool::Tool) for interpretation.
@@ -456,6 +772,9 @@ pub struct Pose {
     /// This will not necessarily be zero when in contact with the device, and may
     /// stop updating after contact is reported.
     pub distance: NicheF32,
+    /// [`Pose::distance`] exactly as reported by the hardware, before any [`Builder::distance_curve`](crate::Builder::distance_curve)
+    /// was applied. Identical to `distance` if no curve is set.
+    pub raw_distance: NicheF32,
     /// The force the nib is pressed with.
     ///
     /// # Quirks
@@ -482,5 +801,9 @@ pub struct Pose {
     pub slider: NicheF32,
     /// The size of the contact ellipse. First element describes the X-axis width of the ellipse,
     /// and second describes the Y-axis height. See [`FullInfo::contact_size`] of the reporting [`Tool`](crate::tool::Tool) for units.
+    ///
+    /// # Platform support
+    /// * Windows Ink, from the packet width and height. This is where some chisel erasers report their width, for
+    ///   realistically sized erasing. Hardware reporting only one dimension has it used for both.
     pub contact_size: Option<[f32; 2]>,
 }
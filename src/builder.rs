@@ -1,6 +1,11 @@
 //! Builder-style configuration for connecting to the system tablet API.
 //!
 //! For a default configuration, `Builder::new().build_{shared, raw}` is all you need!
+//!
+//! ## Multiple windows
+//! A manager starts out reporting the events of the window it was built for, and only those. On Wayland, the app's
+//! other windows may be added to the same manager with [`Manager::add_window`], telling events apart by
+//! [`Event::window_id`](crate::events::Event::window_id). On Windows Ink, build a manager per window instead.
 
 use crate::{Backing, Manager};
 
@@ -22,19 +27,161 @@ impl From<raw_window_handle::HandleError> for BuildError {
 }
 
 /// Pre-construction configuration for a [`Manager`].
+///
+/// Unlike the manager, this is `Send`, so it may be set up anywhere and then moved to the thread that should own the
+/// manager before building. See [the threading notes](Manager#threading).
 // Not every integration will use every field, leaving some "unread" on some targets.
 #[allow(unused)]
+// One bool per opt-in setter below.
+#[allow(clippy::struct_excessive_bools)]
 pub struct Builder {
     pub(crate) emulate_tool_from_mouse: bool,
+    pub(crate) auto_range_pressure: bool,
+    pub(crate) normalize_pressure_to_tip: bool,
+    pub(crate) synthesize_timestamps: bool,
+    pub(crate) debug_history: usize,
+    pub(crate) distance_curve: crate::axis::Curve,
+    pub(crate) out_on_focus_loss: OutOnFocusLoss,
+    pub(crate) fingerprint_tools: bool,
+    pub(crate) quantize: crate::axis::Quantization,
+    pub(crate) smoothing: crate::axis::SmoothingConfig,
+    pub(crate) pointer_lock: PointerLock,
+    pub(crate) pressure_curve: crate::mapping::PressureCurve,
+    pub(crate) stuck_down_timeout: Option<std::time::Duration>,
+    pub(crate) suppress_duplicate_poses: bool,
+    pub(crate) capture_touch: bool,
+    pub(crate) report_raw_axes: bool,
+    pub(crate) coordinate_space: CoordinateSpace,
+    pub(crate) null_fallback: bool,
+    pub(crate) axes: crate::axis::AvailableAxes,
 }
+// Keep it that way, it's the only way to get a manager onto another thread!
+const _: fn() = || {
+    fn assert_send<T: Send>() {}
+    assert_send::<Builder>();
+};
 impl Default for Builder {
     fn default() -> Self {
         Self {
             emulate_tool_from_mouse: true,
+            auto_range_pressure: false,
+            normalize_pressure_to_tip: false,
+            synthesize_timestamps: false,
+            debug_history: 0,
+            distance_curve: crate::axis::Curve::Linear,
+            out_on_focus_loss: OutOnFocusLoss::Never,
+            fingerprint_tools: false,
+            quantize: crate::axis::Quantization::default(),
+            smoothing: crate::axis::SmoothingConfig::default(),
+            pointer_lock: PointerLock::Deliver,
+            pressure_curve: crate::mapping::PressureCurve::default(),
+            stuck_down_timeout: None,
+            suppress_duplicate_poses: false,
+            capture_touch: false,
+            report_raw_axes: false,
+            coordinate_space: CoordinateSpace::Native,
+            null_fallback: false,
+            axes: crate::axis::AvailableAxes::all(),
         }
     }
 }
 
+/// Which in-proximity tools should be sent [`Out`](crate::events::ToolEvent::Out) when the window loses focus,
+/// see [`Builder::out_on_focus_loss`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum OutOnFocusLoss {
+    /// Every tool is sent out, ending any strokes in progress.
+    Always,
+    /// Leave tools as they are, the platform's say is final.
+    #[default]
+    Never,
+    /// Tools are sent out, except those mid-stroke. Hovering tools are cleaned up while a popup briefly taking focus
+    /// doesn't cut the stroke that summoned it short.
+    UnlessDown,
+}
+
+/// What becomes of tool events while the application has the pointer locked, see [`Builder::pointer_lock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PointerLock {
+    /// Tools keep reporting absolute positions as usual, unaffected by the lock.
+    #[default]
+    Deliver,
+    /// Every tool is sent out upon locking, and tools are ignored until they leave proximity after unlocking.
+    Suspend,
+}
+
+/// Which space tool and touch positions are reported in, see [`Builder::coordinate_space`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CoordinateSpace {
+    /// Whatever the platform uses for the window - the same space the toolkit reports pointer events in.
+    #[default]
+    Native,
+    /// Logical pixels relative to the top left of the window's client area, independent of scale factor.
+    Logical,
+    /// Physical pixels relative to the top left of the window's client area.
+    Physical,
+    /// Physical pixels relative to the top left of the virtual desktop, for overlays and the like.
+    Screen,
+}
+
+/// A [`Builder`] option as it was requested, along with whether the backend honored it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Setting<T> {
+    /// The value given to the [`Builder`], or its default.
+    pub requested: T,
+    /// Whether the behavior actually in effect matches the request. Options unsupported by the backend
+    /// are still considered honored when requesting the behavior the backend has anyway (e.g. requesting
+    /// *no* mouse emulation from a backend that never emulates).
+    pub honored: bool,
+}
+impl<T> Setting<T> {
+    fn new(requested: T, honored: bool) -> Self {
+        Self { requested, honored }
+    }
+}
+
+/// The effective configuration of a [`Manager`], see [`Manager::config`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Config {
+    /// See [`Builder::emulate_tool_from_mouse`].
+    pub emulate_tool_from_mouse: Setting<bool>,
+    /// See [`Builder::auto_range_pressure`].
+    pub auto_range_pressure: Setting<bool>,
+    /// See [`Builder::normalize_pressure_to_tip`].
+    pub normalize_pressure_to_tip: Setting<bool>,
+    /// See [`Builder::synthesize_timestamps`].
+    pub synthesize_timestamps: Setting<bool>,
+    /// See [`Builder::debug_history`].
+    pub debug_history: Setting<usize>,
+    /// See [`Builder::distance_curve`].
+    pub distance_curve: Setting<crate::axis::Curve>,
+    /// See [`Builder::out_on_focus_loss`].
+    pub out_on_focus_loss: Setting<OutOnFocusLoss>,
+    /// See [`Builder::fingerprint_tools`].
+    pub fingerprint_tools: Setting<bool>,
+    /// See [`Builder::quantize`].
+    pub quantize: Setting<crate::axis::Quantization>,
+    /// See [`Builder::smoothing`].
+    pub smoothing: Setting<crate::axis::SmoothingConfig>,
+    /// See [`Builder::pointer_lock`].
+    pub pointer_lock: Setting<PointerLock>,
+    /// See [`Builder::stuck_down_timeout`].
+    pub stuck_down_timeout: Setting<Option<std::time::Duration>>,
+    /// See [`Builder::suppress_duplicate_poses`].
+    pub suppress_duplicate_poses: Setting<bool>,
+    /// See [`Builder::capture_touch`].
+    pub capture_touch: Setting<bool>,
+    /// See [`Builder::report_raw_axes`].
+    pub report_raw_axes: Setting<bool>,
+    /// See [`Builder::coordinate_space`].
+    pub coordinate_space: Setting<CoordinateSpace>,
+    /// See [`Builder::null_fallback`].
+    pub null_fallback: Setting<bool>,
+    /// See [`Builder::axes`].
+    pub axes: Setting<crate::axis::AvailableAxes>,
+}
+
 /// # Configuration
 #[allow(clippy::needless_update)]
 impl Builder {
@@ -56,6 +203,307 @@ impl Builder {
             ..self
         }
     }
+    /// Set whether pressure should be re-normalized according to the range each tool actually reports at runtime.
+    ///
+    /// Some hardware claims a pressure range of which only a subset is ever reported, making full pressure
+    /// hard or impossible to reach. When enabled, the highest pressure seen from each tool is tracked and the
+    /// normalization is gradually adapted to it at the end of each stroke, much like many art programs do internally.
+    /// See [`Manager::pressure_ceiling`] for persisting the learned range between runs.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn auto_range_pressure(self, auto_range: bool) -> Self {
+        Self {
+            auto_range_pressure: auto_range,
+            ..self
+        }
+    }
+    /// Set whether pressure should be re-normalized such that `0.0` is the moment the tool goes
+    /// [down](crate::events::ToolEvent::Down), for consistent "pressure at the moment of contact" across devices.
+    /// Otherwise, some tools go down already well above zero pressure, while others sit at zero for a while after.
+    ///
+    /// Uses the tool's [`tip_threshold`](crate::tool::Tool::tip_threshold) where the platform reports it. Otherwise,
+    /// the lowest pressure each tool has been seen to go down at is used, which settles after a few strokes. Query
+    /// the threshold in use with [`Manager::tip_threshold`]. Applied before [`Builder::auto_range_pressure`].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate, though no built-in backend reports the threshold as of yet.
+    #[must_use]
+    pub fn normalize_pressure_to_tip(self, normalize: bool) -> Self {
+        Self {
+            normalize_pressure_to_tip: normalize,
+            ..self
+        }
+    }
+    /// Set whether frames without a timestamp should instead be stamped with the time they were received by
+    /// the [`Manager`]. Such [synthetic](crate::events::FrameTimestamp::is_synthetic) timestamps are far less precise,
+    /// but allow velocity-dependent features to degrade gracefully rather than special-casing `None`.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn synthesize_timestamps(self, synthesize: bool) -> Self {
+        Self {
+            synthesize_timestamps: synthesize,
+            ..self
+        }
+    }
+    /// Set how many of the most recent raw platform messages should be retained for [`Manager::debug_dump`].
+    /// This is intended for attaching to quirk and bug reports, exactly what the server said.
+    ///
+    /// Defaults to `0` (disabled).
+    ///
+    /// # Supprted platforms
+    /// * Wayland
+    /// * Windows Ink
+    #[must_use]
+    pub fn debug_history(self, messages: usize) -> Self {
+        Self {
+            debug_history: messages,
+            ..self
+        }
+    }
+    /// Set a response curve to reshape hover [distance](crate::axis::Pose::distance) with, for consistent feel across
+    /// hardware with differing distance responses. Physical distances are reshaped within the tool's reported
+    /// distance [limits](crate::axis::LengthInfo::limits), and left as-is if there are none.
+    /// The value before reshaping remains available as [`Pose::raw_distance`](crate::axis::Pose::raw_distance).
+    ///
+    /// Defaults to [`Curve::Linear`](crate::axis::Curve::Linear).
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn distance_curve(self, curve: crate::axis::Curve) -> Self {
+        Self {
+            distance_curve: curve,
+            ..self
+        }
+    }
+    /// Set whether tools should be sent [`Out`](crate::events::ToolEvent::Out) when the window loses
+    /// [focus](crate::events::Event::WindowFocus), for consistent behavior regardless of whether the platform
+    /// keeps tracking tools across focus changes. Tools that are sent out have any further events ignored until they
+    /// genuinely leave proximity, after which they come back in as usual.
+    ///
+    /// Defaults to [`OutOnFocusLoss::Never`].
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate, on backends reporting window focus.
+    #[must_use]
+    pub fn out_on_focus_loss(self, policy: OutOnFocusLoss) -> Self {
+        Self {
+            out_on_focus_loss: policy,
+            ..self
+        }
+    }
+    /// Set whether a heuristic [fingerprint](crate::tool::Fingerprint) of each tool's behavior should be built up
+    /// as it is used, for telling apart tools that don't report a [`HardwareID`](crate::tool::HardwareID).
+    /// See [`Manager::tool_fingerprint`].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn fingerprint_tools(self, fingerprint: bool) -> Self {
+        Self {
+            fingerprint_tools: fingerprint,
+            ..self
+        }
+    }
+    /// Set how strongly to ease axes that the tool reports to be coarsely quantized, such as tilt reported in
+    /// whole degrees, which otherwise shows up as visible stair-stepping in e.g. brush angle. Axes which are finely
+    /// quantized or whose [granularity](crate::axis::Granularity) is unknown are left as-is.
+    ///
+    /// Defaults to no easing.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate, on backends reporting axis granularity.
+    #[must_use]
+    pub fn quantize(self, quantize: crate::axis::Quantization) -> Self {
+        Self { quantize, ..self }
+    }
+    /// Set filters to take the noise out of position, pressure, and tilt, such as the jitter of a cheap tablet or
+    /// the wobble of a shaky hand. Filtering here rather than in the app gets to use the platform's frame
+    /// timestamps, so behaves the same no matter how irregularly the app gets around to pumping.
+    ///
+    /// Frames without timestamps are assumed to be evenly spaced, see also [`Builder::synthesize_timestamps`].
+    /// Any filter adds some lag, which may need making up for with [prediction](crate::predict).
+    ///
+    /// Defaults to no filtering.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn smoothing(self, smoothing: crate::axis::SmoothingConfig) -> Self {
+        Self { smoothing, ..self }
+    }
+    /// Set how long a tool may be down without reporting any poses before it's considered stuck, in which case it is
+    /// sent [`TimedOut`](crate::events::ToolEvent::TimedOut), [`Up`](crate::events::ToolEvent::Up), and
+    /// [`Out`](crate::events::ToolEvent::Out) on the platform's behalf. This puts an end to never-ending strokes
+    /// when drivers lose track of a tool. Further events from it are ignored until it genuinely leaves proximity.
+    ///
+    /// Some platforms only report poses upon change, so a tool held perfectly still is indistinguishable from a
+    /// stuck one - be generous, on the order of seconds.
+    ///
+    /// Defaults to no timeout.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate. Only checked as the manager is [pumped](Manager::pump).
+    #[must_use]
+    pub fn stuck_down_timeout(self, timeout: std::time::Duration) -> Self {
+        Self {
+            stuck_down_timeout: Some(timeout),
+            ..self
+        }
+    }
+    /// Drop frames consisting of nothing but a pose identical to the last one reported by that tool, which some
+    /// drivers send in a constant stream while the tool is held still. This saves waking the render loop for nothing
+    /// while hovering idly. A repeated frame is still let through every second or so, in case anyone was counting
+    /// on them.
+    ///
+    /// Frames carrying anything else, such as a button press, are always kept.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn suppress_duplicate_poses(self, suppress: bool) -> Self {
+        Self {
+            suppress_duplicate_poses: suppress,
+            ..self
+        }
+    }
+    /// Set whether fingers on touch-sensitive surfaces should be reported, as [`Event::Touch`](crate::events::Event::Touch).
+    /// See the [`touch`](crate::touch) module.
+    ///
+    /// Defaults to `false`, leaving touch to the windowing toolkit.
+    ///
+    /// # Supprted platforms
+    /// * Wayland - every touch device of the seat, touchscreens included. The toolkit receives the touches all the
+    ///   same.
+    /// * Windows Ink - touch digitizers, the fingers of which are otherwise reported as tools.
+    #[must_use]
+    pub fn capture_touch(self, capture: bool) -> Self {
+        Self {
+            capture_touch: capture,
+            ..self
+        }
+    }
+    /// Set whether the platform's unprocessed axis values should be reported alongside each pose, as
+    /// [`ToolEvent::RawPose`](crate::events::ToolEvent::RawPose). Handy for archival, replay, and debugging, but
+    /// otherwise just noise.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * Wayland and Windows Ink - See [`RawPose`](crate::axis::RawPose) for caveats.
+    /// * Custom backends - whatever `RawPose` events the backend sends are passed on, and dropped otherwise.
+    #[must_use]
+    pub fn report_raw_axes(self, report: bool) -> Self {
+        Self {
+            report_raw_axes: report,
+            ..self
+        }
+    }
+    /// Set which space tool and touch positions, as well as [`Manager::cursor_position`], are reported in. Whichever
+    /// is chosen, [`Pose::position`](crate::axis::Pose::position) may land outside of the window while a tool is
+    /// held down.
+    ///
+    /// Defaults to [`CoordinateSpace::Native`].
+    ///
+    /// # Supprted platforms
+    /// * Wayland - natively in logical pixels. The toolkit owns the scale factor and the protocol has no notion of
+    ///   screen space, so only [`Native`](CoordinateSpace::Native) and [`Logical`](CoordinateSpace::Logical) are
+    ///   honored.
+    /// * Windows Ink - natively in client-area pixels, which are physical pixels for DPI-aware applications. Those
+    ///   are all [`Physical`](CoordinateSpace::Physical) gets, so it's reported as not honored. The window's position
+    ///   for [`Screen`](CoordinateSpace::Screen) is sampled once per pump, so positions may lag a moving window
+    ///   slightly.
+    /// * Custom backends - reported as given, only [`Native`](CoordinateSpace::Native) is honored.
+    #[must_use]
+    pub fn coordinate_space(self, space: CoordinateSpace) -> Self {
+        Self {
+            coordinate_space: space,
+            ..self
+        }
+    }
+    /// Set a response curve to reshape tool pressure with, such as to adapt to a user's hand. Applied after
+    /// [`Builder::auto_range_pressure`], if enabled. May be changed later with [`Manager::set_pressure_curve`].
+    ///
+    /// Defaults to leaving pressure as reported.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn pressure_curve(self, curve: crate::mapping::PressureCurve) -> Self {
+        Self {
+            pressure_curve: curve,
+            ..self
+        }
+    }
+    /// Set what becomes of tool events while the application has the pointer locked, as reported with
+    /// [`Manager::set_pointer_locked`]. Pointer lock (or grab, or constraint, or clip, depending on who you ask)
+    /// applies to the mouse pointer alone, and tablets keep reporting absolute positions right through it - which
+    /// is great for a sculpting app navigating with a locked mouse while sculpting with the pen, and not so great
+    /// for a game that didn't expect the pen to keep on doing things.
+    ///
+    /// Locking and unlocking is reported in the event stream as [`Event::PointerLock`](crate::events::Event::PointerLock)
+    /// regardless of this setting.
+    ///
+    /// Defaults to [`PointerLock::Deliver`].
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate. Neither Wayland's pointer constraints nor Windows' cursor clipping affect
+    ///   tablet input, so no backend needs to be told.
+    #[must_use]
+    pub fn pointer_lock(self, policy: PointerLock) -> Self {
+        Self {
+            pointer_lock: policy,
+            ..self
+        }
+    }
+    /// Set which axes should be reported, for consumers with no use for the rest. Axes missing from the set are
+    /// always `None` in [`Pose`](crate::axis::Pose)s, and backends able to are told not to bother collecting them.
+    /// Position is always reported.
+    ///
+    /// [`Tool::axes`](crate::tool::Tool::axes) still describes the hardware, except where noted below.
+    ///
+    /// Defaults to [`AvailableAxes::all`](crate::axis::AvailableAxes::all).
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    /// * Windows Ink - the axes aren't requested from the driver at all, trimming its packets. Being unrequested,
+    ///   they're missing from [`Tool::axes`](crate::tool::Tool::axes) too.
+    #[must_use]
+    pub fn axes(self, axes: crate::axis::AvailableAxes) -> Self {
+        Self { axes, ..self }
+    }
+    /// Set whether building for a handle no backend supports should give a [`Null`](crate::Backend::Null) manager,
+    /// with no devices and no events, in place of [`BuildError::Unsupported`]. Handy for treating tablet support as
+    /// a nice-to-have, without keeping track of whether there's a manager at all. Check [`Manager::backed`] to tell
+    /// if there's anything behind it.
+    ///
+    /// Other errors, such as failing to get at the handles in the first place, are still reported.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Supprted platforms
+    /// * All - implemented by this crate.
+    #[must_use]
+    pub fn null_fallback(self, fallback: bool) -> Self {
+        Self {
+            null_fallback: fallback,
+            ..self
+        }
+    }
 }
 /// # Finishing
 impl Builder {
@@ -94,6 +542,27 @@ impl Builder {
         // Safety: forwarded to this fn's contract.
         unsafe { self.build(rwh, Backing::Raw) }
     }
+    /// Build from a user-provided backend. See the [`custom`](crate::custom) module for details.
+    #[cfg(custom_backend)]
+    #[must_use]
+    pub fn build_custom(self, backend: impl crate::custom::CustomBackend + 'static) -> Manager {
+        let internal =
+            crate::platform::PlatformManager::Custom(crate::platform::custom::Manager::new(
+                Box::new(backend),
+                self.capture_touch,
+                self.report_raw_axes,
+            ));
+        // No handles involved, nothing to keep alive!
+        self.finish(internal, Backing::Raw)
+    }
+    /// Build a headless manager, along with the controller to drive it with. See the [`mock`](crate::mock) module for
+    /// details.
+    #[cfg(mock_backend)]
+    #[must_use]
+    pub fn build_mock(self) -> (Manager, crate::mock::Controller) {
+        let (backend, controller) = crate::mock::new();
+        (self.build_custom(backend), controller)
+    }
     /// Private, raw builder that the others delegate into.
     ///
     /// The `rwh` implementor object is *not* kept.
@@ -108,12 +577,20 @@ impl Builder {
         let internal = match rwh.display_handle()?.as_raw() {
             #[cfg(wl_tablet)]
             raw_window_handle::RawDisplayHandle::Wayland(wlh) => {
+                // Without a surface to tell our events apart by, we'll just have to take them all.
+                let surface = match rwh.window_handle().map(|wh| wh.as_raw()) {
+                    Ok(raw_window_handle::RawWindowHandle::Wayland(wh)) => {
+                        Some(wh.surface.as_ptr().cast())
+                    }
+                    _ => None,
+                };
                 Ok(crate::platform::PlatformManager::Wayland(
                     // Safety: forwarded to this fn's contract.
                     unsafe {
                         crate::platform::wl::Manager::build_wayland_display(
-                            self,
+                            &self,
                             wlh.display.as_ptr().cast(),
+                            surface,
                         )
                     },
                 ))
@@ -128,7 +605,7 @@ impl Builder {
                         // Safety: forwarded to this fn's contract.
                         // Fixme: unwrap.
                         unsafe {
-                            crate::platform::ink::Manager::build_hwnd(self, wh.hwnd).unwrap()
+                            crate::platform::ink::Manager::build_hwnd(&self, wh.hwnd).unwrap()
                         },
                     ))
                 } else {
@@ -136,11 +613,113 @@ impl Builder {
                 }
             }
             _ => Err(BuildError::Unsupported),
-        }?;
+        }
+        .or_else(|err| match err {
+            BuildError::Unsupported if self.null_fallback => Ok(
+                crate::platform::PlatformManager::Null(crate::platform::null::Manager),
+            ),
+            err => Err(err),
+        })?;
 
-        Ok(Manager {
-            internal,
-            _backing: backing,
-        })
+        Ok(self.finish(internal, backing))
+    }
+    /// Determine which of the options the given backend honors.
+    fn effective(&self, backend: crate::Backend) -> Config {
+        use crate::Backend;
+        let emulates_mouse = matches!(backend, Backend::WindowsInkRealTimeStylus);
+        let keeps_history = matches!(
+            backend,
+            Backend::WaylandTabletUnstableV2 | Backend::WindowsInkRealTimeStylus
+        );
+        Config {
+            emulate_tool_from_mouse: Setting::new(
+                self.emulate_tool_from_mouse,
+                emulates_mouse || !self.emulate_tool_from_mouse,
+            ),
+            // Crate-side, always honored.
+            auto_range_pressure: Setting::new(self.auto_range_pressure, true),
+            normalize_pressure_to_tip: Setting::new(self.normalize_pressure_to_tip, true),
+            synthesize_timestamps: Setting::new(self.synthesize_timestamps, true),
+            debug_history: Setting::new(
+                self.debug_history,
+                keeps_history || self.debug_history == 0,
+            ),
+            distance_curve: Setting::new(self.distance_curve, true),
+            out_on_focus_loss: Setting::new(self.out_on_focus_loss, true),
+            fingerprint_tools: Setting::new(self.fingerprint_tools, true),
+            quantize: Setting::new(self.quantize, true),
+            smoothing: Setting::new(self.smoothing, true),
+            pointer_lock: Setting::new(self.pointer_lock, true),
+            stuck_down_timeout: Setting::new(self.stuck_down_timeout, true),
+            suppress_duplicate_poses: Setting::new(self.suppress_duplicate_poses, true),
+            // Every backend can, hardware permitting.
+            capture_touch: Setting::new(self.capture_touch, true),
+            report_raw_axes: Setting::new(self.report_raw_axes, true),
+            coordinate_space: Setting::new(
+                self.coordinate_space,
+                match self.coordinate_space {
+                    CoordinateSpace::Native => true,
+                    CoordinateSpace::Logical => matches!(
+                        backend,
+                        Backend::WaylandTabletUnstableV2 | Backend::WindowsInkRealTimeStylus
+                    ),
+                    // Ink's client pixels are only physical if the app is DPI-aware, which isn't ours to know.
+                    CoordinateSpace::Physical => false,
+                    CoordinateSpace::Screen => matches!(backend, Backend::WindowsInkRealTimeStylus),
+                },
+            ),
+            null_fallback: Setting::new(self.null_fallback, true),
+            axes: Setting::new(self.axes, true),
+        }
+    }
+    /// Wrap up a backend into a `Manager`, applying the crate-side configuration.
+    fn finish(&self, internal: crate::platform::PlatformManager, backing: Backing) -> Manager {
+        let backend = crate::platform::backend_of(&internal);
+        Manager {
+            config: self.effective(backend),
+            internal: crate::util::Backed::new(internal, backing),
+            events: Vec::new(),
+            backlog: std::collections::VecDeque::new(),
+            auto_range_pressure: self
+                .auto_range_pressure
+                .then(crate::pressure::AutoRange::default),
+            tip_normalize: self
+                .normalize_pressure_to_tip
+                .then(crate::pressure::TipNormalize::default),
+            synthetic_epoch: self.synthesize_timestamps.then(std::time::Instant::now),
+            distance_curve: self.distance_curve,
+            pressure_curve: self.pressure_curve.clone(),
+            focus_out: (self.out_on_focus_loss != OutOnFocusLoss::Never
+                || self.pointer_lock != PointerLock::Deliver)
+                .then(|| crate::focus::FocusOut::new(self.out_on_focus_loss, self.pointer_lock)),
+            fingerprints: self
+                .fingerprint_tools
+                .then(crate::fingerprint::Tracker::default),
+            quantize: self
+                .quantize
+                .is_enabled()
+                .then(|| crate::quantize::Dequantizer::new(self.quantize)),
+            filters: self
+                .smoothing
+                .is_enabled()
+                .then(|| crate::filter::Filters::new(self.smoothing)),
+            watchdog: self.stuck_down_timeout.map(crate::watchdog::Watchdog::new),
+            dedup: self
+                .suppress_duplicate_poses
+                .then(crate::dedup::Dedup::default),
+            axes: self.axes,
+            devices_epoch: 0,
+            removal_pending: false,
+            pointer_locked: false,
+            pointer_lock_change: None,
+            pumped_at: std::time::Instant::now(),
+            pumping: false,
+            summary: crate::summary::Tracker::default(),
+            hit_region: crate::region::HitRegion::default(),
+            announcer: crate::announce::Announcer::default(),
+            snapshot: std::cell::OnceCell::new(),
+            next_window: 1,
+            affinity: crate::util::ThreadAffinity::current(),
+        }
     }
 }
@@ -0,0 +1,271 @@
+//! # Custom backends
+//!
+//! For environments not covered by the builtin backends - custom compositors, embedded digitizers, simulators,
+//! and the like. Implement [`CustomBackend`] and hand it to [`Builder::build_custom`](crate::Builder::build_custom)
+//! to reuse the rest of this crate's event model and utilities.
+//!
+//! Devices are identified by plain `u64`s of the backend's choosing, which must be unique amongst devices
+//! of the same kind for as long as the device is alive. Buttons are likewise identified by plain `u32`s.
+
+use crate::{
+    axis::{ExtraAxes, Pose, RawPose},
+    events::{DialEvent, FrameTimestamp, TabletEvent, TouchStripEvent},
+    pad::{Dial, Group, Pad, Ring, Strip},
+    tablet::Tablet,
+    tool::Tool,
+    touch::{ContactID, Finger, TouchEvent},
+};
+
+/// Errors that a custom backend may report from [`CustomBackend::pump`].
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// The interface a user-provided backend implements. See the [module level docs](crate::custom).
+pub trait CustomBackend {
+    /// Dispatch pending events, updating hardware reports and pushing any new events to `events`, which is
+    /// always empty upon call.
+    ///
+    /// Devices introduced this pump must already be present in the hardware reports when their `Added` event
+    /// is pushed. Devices `Removed` this pump must remain in the hardware reports until the *next* call to `pump`, so
+    /// that events can still refer to them.
+    ///
+    /// # Errors
+    /// Any error is forwarded to the caller of [`Manager::pump`](crate::Manager::pump).
+    fn pump(&mut self, events: &mut Vec<Event>) -> Result<(), Error>;
+    /// See [`Manager::pads`](crate::Manager::pads).
+    fn pads(&self) -> &[Pad];
+    /// See [`Manager::tools`](crate::Manager::tools).
+    fn tools(&self) -> &[Tool];
+    /// See [`Manager::tablets`](crate::Manager::tablets).
+    fn tablets(&self) -> &[Tablet];
+    /// See [`Manager::timestamp_granularity`](crate::Manager::timestamp_granularity). Defaults to `None`.
+    fn timestamp_granularity(&self) -> Option<std::time::Duration> {
+        None
+    }
+    /// See [`Manager::cursor_position`](crate::Manager::cursor_position). Defaults to `None`.
+    fn cursor_position(&self) -> Option<[f32; 2]> {
+        None
+    }
+    /// See [`Manager::hide_cursor_while_down`](crate::Manager::hide_cursor_while_down). Defaults to ignoring the request.
+    fn hide_cursor_while_down(&mut self, _hide: bool) {}
+    /// See [`Manager::set_button_feedback`](crate::Manager::set_button_feedback) and friends. Defaults to
+    /// [`FeedbackError::Unsupported`](crate::FeedbackError::Unsupported).
+    ///
+    /// # Errors
+    /// Forwarded to the caller.
+    fn set_feedback(
+        &mut self,
+        _target: Feedback,
+        _description: &str,
+    ) -> Result<(), crate::FeedbackError> {
+        Err(crate::FeedbackError::Unsupported)
+    }
+    /// See [`Manager::accessibility`](crate::Manager::accessibility). Defaults to knowing nothing.
+    fn accessibility(&self) -> crate::Accessibility {
+        crate::Accessibility::default()
+    }
+}
+
+/// The pad control to describe in [`CustomBackend::set_feedback`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Feedback {
+    Button { pad: u64, button_idx: u32 },
+    Ring(u64),
+    Strip(u64),
+}
+
+/// Events associated with a specific tool. See [`crate::events::ToolEvent`] for semantics.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ToolEvent {
+    Added,
+    Removed,
+    In {
+        tablet: u64,
+    },
+    Down,
+    Button {
+        button_id: u32,
+        pressed: bool,
+    },
+    Pose(Pose),
+    /// Only for tools that [report out of proximity](crate::tool::Tool::reports_out_of_proximity), and only while out.
+    Tracking(Pose),
+    Extras(ExtraAxes),
+    /// Only passed on if [`Builder::report_raw_axes`](crate::Builder::report_raw_axes) is set.
+    RawPose(Box<RawPose>),
+    Frame(Option<FrameTimestamp>),
+    Up,
+    Out,
+}
+/// Events associated with a specific pad. See [`crate::events::PadEvent`] for semantics.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum PadEvent {
+    Added,
+    Removed,
+    Group { group: u64, event: PadGroupEvent },
+    Button { button_idx: u32, pressed: bool },
+    Enter { tablet: u64 },
+    Exit,
+}
+/// Events associated with a specific group within a pad. See [`crate::events::PadGroupEvent`] for semantics.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum PadGroupEvent {
+    Ring { ring: u64, event: TouchStripEvent },
+    Strip { strip: u64, event: TouchStripEvent },
+    Dial { dial: u64, event: DialEvent },
+    Mode(u32),
+}
+/// Enum over all possible event sources, referring to devices by their `u64` IDs.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Event {
+    Tool {
+        tool: u64,
+        event: ToolEvent,
+    },
+    Tablet {
+        tablet: u64,
+        event: TabletEvent,
+    },
+    Pad {
+        pad: u64,
+        event: PadEvent,
+    },
+    /// See [`crate::events::Event::EnumerationComplete`]. Should be pushed at most once.
+    EnumerationComplete,
+    /// See [`crate::events::Event::WindowFocus`].
+    WindowFocus {
+        focused: bool,
+    },
+    /// See [`crate::events::Event::Touch`]. Dropped by the manager unless touch was asked for with
+    /// [`Builder::capture_touch`](crate::Builder::capture_touch), so there's no need to check.
+    Touch(TouchEvent),
+    /// See [`crate::events::Event::System`].
+    System(crate::events::SystemEvent),
+}
+
+/// Create a timestamp from a duration since some arbitrary epoch of the backend's choosing.
+/// The epoch must remain consistent for the lifetime of the backend.
+#[must_use]
+pub fn timestamp(since_epoch: std::time::Duration) -> FrameTimestamp {
+    FrameTimestamp::hardware(since_epoch)
+}
+
+/// Refer to a finger by an ID of the backend's choosing, unique amongst the fingers currently down.
+#[must_use]
+pub fn contact(id: u64) -> ContactID {
+    ContactID(id)
+}
+/// Create a finger with the given contact ID and position. Fill out the public fields as needed.
+#[must_use]
+pub fn finger(contact: u64, position: [f32; 2]) -> Finger {
+    Finger {
+        contact: ContactID(contact),
+        position,
+        contact_size: None,
+        window: None,
+    }
+}
+
+/// Create a tool with the given ID and no further info. Fill out the public fields as needed.
+#[must_use]
+pub fn tool(id: u64) -> Tool {
+    Tool {
+        internal_id: id.into(),
+        name: None,
+        hardware_id: None,
+        wacom_id: None,
+        tool_type: None,
+        axes: crate::axis::FullInfo::default(),
+        buttons: Vec::new(),
+        button_count: None,
+        reports_out_of_proximity: false,
+        tip_threshold: None,
+    }
+}
+/// Describe a button for [`Tool::buttons`], with the same `id` as used by [`ToolEvent::Button`]. Its
+/// [`role`](crate::tool::ButtonInfo::role) starts out unknown, fill it in if you know better.
+#[must_use]
+pub fn button(id: u32, name: Option<String>) -> crate::tool::ButtonInfo {
+    let id = crate::tool::ButtonID(crate::platform::custom::ButtonID(id).into());
+    crate::tool::ButtonInfo {
+        id,
+        name,
+        role: crate::tool::ButtonRole::Unknown(id),
+    }
+}
+/// Create a tablet with the given ID and no further info. Fill out the public fields as needed.
+#[must_use]
+pub fn tablet(id: u64) -> Tablet {
+    Tablet {
+        internal_id: id.into(),
+        name: None,
+        usb_id: None,
+        report_rate: None,
+        rotation: None,
+        physical_size: None,
+        resolution: None,
+    }
+}
+/// Create a pad with the given ID and no buttons or groups. Fill out the public fields as needed,
+/// keeping in mind that a pad should always have at least one group.
+#[must_use]
+pub fn pad(id: u64) -> Pad {
+    Pad {
+        internal_id: id.into(),
+        total_buttons: 0,
+        groups: Vec::new(),
+        tablet: None,
+    }
+}
+/// Set the tablet the pad belongs to, as reported by [`Pad::tablet`]. This should be kept in sync with the
+/// [`Enter`](PadEvent::Enter) and [`Exit`](PadEvent::Exit) events reported for the pad.
+pub fn set_pad_tablet(pad: &mut Pad, tablet: Option<u64>) {
+    pad.tablet = tablet.map(Into::into);
+}
+/// Create a pad group with the given ID and no members. Fill out the public fields as needed.
+#[must_use]
+pub fn group(id: u64) -> Group {
+    Group {
+        internal_id: id.into(),
+        mode_count: None,
+        mode_indicators: None,
+        buttons: Vec::new(),
+        rings: Vec::new(),
+        strips: Vec::new(),
+        dials: Vec::new(),
+        feedback: None,
+    }
+}
+/// Create a ring with the given ID, which is the `index_in_group`th ring of its group
+/// (see [`Ring::index_in_group`]). Fill out the public fields as needed.
+#[must_use]
+pub fn ring(id: u64, index_in_group: usize) -> Ring {
+    Ring {
+        internal_id: id.into(),
+        granularity: None,
+        index_in_group,
+    }
+}
+/// Create a strip with the given ID, which is the `index_in_group`th strip of its group
+/// (see [`Strip::index_in_group`]). Fill out the public fields as needed.
+#[must_use]
+pub fn strip(id: u64, index_in_group: usize) -> Strip {
+    Strip {
+        internal_id: id.into(),
+        granularity: None,
+        index_in_group,
+    }
+}
+/// Create a dial with the given ID, which is the `index_in_group`th dial of its group
+/// (see [`Dial::index_in_group`]).
+#[must_use]
+pub fn dial(id: u64, index_in_group: usize) -> Dial {
+    Dial {
+        internal_id: id.into(),
+        index_in_group,
+    }
+}
@@ -0,0 +1,105 @@
+//! Dropping of frames which repeat the last pose verbatim, as some drivers spam while the tool is held still.
+//! See [`Builder::suppress_duplicate_poses`](crate::builder::Builder::suppress_duplicate_poses).
+
+use crate::{
+    axis::Pose,
+    events::raw::{Event as RawEvent, PoseFrame, ToolEvent as RawTool},
+    platform::InternalID,
+};
+
+/// Even a perfectly still tool gets a frame through this often, so clients waiting on motion to e.g. time out a
+/// hover preview needn't special-case a quiet stream.
+const KEEPALIVE: std::time::Duration = std::time::Duration::from_secs(1);
+
+#[derive(Default)]
+pub(crate) struct Dedup {
+    /// The last pose handed out for each tool, and when.
+    last: std::collections::HashMap<InternalID, (Pose, std::time::Instant)>,
+    /// Each tool's frame in progress. Those with anything besides a pose are kept regardless.
+    frames: std::collections::HashMap<InternalID, PoseFrame>,
+}
+impl Dedup {
+    /// Filter newly ingested events, `events[from..]`. Frames are judged as they end, which under
+    /// [`Manager::pump_budgeted`](crate::Manager::pump_budgeted) may be a later batch of the same pump than their
+    /// pose, hence `events` being the whole lot.
+    ///
+    /// Returns the indices of the events removed, ascending, for anyone else holding indices into `events`.
+    pub(crate) fn process(
+        &mut self,
+        events: &mut Vec<RawEvent<InternalID>>,
+        from: usize,
+        now: std::time::Instant,
+    ) -> Vec<usize> {
+        // Frames left open by the last pump had their beginnings handed out already, so they're kept.
+        if from == 0 {
+            for frame in self.frames.values_mut() {
+                *frame = PoseFrame {
+                    other: true,
+                    ..PoseFrame::default()
+                };
+            }
+        }
+        let mut removed = Vec::new();
+        for idx in from..events.len() {
+            let RawEvent::Tool { tool, event } = &events[idx] else {
+                continue;
+            };
+            if let RawTool::Removed = event {
+                self.frames.remove(tool);
+                self.last.remove(tool);
+                continue;
+            }
+            let Some(frame) = self
+                .frames
+                .entry(tool.clone())
+                .or_default()
+                .push(idx, event)
+            else {
+                continue;
+            };
+            self.frames.remove(tool);
+            let Some(at) = frame.pose_at else {
+                continue;
+            };
+            let RawEvent::Tool {
+                event: RawTool::Pose(pose),
+                ..
+            } = events[at]
+            else {
+                continue;
+            };
+            let repeat = self
+                .last
+                .get(tool)
+                .is_some_and(|(last, when)| *last == pose && now.duration_since(*when) < KEEPALIVE);
+            if repeat && !frame.other {
+                removed.extend([Some(at), frame.raw_at, Some(idx)].into_iter().flatten());
+            } else {
+                self.last.insert(tool.clone(), (pose, now));
+            }
+        }
+        if removed.is_empty() {
+            return removed;
+        }
+        removed.sort_unstable();
+        let mut idx = 0;
+        events.retain(|_| {
+            idx += 1;
+            removed.binary_search(&(idx - 1)).is_err()
+        });
+        for frame in self.frames.values_mut() {
+            for at in [&mut frame.pose_at, &mut frame.raw_at]
+                .into_iter()
+                .flatten()
+            {
+                *at = shifted(*at, &removed);
+            }
+        }
+        removed
+    }
+}
+
+/// Where the event at `at` ends up once those at the ascending indices `removed` are taken out from before it.
+pub(crate) fn shifted(at: usize, removed: &[usize]) -> usize {
+    at - removed.partition_point(|&removed| removed < at)
+}
@@ -0,0 +1,127 @@
+//! Glue for turning pad [rings](crate::pad::Ring) and [strips](crate::pad::Strip) into [`egui`](::egui) scrolling
+//! and zooming, requiring the `egui` feature.
+//!
+//! Feed every event through a [`TouchScroll`], and hand the results to egui - for `eframe` apps, this is best done from
+//! `App::raw_input_hook` with [`TouchScroll::extend_raw_input`]. Like the mouse wheel, the resulting scrolls and zooms
+//! apply to whatever the pointer is hovering.
+
+use crate::events::{Event, PadEvent, PadGroupEvent, TouchStripEvent};
+
+/// What a ring or strip is bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Binding {
+    /// Scroll up and down.
+    #[default]
+    Vertical,
+    /// Scroll left and right.
+    Horizontal,
+    /// Zoom in and out.
+    Zoom,
+    /// Leave it be.
+    None,
+}
+
+/// Configuration of a [`TouchScroll`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    pub ring: Binding,
+    /// Points scrolled per radian of clockwise rotation, or the natural log of the zoom factor per radian when bound
+    /// to [`Binding::Zoom`]. Negate to reverse the direction.
+    pub ring_sensitivity: f32,
+    pub strip: Binding,
+    /// Points scrolled per length of the strip towards its bottom/right end, or the natural log of the zoom factor per
+    /// length when bound to [`Binding::Zoom`]. Negate to reverse the direction.
+    pub strip_sensitivity: f32,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            ring: Binding::Vertical,
+            // Clockwise scrolls down, like most mouse wheels. A full turn is a decent chunk of a page.
+            ring_sensitivity: -100.0,
+            strip: Binding::Vertical,
+            // Dragging along the strip drags the content along with it, like a touchscreen.
+            strip_sensitivity: 400.0,
+        }
+    }
+}
+
+/// Tracks ongoing ring and strip interactions, translating their movement into egui events.
+#[derive(Default)]
+pub struct TouchScroll {
+    pub config: Config,
+    rings: std::collections::HashMap<crate::pad::ring::ID, f32>,
+    strips: std::collections::HashMap<crate::pad::strip::ID, f32>,
+}
+impl TouchScroll {
+    #[must_use]
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+    /// Observe an event, returning the egui event it amounts to, if any.
+    pub fn translate(&mut self, event: &Event<'_>) -> Option<::egui::Event> {
+        let Event::Pad {
+            event: PadEvent::Group { event, .. },
+            ..
+        } = event
+        else {
+            return None;
+        };
+        let (binding, delta) = match event {
+            PadGroupEvent::Ring { ring, event } => {
+                let delta = Self::delta(&mut self.rings, ring.id(), event, |from, to| {
+                    // The short way around, rather than the long way across the zero point.
+                    (to - from + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+                        - std::f32::consts::PI
+                })?;
+                (self.config.ring, delta * self.config.ring_sensitivity)
+            }
+            PadGroupEvent::Strip { strip, event } => {
+                let delta = Self::delta(&mut self.strips, strip.id(), event, |from, to| to - from)?;
+                (self.config.strip, delta * self.config.strip_sensitivity)
+            }
+            _ => return None,
+        };
+        match binding {
+            Binding::Vertical => Some(::egui::Event::Scroll(::egui::vec2(0.0, delta))),
+            Binding::Horizontal => Some(::egui::Event::Scroll(::egui::vec2(delta, 0.0))),
+            Binding::Zoom => Some(::egui::Event::Zoom(delta.exp())),
+            Binding::None => None,
+        }
+    }
+    /// Translate every event, appending the results to the input egui is about to process.
+    pub fn extend_raw_input<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = Event<'a>>,
+        raw_input: &mut ::egui::RawInput,
+    ) {
+        raw_input.events.extend(
+            events
+                .into_iter()
+                .filter_map(|event| self.translate(&event)),
+        );
+    }
+    /// Movement since the last pose of this interaction, `None` if there's nothing to move by.
+    fn delta<Id: std::hash::Hash + Eq>(
+        last: &mut std::collections::HashMap<Id, f32>,
+        id: Id,
+        event: &TouchStripEvent,
+        difference: impl FnOnce(f32, f32) -> f32,
+    ) -> Option<f32> {
+        match *event {
+            TouchStripEvent::Pose(pose) => last
+                .insert(id, pose)
+                .map(|from| difference(from, pose))
+                .filter(|delta| *delta != 0.0),
+            // Interaction over, the next pose is a fresh start.
+            TouchStripEvent::Up => {
+                last.remove(&id);
+                None
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! A lightweight dispatcher, for when several independent components each only care about a small slice of the
+//! event stream.
+//!
+//! Components [subscribe](Bus::subscribe) to [`Topic`]s, and the host app forwards each pump to the [`Bus`] once.
+//! Each event is then handed only to the subscribers whose topic matches it, in the order they subscribed.
+//!
+//! ```no_run
+//! # fn plugins(manager: &mut octotablet::Manager) {
+//! use octotablet::events::bus::{Bus, Kind, Topic};
+//!
+//! let mut bus = Bus::new();
+//! bus.subscribe(Topic::tools().kind(Kind::Pose), |event| println!("Moved! {event:?}"));
+//! bus.subscribe(Topic::pads().kind(Kind::Button), |event| println!("Clicked! {event:?}"));
+//!
+//! loop {
+//!     if let Ok(events) = manager.pump() {
+//!         bus.dispatch(events);
+//!     }
+//! }
+//! # }
+//! ```
+
+use super::{Event, PadEvent, ToolEvent};
+use crate::{pad, tablet, tool};
+
+/// Which devices a [`Topic`] is concerned with.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Device {
+    /// Every event, including those not associated with any device.
+    #[default]
+    Any,
+    /// Tool events, optionally from only the one tool.
+    Tool(Option<tool::ID>),
+    /// Tablet events, optionally from only the one tablet.
+    Tablet(Option<tablet::ID>),
+    /// Pad events, optionally from only the one pad.
+    Pad(Option<pad::ID>),
+}
+
+/// The kind of an event, regardless of the device it came from or the data it carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Kind {
+    /// [`ToolEvent::Added`], [`TabletEvent::Added`](super::TabletEvent::Added), or [`PadEvent::Added`].
+    Added,
+    /// [`ToolEvent::Removed`], [`TabletEvent::Removed`](super::TabletEvent::Removed), or [`PadEvent::Removed`].
+    Removed,
+    /// [`ToolEvent::In`] or [`PadEvent::Enter`].
+    In,
+    /// [`ToolEvent::Out`] or [`PadEvent::Exit`].
+    Out,
+    /// [`ToolEvent::Down`].
+    Down,
+    /// [`ToolEvent::Up`].
+    Up,
+    /// [`ToolEvent::Button`] or [`PadEvent::Button`].
+    Button,
+    /// [`ToolEvent::Pose`], [`ToolEvent::Tracking`], [`ToolEvent::Extras`], or [`ToolEvent::RawPose`].
+    Pose,
+    /// [`ToolEvent::Frame`].
+    Frame,
+    /// [`ToolEvent::TimedOut`].
+    TimedOut,
+    /// [`PadEvent::Group`], covering rings, strips, and mode changes.
+    Group,
+    /// [`Event::EnumerationComplete`].
+    EnumerationComplete,
+    /// [`Event::WindowFocus`].
+    WindowFocus,
+    /// [`Event::PointerLock`].
+    PointerLock,
+    /// [`Event::Touch`].
+    Touch,
+    /// [`Event::System`].
+    System,
+}
+impl Kind {
+    /// The kind of the given event.
+    #[must_use]
+    pub fn of(event: &Event<'_>) -> Self {
+        match event {
+            Event::Tool { event, .. } => match event {
+                ToolEvent::Added => Self::Added,
+                ToolEvent::Removed => Self::Removed,
+                ToolEvent::In { .. } => Self::In,
+                ToolEvent::Out => Self::Out,
+                ToolEvent::TimedOut => Self::TimedOut,
+                ToolEvent::Down => Self::Down,
+                ToolEvent::Up => Self::Up,
+                ToolEvent::Button { .. } => Self::Button,
+                ToolEvent::Pose(_)
+                | ToolEvent::Tracking(_)
+                | ToolEvent::Extras(_)
+                | ToolEvent::RawPose(_) => Self::Pose,
+                ToolEvent::Frame(_) => Self::Frame,
+            },
+            Event::Tablet { event, .. } => match event {
+                super::TabletEvent::Added => Self::Added,
+                super::TabletEvent::Removed => Self::Removed,
+            },
+            Event::Pad { event, .. } => match event {
+                PadEvent::Added => Self::Added,
+                PadEvent::Removed => Self::Removed,
+                PadEvent::Group { .. } => Self::Group,
+                PadEvent::Button { .. } => Self::Button,
+                PadEvent::Enter { .. } => Self::In,
+                PadEvent::Exit => Self::Out,
+            },
+            Event::EnumerationComplete => Self::EnumerationComplete,
+            Event::WindowFocus { .. } => Self::WindowFocus,
+            Event::PointerLock { .. } => Self::PointerLock,
+            Event::Touch(_) => Self::Touch,
+            Event::System(_) => Self::System,
+        }
+    }
+}
+
+/// A filter over events, by the device they're from and their [`Kind`].
+///
+/// Start from one of the constructors and narrow it down from there. The default topic matches everything.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Topic {
+    pub device: Device,
+    /// Only events of this kind, or every kind if `None`.
+    pub kind: Option<Kind>,
+}
+impl Topic {
+    /// Every event.
+    #[must_use]
+    pub fn any() -> Self {
+        Self::default()
+    }
+    /// Events from any tool.
+    #[must_use]
+    pub fn tools() -> Self {
+        Self {
+            device: Device::Tool(None),
+            kind: None,
+        }
+    }
+    /// Events from the given tool only.
+    #[must_use]
+    pub fn tool(id: tool::ID) -> Self {
+        Self {
+            device: Device::Tool(Some(id)),
+            kind: None,
+        }
+    }
+    /// Events from any tablet.
+    #[must_use]
+    pub fn tablets() -> Self {
+        Self {
+            device: Device::Tablet(None),
+            kind: None,
+        }
+    }
+    /// Events from the given tablet only.
+    #[must_use]
+    pub fn tablet(id: tablet::ID) -> Self {
+        Self {
+            device: Device::Tablet(Some(id)),
+            kind: None,
+        }
+    }
+    /// Events from any pad.
+    #[must_use]
+    pub fn pads() -> Self {
+        Self {
+            device: Device::Pad(None),
+            kind: None,
+        }
+    }
+    /// Events from the given pad only.
+    #[must_use]
+    pub fn pad(id: pad::ID) -> Self {
+        Self {
+            device: Device::Pad(Some(id)),
+            kind: None,
+        }
+    }
+    /// Narrow the topic down to a single kind of event.
+    #[must_use]
+    pub fn kind(self, kind: Kind) -> Self {
+        Self {
+            kind: Some(kind),
+            ..self
+        }
+    }
+    /// Whether the event falls under this topic.
+    #[must_use]
+    pub fn matches(&self, event: &Event<'_>) -> bool {
+        let device = match (&self.device, event) {
+            (Device::Any, _)
+            | (Device::Tool(None), Event::Tool { .. })
+            | (Device::Tablet(None), Event::Tablet { .. })
+            | (Device::Pad(None), Event::Pad { .. }) => true,
+            (Device::Tool(Some(id)), Event::Tool { tool, .. }) => id.0 == tool.internal_id,
+            (Device::Tablet(Some(id)), Event::Tablet { tablet, .. }) => id.0 == tablet.internal_id,
+            (Device::Pad(Some(id)), Event::Pad { pad, .. }) => id.0 == pad.internal_id,
+            _ => false,
+        };
+        device && self.kind.is_none_or(|kind| Kind::of(event) == kind)
+    }
+}
+
+/// Handle to a subscription, for later [unsubscribing](Bus::unsubscribe).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Subscription(u64);
+
+type Callback<'a> = Box<dyn for<'e> FnMut(Event<'e>) + 'a>;
+
+/// Routes events to the subscribers interested in them. See the [module level docs](self).
+#[derive(Default)]
+pub struct Bus<'a> {
+    subscribers: Vec<(Subscription, Topic, Callback<'a>)>,
+    next: u64,
+}
+impl<'a> Bus<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Call `callback` with every future event matching `topic`.
+    pub fn subscribe(
+        &mut self,
+        topic: Topic,
+        callback: impl for<'e> FnMut(Event<'e>) + 'a,
+    ) -> Subscription {
+        let subscription = Subscription(self.next);
+        self.next += 1;
+        self.subscribers
+            .push((subscription, topic, Box::new(callback)));
+        subscription
+    }
+    /// Stop calling a subscriber. Returns false if it was already unsubscribed.
+    pub fn unsubscribe(&mut self, subscription: Subscription) -> bool {
+        let before = self.subscribers.len();
+        self.subscribers.retain(|(s, ..)| *s != subscription);
+        self.subscribers.len() != before
+    }
+    /// Whether there are no subscribers at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+    /// Hand each event to the interested subscribers. Typically, call this once with the result of every
+    /// [`Manager::pump`](crate::Manager::pump).
+    pub fn dispatch<'e>(&mut self, events: impl IntoIterator<Item = Event<'e>>) {
+        for event in events {
+            for (_, topic, callback) in &mut self.subscribers {
+                if topic.matches(&event) {
+                    callback(event);
+                }
+            }
+        }
+    }
+}
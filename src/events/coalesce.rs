@@ -0,0 +1,95 @@
+//! Thinning of the event stream to the latest pose per tool, see [`Events::coalesced`].
+
+use super::{raw, Devices, Event, Events};
+use crate::InternalID;
+
+/// A tool and the state of its frames so far.
+struct ToolState<'a> {
+    tool: &'a InternalID,
+    frame: raw::PoseFrame,
+    /// The last frame and the index of its frame event, if it had nothing but a pose. Superseded by the next such
+    /// frame, unless anything else happens to the tool in between.
+    superseded_by_next: Option<(raw::PoseFrame, usize)>,
+}
+
+/// Work out which events to keep, as a mask parallel to `events`.
+fn keep_mask(events: &[raw::Event<InternalID>]) -> Vec<bool> {
+    let mut keep = vec![true; events.len()];
+    // A handful of tools at most, not worth a map.
+    let mut tools: Vec<ToolState<'_>> = Vec::new();
+    for (idx, event) in events.iter().enumerate() {
+        let raw::Event::Tool { tool, event } = event else {
+            continue;
+        };
+        let state = if let Some(pos) = tools.iter().position(|state| state.tool == tool) {
+            &mut tools[pos]
+        } else {
+            tools.push(ToolState {
+                tool,
+                frame: raw::PoseFrame::default(),
+                superseded_by_next: None,
+            });
+            tools.last_mut().unwrap()
+        };
+        match state.frame.push(idx, event) {
+            Some(frame) if frame.pose_at.is_some() && !frame.other => {
+                if let Some((superseded, frame_at)) = state.superseded_by_next.replace((frame, idx))
+                {
+                    for at in [superseded.pose_at, superseded.raw_at, Some(frame_at)]
+                        .into_iter()
+                        .flatten()
+                    {
+                        keep[at] = false;
+                    }
+                }
+            }
+            // Buttons, transitions, and the like are kept, as is the pose right before them.
+            Some(_) => state.superseded_by_next = None,
+            None if state.frame.other => state.superseded_by_next = None,
+            None => (),
+        }
+    }
+    keep
+}
+
+/// Iterator over [`Events::coalesced`].
+pub struct CoalescedIterator<'a> {
+    devices: Devices<'a>,
+    raw: std::slice::Iter<'a, raw::Event<InternalID>>,
+    keep: std::vec::IntoIter<bool>,
+}
+impl<'a> Iterator for CoalescedIterator<'a> {
+    type Item = Event<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (event, keep) = self.raw.next().zip(self.keep.next())?;
+            if !keep {
+                continue;
+            }
+            if let Ok(event) = self.devices.resolve(event) {
+                return Some(event);
+            }
+        }
+    }
+}
+
+impl<'manager> Events<'manager> {
+    /// Iterate over these events with tool motion thinned down to the latest pose, for consumers that only care
+    /// where the tool is now rather than every sample along the way.
+    ///
+    /// A tool's frames consisting of nothing but a [`Pose`](super::ToolEvent::Pose) are dropped if a later such frame
+    /// of the same tool follows in this pump, with nothing else happening to the tool in between. Everything else is
+    /// kept in order - including the pose right before e.g. a button press or [`Down`](super::ToolEvent::Down), so
+    /// those still happen where the user would expect.
+    ///
+    /// Iterating `self` as usual still gives every sample.
+    #[must_use]
+    pub fn coalesced(self) -> CoalescedIterator<'manager> {
+        let events = &self.manager.events;
+        CoalescedIterator {
+            devices: Devices::of(self.manager),
+            raw: events.iter(),
+            keep: keep_mask(events).into_iter(),
+        }
+    }
+}
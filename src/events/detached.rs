@@ -0,0 +1,119 @@
+//! Events that no longer borrow the [`Manager`](crate::Manager), see [`Events::detach`].
+
+use super::{raw, Devices, Event, Events};
+use crate::{pad::Pad, tablet::Tablet, tool::Tool};
+
+/// A snapshot of a pump's worth of events alongside the devices they refer to, made with [`Events::detach`].
+///
+/// This owns everything it needs, so the manager is free to be pumped again (or borrowed mutably elsewhere) while it
+/// sticks around - handy for buffering events until after other state has been updated. Iterate over a reference to
+/// it to get the usual [`Event`]s, referring to the devices of the snapshot rather than those of the manager.
+///
+/// Devices are as they were at the time of the snapshot, and pad [group feedback](crate::pad::Group::feedback) is
+/// not carried over. Compare by [ID](crate::tool::ID) to relate them to the manager's current devices.
+///
+/// Unlike the manager, this is `Send` and `Sync`. To share one between threads, see
+/// [`Manager::snapshot`](crate::Manager::snapshot).
+#[derive(Debug)]
+pub struct Detached {
+    tools: Vec<Tool>,
+    tablets: Vec<Tablet>,
+    pads: Vec<Pad>,
+    events: Vec<raw::Event<crate::InternalID>>,
+    pumped_at: std::time::Instant,
+}
+impl Detached {
+    pub(crate) fn of(manager: &crate::Manager) -> Self {
+        Self {
+            tools: manager.tools().to_vec(),
+            tablets: manager.tablets().to_vec(),
+            pads: manager.pads().iter().map(Pad::snapshot).collect(),
+            events: manager.events.clone(),
+            pumped_at: manager.pumped_at,
+        }
+    }
+    /// The tools as they were when detached. See [`Manager::tools`](crate::Manager::tools).
+    #[must_use]
+    pub fn tools(&self) -> &[Tool] {
+        &self.tools
+    }
+    /// The tablets as they were when detached. See [`Manager::tablets`](crate::Manager::tablets).
+    #[must_use]
+    pub fn tablets(&self) -> &[Tablet] {
+        &self.tablets
+    }
+    /// The pads as they were when detached. See [`Manager::pads`](crate::Manager::pads).
+    #[must_use]
+    pub fn pads(&self) -> &[Pad] {
+        &self.pads
+    }
+    /// See [`Events::pumped_at`].
+    #[must_use]
+    pub fn pumped_at(&self) -> std::time::Instant {
+        self.pumped_at
+    }
+    /// Look up a tool of this snapshot by ID.
+    #[must_use]
+    pub fn tool(&self, id: &crate::tool::ID) -> Option<&Tool> {
+        self.tools.iter().find(|tool| tool.internal_id == id.0)
+    }
+    /// Look up a tablet of this snapshot by ID.
+    #[must_use]
+    pub fn tablet(&self, id: &crate::tablet::ID) -> Option<&Tablet> {
+        self.tablets
+            .iter()
+            .find(|tablet| tablet.internal_id == id.0)
+    }
+    /// Look up a pad of this snapshot by ID.
+    #[must_use]
+    pub fn pad(&self, id: &crate::pad::ID) -> Option<&Pad> {
+        self.pads.iter().find(|pad| pad.internal_id == id.0)
+    }
+    /// Returns true if there are no events at all, without the need to iterate.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+    /// Iterate over the events, referring to this snapshot's devices.
+    #[must_use]
+    pub fn iter(&self) -> DetachedIterator<'_> {
+        self.into_iter()
+    }
+}
+impl<'a> IntoIterator for &'a Detached {
+    type IntoIter = DetachedIterator<'a>;
+    type Item = Event<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        DetachedIterator {
+            devices: Devices {
+                tools: &self.tools,
+                tablets: &self.tablets,
+                pads: &self.pads,
+            },
+            raw: self.events.iter(),
+        }
+    }
+}
+
+pub struct DetachedIterator<'a> {
+    devices: Devices<'a>,
+    raw: std::slice::Iter<'a, raw::Event<crate::InternalID>>,
+}
+impl<'a> Iterator for DetachedIterator<'a> {
+    type Item = Event<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.devices.next(&mut self.raw)
+    }
+}
+
+impl Events<'_> {
+    /// Copy these events and the devices they refer to into a [`Detached`] snapshot, which no longer borrows the
+    /// manager.
+    ///
+    /// This clones every device, which is cheap but not free. If only the events are needed,
+    /// [`Event::to_owned`] is lighter.
+    #[must_use]
+    pub fn detach(self) -> Detached {
+        Detached::of(self.manager)
+    }
+}
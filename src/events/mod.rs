@@ -1,16 +1,39 @@
 //! Sequential information about interactions.
+//!
+//! All of the event enums are `#[non_exhaustive]`, and new kinds of events will be added over time without
+//! that being considered a breaking change. Ignoring events you don't recognize is always a safe choice.
 
+pub mod bus;
+mod coalesce;
+mod detached;
+mod owned;
 pub(crate) mod raw;
 
-use crate::{axis::Pose, pad, platform::PlatformImpl, tablet::Tablet, tool::Tool, Manager};
+pub use coalesce::CoalescedIterator;
+pub use detached::{Detached, DetachedIterator};
+pub use owned::{OwnedEvent, OwnedPadEvent, OwnedPadGroupEvent, OwnedToolEvent};
+
+use crate::{
+    axis::{AvailableAxes, Pose},
+    pad,
+    tablet::Tablet,
+    tool::Tool,
+    Manager,
+};
 
 /// An opaque, monotonic timestamp with unspecified epoch.
 /// The precision of this is given by [`crate::Manager::timestamp_granularity`].
 ///
 /// Subtract two timestamps to get the duration between them, with [`FrameTimestamp::epoch`]
 /// being the somewhat-meaningless starting point.
+///
+/// Timestamps may be [synthetic](FrameTimestamp::is_synthetic), in which case they do not share an epoch with
+/// hardware timestamps and the two should not be compared.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub struct FrameTimestamp(pub(crate) std::time::Duration);
+pub struct FrameTimestamp {
+    time: std::time::Duration,
+    synthetic: bool,
+}
 impl FrameTimestamp {
     /// Get the epoch. This is only useful to subtract from other timestamps.
     ///
@@ -18,13 +41,34 @@ impl FrameTimestamp {
     /// it's not to be relied on and is consistent only for comparisons.
     #[must_use]
     pub fn epoch() -> Self {
-        Self(std::time::Duration::ZERO)
+        Self::hardware(std::time::Duration::ZERO)
+    }
+    /// A timestamp reported by the platform.
+    pub(crate) const fn hardware(time: std::time::Duration) -> Self {
+        Self {
+            time,
+            synthetic: false,
+        }
+    }
+    /// A timestamp made up by us, on the [`Manager`]'s own clock.
+    pub(crate) const fn synthetic(time: std::time::Duration) -> Self {
+        Self {
+            time,
+            synthetic: true,
+        }
+    }
+    /// Whether this timestamp was made up from the time the event was received rather than reported by the platform,
+    /// see [`crate::Builder::synthesize_timestamps`]. Being a receive time, it is much less precise than hardware
+    /// timestamps - several frames received in the same pump will share the same time.
+    #[must_use]
+    pub fn is_synthetic(self) -> bool {
+        self.synthetic
     }
 }
 impl std::ops::Sub for FrameTimestamp {
     type Output = std::time::Duration;
     fn sub(self, rhs: Self) -> Self::Output {
-        self.0 - rhs.0
+        self.time - rhs.time
     }
 }
 
@@ -50,9 +94,13 @@ impl std::ops::Sub for FrameTimestamp {
 /// -Removed
 /// </pre>
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum ToolEvent<'a> {
     /// The tool is new. May be enumerated at the start of the program,
     /// or sent immediately before its first use. This is not part of a `Frame`.
+    ///
+    /// Always delivered before any other event of the tool, in the same pump or an earlier one, so per-tool resources
+    /// may be set up here without racing the first stroke. Made up by this crate if the platform skips it.
     Added,
     /// The tool will no longer send events. It is undefined under what situation this occurs -
     /// it could be on `Out`, when the tablet that discovered it is disconnected, or never.
@@ -65,7 +113,13 @@ pub enum ToolEvent<'a> {
     /// Note that this is subject to filtering by the OS -
     /// you may or may not recieve this event when the pen enters sensing range
     /// above a different window.
-    In { tablet: &'a Tablet },
+    ///
+    /// Every event of the tool up until it's [`Out`](ToolEvent::Out) again is for the given window, if known. See
+    /// [`Manager::add_window`](crate::Manager::add_window).
+    In {
+        tablet: &'a Tablet,
+        window: Option<crate::WindowId>,
+    },
     /// The tool is considered "pressed." It is implementation defined what the exact semantics are,
     /// but you should treat this as a click or command to start drawing.
     ///
@@ -81,15 +135,38 @@ pub enum ToolEvent<'a> {
     // This single variant is so much larger than all the others and inflates the whole
     // event enum by over 2x D:
     Pose(Pose),
+    /// A snapshot of the axes of a tool that's *not* in proximity, only from tools which
+    /// [report out of proximity](crate::tool::Tool::reports_out_of_proximity). Part of a frame, like `Pose`.
+    ///
+    /// Where the tool is matters, but clients treating the tool as hovering (drawing a brush preview, say) should
+    /// ignore these.
+    Tracking(Pose),
+    /// Values of axes not modeled by [`Pose`], as part of the same frame as the pose. Only sent if there's any.
+    /// See [`ExtraAxes`](crate::axis::ExtraAxes) for caveats.
+    ///
+    /// # Platform support
+    /// * Windows Ink - The standard packet properties without a home in `Pose`, such as azimuth, altitude, and
+    ///   tangent pressure, keyed by [GUID](crate::axis::ExtraAxisKey::Guid).
+    /// * Wayland - Never, the protocol has no axes beyond those modeled.
+    /// * Custom backends - Whatever the backend sends.
+    Extras(&'a crate::axis::ExtraAxes),
+    /// The platform's unprocessed values behind the frame's pose, as part of the same frame. Only sent if enabled
+    /// by [`Builder::report_raw_axes`](crate::Builder::report_raw_axes). See [`RawPose`](crate::axis::RawPose).
+    RawPose(&'a crate::axis::RawPose),
     /// The preceding events are submitted as a group, at the given time.
     Frame(Option<FrameTimestamp>),
     /// The tool is no longer pressed.
     Up,
     /// The tool has left sensing range or left the window region of the tablet.
     Out,
+    /// The tool was down but went silent for longer than [`Builder::stuck_down_timeout`](crate::Builder::stuck_down_timeout),
+    /// so the [`Up`](Self::Up) and [`Out`](Self::Out) that follow in this frame were made up rather than reported.
+    /// Further events from the tool are ignored until it genuinely leaves proximity.
+    TimedOut,
 }
 /// Events associated with a specific [`Tablet`].
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum TabletEvent {
     /// The tablet is new. May be enumerated at the start of the program,
     /// may be newly plugged in, or sent immediately before its first use.
@@ -99,6 +176,7 @@ pub enum TabletEvent {
 }
 /// Events associated with a specific [`Pad`](pad::Pad).
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum PadEvent<'a> {
     /// The pad is new. May be enumerated at the start of the program,
     /// may be newly plugged in, or sent immediately before its first use.
@@ -127,6 +205,7 @@ pub enum PadEvent<'a> {
 }
 /// Events associated with a specific [`Group`](pad::Group) within a larger [`Pad`](pad::Pad).
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum PadGroupEvent<'a> {
     /// A ring was interacted.
     Ring {
@@ -140,6 +219,11 @@ pub enum PadGroupEvent<'a> {
         /// Contains the absolute position, when changed.
         event: TouchStripEvent,
     },
+    /// A dial was turned.
+    Dial {
+        dial: &'a pad::Dial,
+        event: DialEvent,
+    },
     /// The mode layer was changed to the given mode, zero-indexed. Modes are to be interpreted on a per-group basis, not per-pad.
     ///
     /// You may want to use this to re-interpret meaning to all members of this group, in order to have
@@ -150,6 +234,7 @@ pub enum PadGroupEvent<'a> {
 }
 /// Events for actions on a touch sensitive linear strip or circular ring.
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum TouchStripEvent {
     /// Single degree-of-freedom pose. Interpretation depends on the context under which this event was fired - if from a ring,
     /// this is `[0..TAU)` in radians clockwise from "logical north". If from a strip, it is `[0..1]` where 0 is "logical top or left".
@@ -165,8 +250,34 @@ pub enum TouchStripEvent {
     /// flick scrolling for example.
     Up,
 }
+/// Events for actions on a relative [dial](pad::Dial).
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum DialEvent {
+    /// The dial was turned by the given amount, in 120ths of a logical detent (as with mouse wheels), positive being
+    /// clockwise. Dials without detents may report any fraction thereof.
+    Delta(i32),
+    /// End of a frame. See [`ToolEvent`] for a description of frames. This timestamp is not necessarily
+    /// coordinated with other types of `Frame`.
+    Frame(Option<FrameTimestamp>),
+}
+/// Changes to the system at large, affecting how other events are to be interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SystemEvent {
+    /// The scale from the platform's units to [`Pose::position`](crate::axis::Pose::position) changed, such as when
+    /// the window moved to a monitor of a different DPI. Positions from here on already account for it, but
+    /// anything derived from earlier ones (cached strokes, hit-test regions, and the like) is now stale.
+    ///
+    /// # Platform support
+    /// * Wayland - never, as positions are in logical pixels regardless of the output's scale.
+    /// * Windows Ink - upon the next pump after the window's DPI changes.
+    /// * Custom backends report this whenever they choose to, if at all.
+    ScaleFactorChanged,
+}
 /// Enum over all possible event sources.
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum Event<'a> {
     Tool {
         tool: &'a Tool,
@@ -180,6 +291,150 @@ pub enum Event<'a> {
         pad: &'a pad::Pad,
         event: PadEvent<'a>,
     },
+    /// The initial burst of devices has been fully reported, such that the hardware reports reflect every device
+    /// that was connected at the time the [`Manager`] was built. Emitted exactly once per `Manager`.
+    ///
+    /// Devices may still come and go afterwards, this is just a signal that it's a good time to build device UI
+    /// without it immediately going stale. Some devices may only be reported upon first use regardless (notably
+    /// tools on some platforms), so don't expect this to be an exhaustive list.
+    ///
+    /// # Platform support
+    /// * Wayland reports devices asynchronously after the `Manager` is built, and this arrives once the compositor
+    ///   has finished describing them, possibly several pumps in.
+    /// * Ink reports this alongside the tablets, on the first pump after the `Manager` is built.
+    /// * Custom backends report this whenever they choose to, if at all.
+    EnumerationComplete,
+    /// The window gained or lost keyboard focus. Tool state is often reset by the platform upon focus loss, and this
+    /// arrives in order with the tool events such that it's clear which side of the change each falls on.
+    ///
+    /// The initial state is reported shortly after the [`Manager`] is built, with further events only upon change.
+    ///
+    /// # Platform support
+    /// * Wayland reports focus of *any* surface of the app, as the `Manager` is not told which one is its window.
+    /// * Ink reports whether the window (or rather the top-level window containing it) is the foreground window,
+    ///   as sampled each pump.
+    /// * Custom backends report this whenever they choose to, if at all.
+    WindowFocus { focused: bool },
+    /// The application reported the pointer to be locked or unlocked, see [`Manager::set_pointer_locked`].
+    /// Arrives in order with the tool events, such that it's clear which side of the change each falls on.
+    ///
+    /// Whether tools keep reporting while locked is up to [`Builder::pointer_lock`](crate::Builder::pointer_lock).
+    PointerLock { locked: bool },
+    /// A finger touched, moved, or lifted. Only reported if enabled with
+    /// [`Builder::capture_touch`](crate::Builder::capture_touch), see the [`touch`](crate::touch) module.
+    Touch(crate::touch::TouchEvent),
+    /// Something changed about the system. See [`SystemEvent`].
+    System(SystemEvent),
+}
+impl Event<'_> {
+    /// The window this event says a device is over, for managers reporting several, see
+    /// [`Manager::add_window`](crate::Manager::add_window).
+    ///
+    /// Windows are only named where devices arrive: a tool's [`In`](ToolEvent::In), and a finger's
+    /// [`Down`](crate::touch::TouchEvent::Down) and [`Move`](crate::touch::TouchEvent::Move). The rest of a device's
+    /// events are for the same window until it leaves, and `None` is returned for them and for events without a
+    /// window to speak of. Also `None` where the backend can't tell. To know the window of any other tool event, keep
+    /// hold of the one from its `In`.
+    #[must_use]
+    pub fn window_id(&self) -> Option<crate::WindowId> {
+        use crate::touch::TouchEvent;
+        match self {
+            Event::Tool {
+                event: ToolEvent::In { window, .. },
+                ..
+            } => *window,
+            Event::Touch(TouchEvent::Down(finger) | TouchEvent::Move(finger)) => finger.window,
+            _ => None,
+        }
+    }
+}
+
+/// Fill in missing frame timestamps with the given receive time.
+pub(crate) fn synthesize_timestamps(
+    events: &mut [raw::Event<crate::InternalID>],
+    now: std::time::Duration,
+) {
+    let now = Some(FrameTimestamp::synthetic(now));
+    for event in events {
+        match event {
+            raw::Event::Tool {
+                event: raw::ToolEvent::Frame(time @ None),
+                ..
+            }
+            | raw::Event::Pad {
+                event:
+                    raw::PadEvent::Group {
+                        event:
+                            raw::PadGroupEvent::Ring {
+                                event: TouchStripEvent::Frame(time @ None),
+                                ..
+                            }
+                            | raw::PadGroupEvent::Strip {
+                                event: TouchStripEvent::Frame(time @ None),
+                                ..
+                            }
+                            | raw::PadGroupEvent::Dial {
+                                event: DialEvent::Frame(time @ None),
+                                ..
+                            },
+                        ..
+                    },
+                ..
+            }
+            | raw::Event::Touch(crate::touch::TouchEvent::Frame(time @ None)) => *time = now,
+            _ => (),
+        }
+    }
+}
+
+/// Drop the axes the client didn't ask for from every pose, see [`Builder::axes`](crate::Builder::axes).
+pub(crate) fn retain_axes(events: &mut [raw::Event<crate::InternalID>], axes: AvailableAxes) {
+    for event in events {
+        if let raw::Event::Tool {
+            event: raw::ToolEvent::Pose(pose) | raw::ToolEvent::Tracking(pose),
+            ..
+        } = event
+        {
+            axes.retain_in(pose);
+        } else if let raw::Event::Tool {
+            event: raw::ToolEvent::RawPose(raw),
+            ..
+        } = event
+        {
+            axes.retain_in_raw(raw);
+        }
+    }
+}
+
+/// Reshape pose distances according to the given curve, within the limits of each reporting tool.
+pub(crate) fn apply_distance_curve(
+    events: &mut [raw::Event<crate::InternalID>],
+    tools: &[Tool],
+    curve: crate::axis::Curve,
+) {
+    for event in events {
+        let raw::Event::Tool {
+            tool,
+            event: raw::ToolEvent::Pose(pose),
+        } = event
+        else {
+            continue;
+        };
+        let Some(distance) = pose.distance.get() else {
+            continue;
+        };
+        let Some(limits) = tools
+            .iter()
+            .find(|t| &t.internal_id == tool)
+            .and_then(|t| t.axes.distance)
+            // Nothing advertised, but the only unitless range is `[0, 1]` anyway.
+            .map_or(Some((0.0..=1.0).into()), crate::axis::LengthInfo::limits)
+        else {
+            continue;
+        };
+        pose.distance = crate::util::NicheF32::new_some(curve.apply_within(distance, limits))
+            .unwrap_or(crate::util::NicheF32::NONE);
+    }
 }
 
 /// This struct is the primary source of realtime data.
@@ -199,6 +454,21 @@ impl<'manager> Events<'manager> {
     pub fn manager(&'_ self) -> &'manager Manager {
         self.manager
     }
+    /// The time at which these events were taken from the platform, for reasoning about how stale they are by the time
+    /// they're presented. This is as soon after the platform is dispatched as possible, so is more accurate than
+    /// timing the call to [`Manager::pump`] from the outside.
+    ///
+    /// Events left behind by [`Manager::pump_budgeted`] keep the time at which they were taken, even when handed out by
+    /// a later pump.
+    #[must_use]
+    pub fn pumped_at(&self) -> std::time::Instant {
+        self.manager.pumped_at
+    }
+    /// Returns true if there are no events at all, without the need to iterate.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.manager.events.is_empty()
+    }
 }
 impl<'manager> IntoIterator for Events<'manager> {
     type IntoIter = EventIterator<'manager>;
@@ -206,13 +476,15 @@ impl<'manager> IntoIterator for Events<'manager> {
     fn into_iter(self) -> Self::IntoIter {
         EventIterator {
             manager: self.manager,
-            raw: self.manager.internal.raw_events(),
+            devices: Devices::of(self.manager),
+            raw: self.manager.events.iter(),
         }
     }
 }
 pub struct EventIterator<'a> {
     manager: &'a Manager,
-    raw: crate::platform::RawEventsIter<'a>,
+    devices: Devices<'a>,
+    raw: std::slice::Iter<'a, raw::Event<crate::InternalID>>,
 }
 impl<'manager> EventIterator<'manager> {
     /// Get access to the `Manager` that owns these devices and events.
@@ -220,23 +492,76 @@ impl<'manager> EventIterator<'manager> {
     pub fn manager(&'_ self) -> &'manager Manager {
         self.manager
     }
-    // Get the next, or Err to retry.
+}
+
+/// The devices stored events are resolved against - either the manager's own, or those of a [`Detached`] snapshot.
+#[derive(Clone, Copy)]
+pub(crate) struct Devices<'a> {
+    pub(crate) tools: &'a [Tool],
+    pub(crate) tablets: &'a [Tablet],
+    pub(crate) pads: &'a [pad::Pad],
+}
+impl<'a> Devices<'a> {
+    fn of(manager: &'a Manager) -> Self {
+        Self {
+            tools: manager.tools(),
+            tablets: manager.tablets(),
+            pads: manager.pads(),
+        }
+    }
+    /// Resolve the next event that can be, skipping (and complaining about) any that can't.
+    pub(crate) fn next(
+        self,
+        raw: &mut std::slice::Iter<'a, raw::Event<crate::InternalID>>,
+    ) -> Option<Event<'a>> {
+        // Infinite loop safety: the inner iter is a slice iter and thus
+        // finite in size, and each attempt consumes one.
+        for next in raw {
+            if let Ok(event) = self.resolve(next) {
+                return Some(event);
+            }
+            // report impl bug.
+            #[cfg(debug_assertions)]
+            {
+                eprintln!("[octotablet] implementation bug! failed to build event, skipping");
+            }
+        }
+        None
+    }
+    /// Build the public form of a stored event, or Err if it refers to devices that can't be found.
     #[allow(clippy::too_many_lines)]
-    fn try_next(&mut self) -> Result<Option<<Self as Iterator>::Item>, ()> {
+    fn resolve(self, next: &'a raw::Event<crate::InternalID>) -> Result<Event<'a>, ()> {
         use raw::{
             Event as RawEvent, PadEvent as RawPad, TabletEvent as RawTablet, ToolEvent as RawTool,
         };
-        let Some(next) = self.raw.next() else {
-            return Ok(None);
-        };
-        Ok(Some(match next {
+        // Borrows from the event itself, handle before cloning.
+        if let RawEvent::Tool {
+            tool,
+            event: borrowed @ (RawTool::Extras(_) | RawTool::RawPose(_)),
+        } = next
+        {
+            let tool = self
+                .tools
+                .iter()
+                .find(|t| &t.internal_id == tool)
+                // Fail out (essentially a `filter` for invalid commands...)
+                .ok_or(())?;
+            return Ok(Event::Tool {
+                tool,
+                event: match borrowed {
+                    RawTool::Extras(extras) => ToolEvent::Extras(extras),
+                    RawTool::RawPose(raw) => ToolEvent::RawPose(raw),
+                    _ => return Err(()),
+                },
+            });
+        }
+        Ok(match next.clone() {
             RawEvent::Tool { tool, event } => {
                 // A linear scan is gonna be much more efficient than the alternatives
                 // for any reasonable number of tools. If you have like.... 30 tools at once, then
                 // maybe binary search would eek out a win :P
                 let tool = self
-                    .manager
-                    .tools()
+                    .tools
                     .iter()
                     .find(|t| t.internal_id == tool)
                     // Fail out (essentially a `filter` for invalid commands...)
@@ -246,13 +571,14 @@ impl<'manager> EventIterator<'manager> {
                     event: match event {
                         RawTool::Added => ToolEvent::Added,
                         RawTool::Removed => ToolEvent::Removed,
-                        RawTool::In { tablet } => ToolEvent::In {
+                        RawTool::In { tablet, window } => ToolEvent::In {
                             tablet: self
-                                .manager
-                                .tablets()
+                                .tablets
                                 .iter()
                                 .find(|t| t.internal_id == tablet)
-                                .unwrap(),
+                                // Backends should never, but it's not worth panicking over.
+                                .ok_or(())?,
+                            window,
                         },
                         RawTool::Down => ToolEvent::Down,
                         RawTool::Button { button_id, pressed } => ToolEvent::Button {
@@ -260,16 +586,19 @@ impl<'manager> EventIterator<'manager> {
                             pressed,
                         },
                         RawTool::Pose(v) => ToolEvent::Pose(v),
+                        RawTool::Tracking(v) => ToolEvent::Tracking(v),
+                        // Handled above.
+                        RawTool::Extras(_) | RawTool::RawPose(_) => return Err(()),
                         RawTool::Frame(v) => ToolEvent::Frame(v),
                         RawTool::Up => ToolEvent::Up,
                         RawTool::Out => ToolEvent::Out,
+                        RawTool::TimedOut => ToolEvent::TimedOut,
                     },
                 }
             }
             RawEvent::Tablet { tablet, event } => {
                 let tablet = self
-                    .manager
-                    .tablets()
+                    .tablets
                     .iter()
                     .find(|t| t.internal_id == tablet)
                     // Fail out (essentially a `filter` for invalid commands...)
@@ -284,8 +613,7 @@ impl<'manager> EventIterator<'manager> {
             }
             RawEvent::Pad { pad, event } => {
                 let pad = self
-                    .manager
-                    .pads()
+                    .pads
                     .iter()
                     .find(|t| t.internal_id == pad)
                     // Fail out (essentially a `filter` for invalid commands...)
@@ -323,6 +651,15 @@ impl<'manager> EventIterator<'manager> {
                                             .ok_or(())?;
                                         PadGroupEvent::Strip { strip, event }
                                     }
+                                    raw::PadGroupEvent::Dial { dial, event } => {
+                                        let dial = group
+                                            .dials
+                                            .iter()
+                                            .find(|d| d.internal_id == dial)
+                                            // Fail out (essentially a `filter` for invalid commands...)
+                                            .ok_or(())?;
+                                        PadGroupEvent::Dial { dial, event }
+                                    }
                                 },
                             }
                         }
@@ -348,8 +685,7 @@ impl<'manager> EventIterator<'manager> {
                         }
                         RawPad::Enter { tablet } => {
                             let tablet = self
-                                .manager
-                                .tablets()
+                                .tablets
                                 .iter()
                                 .find(|t| t.internal_id == tablet)
                                 // Fail out (essentially a `filter` for invalid commands...)
@@ -360,25 +696,17 @@ impl<'manager> EventIterator<'manager> {
                     },
                 }
             }
-        }))
+            RawEvent::EnumerationComplete => Event::EnumerationComplete,
+            RawEvent::WindowFocus { focused } => Event::WindowFocus { focused },
+            RawEvent::PointerLock { locked } => Event::PointerLock { locked },
+            RawEvent::Touch(touch) => Event::Touch(touch),
+            RawEvent::System(system) => Event::System(system),
+        })
     }
 }
 impl<'manager> Iterator for EventIterator<'manager> {
     type Item = Event<'manager>;
     fn next(&mut self) -> Option<Self::Item> {
-        let mut maybe_next = self.try_next();
-        // Infinite loop safety: the inner iter is a slice iter and thus
-        // finite in size. try_next always advances.
-        while maybe_next.is_err() {
-            // report impl bug.
-            #[cfg(debug_assertions)]
-            {
-                eprintln!("[octotablet] implementation bug! failed to build event, skipping");
-            }
-            maybe_next = self.try_next();
-        }
-        // While condition says it's Ok
-        // this will be Ok(None) on finish.
-        maybe_next.unwrap()
+        self.devices.next(&mut self.raw)
     }
 }
@@ -0,0 +1,312 @@
+//! Owned mirrors of the event types, referring to devices by [ID](crate::tool::ID) rather than by reference.
+
+use super::{
+    DialEvent, Event, FrameTimestamp, PadEvent, PadGroupEvent, TabletEvent, ToolEvent,
+    TouchStripEvent,
+};
+use crate::{axis::Pose, pad, tablet, tool};
+
+/// Owned version of [`ToolEvent`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum OwnedToolEvent {
+    /// See [`ToolEvent::Added`].
+    Added,
+    /// See [`ToolEvent::Removed`].
+    Removed,
+    /// See [`ToolEvent::In`].
+    In {
+        tablet: tablet::ID,
+        window: Option<crate::WindowId>,
+    },
+    /// See [`ToolEvent::Down`].
+    Down,
+    /// See [`ToolEvent::Button`].
+    Button {
+        button_id: tool::ButtonID,
+        pressed: bool,
+    },
+    /// See [`ToolEvent::Pose`].
+    Pose(Pose),
+    /// See [`ToolEvent::Tracking`].
+    Tracking(Pose),
+    /// See [`ToolEvent::Extras`].
+    Extras(crate::axis::ExtraAxes),
+    /// See [`ToolEvent::RawPose`].
+    RawPose(Box<crate::axis::RawPose>),
+    /// See [`ToolEvent::Frame`].
+    Frame(Option<FrameTimestamp>),
+    /// See [`ToolEvent::Up`].
+    Up,
+    /// See [`ToolEvent::Out`].
+    Out,
+    /// See [`ToolEvent::TimedOut`].
+    TimedOut,
+}
+/// Owned version of [`PadEvent`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum OwnedPadEvent {
+    /// See [`PadEvent::Added`].
+    Added,
+    /// See [`PadEvent::Removed`].
+    Removed,
+    /// See [`PadEvent::Group`].
+    Group {
+        group: pad::group::ID,
+        event: OwnedPadGroupEvent,
+    },
+    /// See [`PadEvent::Button`].
+    Button {
+        button_idx: u32,
+        pressed: bool,
+        group: Option<pad::group::ID>,
+    },
+    /// See [`PadEvent::Enter`].
+    Enter { tablet: tablet::ID },
+    /// See [`PadEvent::Exit`].
+    Exit,
+}
+/// Owned version of [`PadGroupEvent`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum OwnedPadGroupEvent {
+    /// See [`PadGroupEvent::Ring`].
+    Ring {
+        ring: pad::ring::ID,
+        event: TouchStripEvent,
+    },
+    /// See [`PadGroupEvent::Strip`].
+    Strip {
+        strip: pad::strip::ID,
+        event: TouchStripEvent,
+    },
+    /// See [`PadGroupEvent::Dial`].
+    Dial {
+        dial: pad::dial::ID,
+        event: DialEvent,
+    },
+    /// See [`PadGroupEvent::Mode`].
+    Mode(u32),
+}
+/// Owned version of [`Event`], made with [`Event::to_owned`]. Unlike `Event`, this can be stored past the next
+/// pump or sent to another thread.
+///
+/// Devices are referred to by ID, which may be resolved back into the device with e.g. [`Manager::tool`](crate::Manager::tool)
+/// for as long as the device is still around.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum OwnedEvent {
+    Tool {
+        tool: tool::ID,
+        event: OwnedToolEvent,
+    },
+    Tablet {
+        tablet: tablet::ID,
+        event: TabletEvent,
+    },
+    Pad {
+        pad: pad::ID,
+        event: OwnedPadEvent,
+    },
+    /// See [`Event::EnumerationComplete`].
+    EnumerationComplete,
+    /// See [`Event::WindowFocus`].
+    WindowFocus {
+        focused: bool,
+    },
+    /// See [`Event::PointerLock`].
+    PointerLock {
+        locked: bool,
+    },
+    /// See [`Event::Touch`].
+    Touch(crate::touch::TouchEvent),
+    /// See [`Event::System`].
+    System(super::SystemEvent),
+}
+// Sending these elsewhere is the whole point.
+const _: fn() = || {
+    fn assert_send<T: Send + Sync>() {}
+    assert_send::<OwnedEvent>();
+};
+
+impl Event<'_> {
+    /// Make an owned copy of this event, which no longer borrows from the [`Manager`](crate::Manager).
+    #[must_use]
+    // Deliberately shadows `ToOwned::to_owned`, which would just give back another borrowing `Event`.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_owned(&self) -> OwnedEvent {
+        match *self {
+            Event::Tool { tool, event } => OwnedEvent::Tool {
+                tool: tool.id(),
+                event: match event {
+                    ToolEvent::Added => OwnedToolEvent::Added,
+                    ToolEvent::Removed => OwnedToolEvent::Removed,
+                    ToolEvent::In { tablet, window } => OwnedToolEvent::In {
+                        tablet: tablet.id(),
+                        window,
+                    },
+                    ToolEvent::Down => OwnedToolEvent::Down,
+                    ToolEvent::Button { button_id, pressed } => {
+                        OwnedToolEvent::Button { button_id, pressed }
+                    }
+                    ToolEvent::Pose(pose) => OwnedToolEvent::Pose(pose),
+                    ToolEvent::Tracking(pose) => OwnedToolEvent::Tracking(pose),
+                    ToolEvent::Extras(extras) => OwnedToolEvent::Extras(extras.clone()),
+                    ToolEvent::RawPose(raw) => OwnedToolEvent::RawPose(Box::new(*raw)),
+                    ToolEvent::Frame(time) => OwnedToolEvent::Frame(time),
+                    ToolEvent::Up => OwnedToolEvent::Up,
+                    ToolEvent::Out => OwnedToolEvent::Out,
+                    ToolEvent::TimedOut => OwnedToolEvent::TimedOut,
+                },
+            },
+            Event::Tablet { tablet, event } => OwnedEvent::Tablet {
+                tablet: tablet.id(),
+                event,
+            },
+            Event::Pad { pad, event } => OwnedEvent::Pad {
+                pad: pad.id(),
+                event: match event {
+                    PadEvent::Added => OwnedPadEvent::Added,
+                    PadEvent::Removed => OwnedPadEvent::Removed,
+                    PadEvent::Group { group, event } => OwnedPadEvent::Group {
+                        group: group.id(),
+                        event: match event {
+                            PadGroupEvent::Ring { ring, event } => OwnedPadGroupEvent::Ring {
+                                ring: ring.id(),
+                                event,
+                            },
+                            PadGroupEvent::Strip { strip, event } => OwnedPadGroupEvent::Strip {
+                                strip: strip.id(),
+                                event,
+                            },
+                            PadGroupEvent::Dial { dial, event } => OwnedPadGroupEvent::Dial {
+                                dial: dial.id(),
+                                event,
+                            },
+                            PadGroupEvent::Mode(mode) => OwnedPadGroupEvent::Mode(mode),
+                        },
+                    },
+                    PadEvent::Button {
+                        button_idx,
+                        pressed,
+                        group,
+                    } => OwnedPadEvent::Button {
+                        button_idx,
+                        pressed,
+                        group: group.map(pad::Group::id),
+                    },
+                    PadEvent::Enter { tablet } => OwnedPadEvent::Enter {
+                        tablet: tablet.id(),
+                    },
+                    PadEvent::Exit => OwnedPadEvent::Exit,
+                },
+            },
+            Event::EnumerationComplete => OwnedEvent::EnumerationComplete,
+            Event::WindowFocus { focused } => OwnedEvent::WindowFocus { focused },
+            Event::PointerLock { locked } => OwnedEvent::PointerLock { locked },
+            Event::Touch(touch) => OwnedEvent::Touch(touch),
+            Event::System(system) => OwnedEvent::System(system),
+        }
+    }
+}
+
+impl OwnedEvent {
+    /// Straight from the stored form, without looking up any of the devices. `pads` is only needed to find which
+    /// group a pad button belongs to.
+    pub(crate) fn from_raw(raw: &super::raw::Event<crate::InternalID>, pads: &[pad::Pad]) -> Self {
+        use super::raw::{Event as RawEvent, PadEvent as RawPad, ToolEvent as RawTool};
+        match raw.clone() {
+            RawEvent::Tool { tool, event } => OwnedEvent::Tool {
+                tool: tool::ID(tool),
+                event: match event {
+                    RawTool::Added => OwnedToolEvent::Added,
+                    RawTool::Removed => OwnedToolEvent::Removed,
+                    RawTool::In { tablet, window } => OwnedToolEvent::In {
+                        tablet: tablet::ID(tablet),
+                        window,
+                    },
+                    RawTool::Down => OwnedToolEvent::Down,
+                    RawTool::Button { button_id, pressed } => OwnedToolEvent::Button {
+                        button_id: tool::ButtonID(button_id),
+                        pressed,
+                    },
+                    RawTool::Pose(pose) => OwnedToolEvent::Pose(pose),
+                    RawTool::Tracking(pose) => OwnedToolEvent::Tracking(pose),
+                    RawTool::Extras(extras) => OwnedToolEvent::Extras(extras),
+                    RawTool::RawPose(raw) => OwnedToolEvent::RawPose(raw),
+                    RawTool::Frame(time) => OwnedToolEvent::Frame(time),
+                    RawTool::Up => OwnedToolEvent::Up,
+                    RawTool::Out => OwnedToolEvent::Out,
+                    RawTool::TimedOut => OwnedToolEvent::TimedOut,
+                },
+            },
+            RawEvent::Tablet { tablet, event } => OwnedEvent::Tablet {
+                tablet: tablet::ID(tablet),
+                event: match event {
+                    super::raw::TabletEvent::Added => TabletEvent::Added,
+                    super::raw::TabletEvent::Removed => TabletEvent::Removed,
+                },
+            },
+            RawEvent::Pad { pad, event } => {
+                let event = match event {
+                    RawPad::Added => OwnedPadEvent::Added,
+                    RawPad::Removed => OwnedPadEvent::Removed,
+                    RawPad::Group { group, event } => OwnedPadEvent::Group {
+                        group: pad::group::ID(group),
+                        event: match event {
+                            super::raw::PadGroupEvent::Ring { ring, event } => {
+                                OwnedPadGroupEvent::Ring {
+                                    ring: pad::ring::ID(ring),
+                                    event,
+                                }
+                            }
+                            super::raw::PadGroupEvent::Strip { strip, event } => {
+                                OwnedPadGroupEvent::Strip {
+                                    strip: pad::strip::ID(strip),
+                                    event,
+                                }
+                            }
+                            super::raw::PadGroupEvent::Dial { dial, event } => {
+                                OwnedPadGroupEvent::Dial {
+                                    dial: pad::dial::ID(dial),
+                                    event,
+                                }
+                            }
+                            super::raw::PadGroupEvent::Mode(mode) => OwnedPadGroupEvent::Mode(mode),
+                        },
+                    },
+                    RawPad::Button {
+                        button_idx,
+                        pressed,
+                    } => OwnedPadEvent::Button {
+                        button_idx,
+                        pressed,
+                        group: pads
+                            .iter()
+                            .find(|p| p.internal_id == pad)
+                            .and_then(|pad| {
+                                pad.groups
+                                    .iter()
+                                    .find(|group| group.buttons.binary_search(&button_idx).is_ok())
+                            })
+                            .map(pad::Group::id),
+                    },
+                    RawPad::Enter { tablet } => OwnedPadEvent::Enter {
+                        tablet: tablet::ID(tablet),
+                    },
+                    RawPad::Exit => OwnedPadEvent::Exit,
+                };
+                OwnedEvent::Pad {
+                    pad: pad::ID(pad),
+                    event,
+                }
+            }
+            RawEvent::EnumerationComplete => OwnedEvent::EnumerationComplete,
+            RawEvent::WindowFocus { focused } => OwnedEvent::WindowFocus { focused },
+            RawEvent::PointerLock { locked } => OwnedEvent::PointerLock { locked },
+            RawEvent::Touch(touch) => OwnedEvent::Touch(touch),
+            RawEvent::System(system) => OwnedEvent::System(system),
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub enum ToolEvent<Id> {
     Removed,
     In {
         tablet: Id,
+        window: Option<crate::WindowId>,
     },
     Down,
     Button {
@@ -16,9 +17,14 @@ pub enum ToolEvent<Id> {
     // This variant is many times the size of all the others resulting in huge inefficiency.
     // If memory usage/throughput becomes appreciably bad, this is a good place to start.
     Pose(super::Pose),
+    Tracking(super::Pose),
+    Extras(crate::axis::ExtraAxes),
+    // Boxed, as it'd otherwise be many times the size of even `Pose`.
+    RawPose(Box<crate::axis::RawPose>),
     Frame(Option<super::FrameTimestamp>),
     Up,
     Out,
+    TimedOut,
 }
 impl<Id> ToolEvent<Id> {
     // Can't impl `From`, due to conflict with `From<T> for T` :(
@@ -26,15 +32,20 @@ impl<Id> ToolEvent<Id> {
         match self {
             Self::Added => ToolEvent::Added,
             Self::Removed => ToolEvent::Removed,
-            Self::In { tablet } => ToolEvent::In {
+            Self::In { tablet, window } => ToolEvent::In {
                 tablet: Into::from(tablet),
+                window,
             },
             Self::Down => ToolEvent::Down,
             Self::Button { button_id, pressed } => ToolEvent::Button { button_id, pressed },
             Self::Pose(v) => ToolEvent::Pose(v),
+            Self::Tracking(v) => ToolEvent::Tracking(v),
+            Self::Extras(v) => ToolEvent::Extras(v),
+            Self::RawPose(v) => ToolEvent::RawPose(v),
             Self::Frame(v) => ToolEvent::Frame(v),
             Self::Up => ToolEvent::Up,
             Self::Out => ToolEvent::Out,
+            Self::TimedOut => ToolEvent::TimedOut,
         }
     }
 }
@@ -86,6 +97,10 @@ pub enum PadGroupEvent<Id> {
         strip: Id,
         event: super::TouchStripEvent,
     },
+    Dial {
+        dial: Id,
+        event: super::DialEvent,
+    },
     Mode(u32),
 }
 impl<Id> PadGroupEvent<Id> {
@@ -100,6 +115,10 @@ impl<Id> PadGroupEvent<Id> {
                 strip: Into::from(strip),
                 event,
             },
+            Self::Dial { dial, event } => PadGroupEvent::Dial {
+                dial: Into::from(dial),
+                event,
+            },
             Self::Mode(mode) => PadGroupEvent::Mode(mode),
         }
     }
@@ -109,6 +128,11 @@ pub enum Event<Id> {
     Tool { tool: Id, event: ToolEvent<Id> },
     Tablet { tablet: Id, event: TabletEvent },
     Pad { pad: Id, event: PadEvent<Id> },
+    EnumerationComplete,
+    WindowFocus { focused: bool },
+    PointerLock { locked: bool },
+    Touch(crate::touch::TouchEvent),
+    System(super::SystemEvent),
 }
 impl<Id> Event<Id> {
     // Can't impl `From`, due to conflict with `From<T> for T` :(
@@ -126,6 +150,72 @@ impl<Id> Event<Id> {
                 pad: Into::from(pad),
                 event: event.id_into::<Into>(),
             },
+            Self::EnumerationComplete => Event::EnumerationComplete,
+            Self::WindowFocus { focused } => Event::WindowFocus { focused },
+            Self::PointerLock { locked } => Event::PointerLock { locked },
+            Self::Touch(touch) => Event::Touch(touch),
+            Self::System(system) => Event::System(system),
+        }
+    }
+    /// Whether this is a device being removed, or `None` if it isn't being added or removed at all.
+    pub fn is_removal(&self) -> Option<bool> {
+        match self {
+            Self::Tool {
+                event: ToolEvent::Added,
+                ..
+            }
+            | Self::Tablet {
+                event: TabletEvent::Added,
+                ..
+            }
+            | Self::Pad {
+                event: PadEvent::Added,
+                ..
+            } => Some(false),
+            Self::Tool {
+                event: ToolEvent::Removed,
+                ..
+            }
+            | Self::Tablet {
+                event: TabletEvent::Removed,
+                ..
+            }
+            | Self::Pad {
+                event: PadEvent::Removed,
+                ..
+            } => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// What's been seen of a tool's frame in progress, for processors that treat frames of nothing but a pose specially.
+/// Indices are of the tool's events, into whatever slice they're being fed from.
+#[derive(Clone, Copy, Default)]
+pub struct PoseFrame {
+    /// Index of the frame's pose, if any.
+    pub pose_at: Option<usize>,
+    /// Index of the [raw values](crate::axis::RawPose) behind it, which go wherever the pose goes.
+    pub raw_at: Option<usize>,
+    /// Whether the frame has anything besides its pose.
+    pub other: bool,
+}
+impl PoseFrame {
+    /// Note down the tool's next `event`, at index `idx`. Gives the finished frame if this closed it.
+    pub fn push<Id>(&mut self, idx: usize, event: &ToolEvent<Id>) -> Option<Self> {
+        match event {
+            ToolEvent::Pose(_) => {
+                // A second pose in one frame is weird, but not ours to judge.
+                self.other |= self.pose_at.is_some();
+                self.pose_at = Some(idx);
+            }
+            ToolEvent::RawPose(_) => {
+                self.other |= self.raw_at.is_some();
+                self.raw_at = Some(idx);
+            }
+            ToolEvent::Frame(_) => return Some(std::mem::take(self)),
+            _ => self.other = true,
         }
+        None
     }
 }
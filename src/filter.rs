@@ -0,0 +1,176 @@
+//! Opt-in noise filtering of poses, applied by the [`Manager`](crate::Manager) during each pump.
+//! See [`Builder::smoothing`](crate::builder::Builder::smoothing).
+
+use crate::{
+    axis::{Filter, SmoothingConfig},
+    events::{
+        raw::{Event as RawEvent, ToolEvent as RawTool},
+        FrameTimestamp,
+    },
+    platform::InternalID,
+    util::NicheF32,
+};
+
+/// Time between frames assumed when it can't be told from their timestamps, in seconds. Around the report rate of
+/// common hardware.
+const NOMINAL_DT: f32 = 1.0 / 200.0;
+
+/// Filter state of a single value.
+#[derive(Clone, Copy)]
+struct Channel {
+    value: f32,
+    /// Smoothed rate of change, per second. Only used by the 1€ filter.
+    speed: f32,
+}
+
+/// Filter `raw`, `dt` seconds after the last value.
+fn filter(state: &mut Option<Channel>, filter: Filter, raw: f32, dt: f32) -> f32 {
+    let Some(last) = *state else {
+        *state = Some(Channel {
+            value: raw,
+            speed: 0.0,
+        });
+        return raw;
+    };
+    let next = match filter {
+        Filter::Ema { time_constant } => {
+            let tau = time_constant.as_secs_f32();
+            let alpha = if tau > 0.0 {
+                1.0 - (-dt / tau).exp()
+            } else {
+                1.0
+            };
+            Channel {
+                value: last.value + alpha * (raw - last.value),
+                speed: 0.0,
+            }
+        }
+        Filter::OneEuro {
+            min_cutoff,
+            beta,
+            derivative_cutoff,
+        } => {
+            let alpha = |cutoff: f32| {
+                // Also catches NaN, in which case don't filter at all.
+                if cutoff > 0.0 {
+                    let tau = 1.0 / (std::f32::consts::TAU * cutoff);
+                    1.0 / (1.0 + tau / dt)
+                } else {
+                    1.0
+                }
+            };
+            let speed =
+                last.speed + alpha(derivative_cutoff) * ((raw - last.value) / dt - last.speed);
+            let cutoff = min_cutoff + beta * speed.abs();
+            Channel {
+                value: last.value + alpha(cutoff) * (raw - last.value),
+                speed,
+            }
+        }
+    };
+    // Don't let a bad parameter poison the filter forever.
+    if next.value.is_finite() && next.speed.is_finite() {
+        *state = Some(next);
+        next.value
+    } else {
+        *state = None;
+        raw
+    }
+}
+
+/// Filter state of a tool.
+#[derive(Default)]
+struct Filtered {
+    /// Index of the pose of the frame in progress, into the whole pump's events.
+    pose_at: Option<usize>,
+    last_time: Option<FrameTimestamp>,
+    position: [Option<Channel>; 2],
+    pressure: Option<Channel>,
+    tilt: [Option<Channel>; 2],
+}
+
+pub(crate) struct Filters {
+    config: SmoothingConfig,
+    tools: std::collections::HashMap<InternalID, Filtered>,
+}
+impl Filters {
+    pub(crate) fn new(config: SmoothingConfig) -> Self {
+        Self {
+            config,
+            tools: std::collections::HashMap::new(),
+        }
+    }
+    /// Follow the events at the ascending indices `removed` being taken out of the pump's events.
+    pub(crate) fn removed(&mut self, removed: &[usize]) {
+        for state in self.tools.values_mut() {
+            if let Some(at) = &mut state.pose_at {
+                *at = crate::dedup::shifted(*at, removed);
+            }
+        }
+    }
+    /// Filter newly ingested events, `events[from..]`, in place. Poses are filtered as their frame ends, so as to know
+    /// its timestamp - which under [`Manager::pump_budgeted`](crate::Manager::pump_budgeted) may be a later batch of
+    /// the same pump, hence `events` being the whole lot.
+    pub(crate) fn process(&mut self, events: &mut [RawEvent<InternalID>], from: usize) {
+        // A fresh pump, the last one's indices point at who knows what now.
+        if from == 0 {
+            for state in self.tools.values_mut() {
+                state.pose_at = None;
+            }
+        }
+        for idx in from..events.len() {
+            let RawEvent::Tool { tool, event } = &events[idx] else {
+                continue;
+            };
+            match event {
+                RawTool::Pose(_) => self.tools.entry(tool.clone()).or_default().pose_at = Some(idx),
+                &RawTool::Frame(time) => {
+                    let Some(state) = self.tools.get_mut(tool) else {
+                        continue;
+                    };
+                    let Some(pose_at) = state.pose_at.take() else {
+                        continue;
+                    };
+                    let dt = match (state.last_time, time) {
+                        // Synthetic and hardware timestamps have different epochs.
+                        (Some(last), Some(time))
+                            if time > last && time.is_synthetic() == last.is_synthetic() =>
+                        {
+                            (time - last).as_secs_f32()
+                        }
+                        _ => NOMINAL_DT,
+                    };
+                    state.last_time = time;
+                    let RawEvent::Tool {
+                        event: RawTool::Pose(pose),
+                        ..
+                    } = &mut events[pose_at]
+                    else {
+                        continue;
+                    };
+                    let config = self.config;
+                    if let Some(kind) = config.position {
+                        for (value, channel) in pose.position.iter_mut().zip(&mut state.position) {
+                            *value = filter(channel, kind, *value, dt);
+                        }
+                    }
+                    if let (Some(kind), Some(pressure)) = (config.pressure, pose.pressure.get()) {
+                        let pressure =
+                            filter(&mut state.pressure, kind, pressure, dt).clamp(0.0, 1.0);
+                        pose.pressure = NicheF32::new_some(pressure).unwrap_or(NicheF32::NONE);
+                    }
+                    if let (Some(kind), Some(tilt)) = (config.tilt, &mut pose.tilt) {
+                        for (value, channel) in tilt.iter_mut().zip(&mut state.tilt) {
+                            *value = filter(channel, kind, *value, dt);
+                        }
+                    }
+                }
+                // Starting afresh, don't ease from wherever it was last time.
+                RawTool::In { .. } | RawTool::Removed => {
+                    self.tools.remove(tool);
+                }
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Opt-in behavioral fingerprinting of tools, observed by the [`Manager`](crate::Manager) during each pump.
+//! See [`Builder::fingerprint_tools`](crate::builder::Builder::fingerprint_tools).
+
+use crate::{
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+    tool::Fingerprint,
+};
+
+/// Number of buckets the pressure range is divided into.
+const PRESSURE_BUCKETS: usize = 8;
+/// Down samples required before we'll even pretend to know anything.
+const MIN_SAMPLES: u32 = 256;
+/// Hover movements under this many logical pixels are considered noise, rather than the user moving the tool.
+const JITTER_RADIUS: f32 = 2.0;
+
+/// Running sums for a single tool.
+#[derive(Clone, Copy, Debug, Default)]
+struct Observations {
+    pressure_buckets: [u32; PRESSURE_BUCKETS],
+    down_samples: u32,
+    down: bool,
+    last_pressure: Option<f32>,
+    pressure_delta_sum: f32,
+    pressure_deltas: u32,
+    last_hover: Option<[f32; 2]>,
+    position_delta_sum: f32,
+    position_deltas: u32,
+}
+impl Observations {
+    fn observe_pose(&mut self, pose: &crate::axis::Pose) {
+        if self.down {
+            let Some(pressure) = pose.pressure.get() else {
+                return;
+            };
+            let pressure = pressure.clamp(0.0, 1.0);
+            // Truncation intended, clamped so `1.0` lands in the top bucket.
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let bucket = ((pressure * PRESSURE_BUCKETS as f32) as usize).min(PRESSURE_BUCKETS - 1);
+            self.pressure_buckets[bucket] += 1;
+            self.down_samples += 1;
+            if let Some(last) = self.last_pressure.replace(pressure) {
+                self.pressure_delta_sum += (pressure - last).abs();
+                self.pressure_deltas += 1;
+            }
+        } else {
+            let [x, y] = pose.position;
+            if let Some([lx, ly]) = self.last_hover.replace([x, y]) {
+                let delta = (x - lx).hypot(y - ly);
+                if delta < JITTER_RADIUS {
+                    self.position_delta_sum += delta;
+                    self.position_deltas += 1;
+                }
+            }
+        }
+    }
+    #[allow(clippy::cast_precision_loss)]
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        if self.down_samples < MIN_SAMPLES {
+            return None;
+        }
+        let mean = |sum: f32, count: u32| if count == 0 { 0.0 } else { sum / count as f32 };
+        Some(Fingerprint {
+            pressure_shape: self
+                .pressure_buckets
+                .map(|count| count as f32 / self.down_samples as f32),
+            pressure_jitter: mean(self.pressure_delta_sum, self.pressure_deltas),
+            position_jitter: mean(self.position_delta_sum, self.position_deltas),
+            samples: self.down_samples,
+        })
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Tracker {
+    tools: std::collections::HashMap<InternalID, Observations>,
+}
+impl Tracker {
+    /// Observe a pump's worth of events.
+    pub(crate) fn process(&mut self, events: &[RawEvent<InternalID>]) {
+        for event in events {
+            let RawEvent::Tool { tool, event } = event else {
+                continue;
+            };
+            match event {
+                RawTool::Pose(pose) => self
+                    .tools
+                    .entry(tool.clone())
+                    .or_default()
+                    .observe_pose(pose),
+                RawTool::Down => {
+                    let observations = self.tools.entry(tool.clone()).or_default();
+                    observations.down = true;
+                    observations.last_pressure = None;
+                }
+                RawTool::Up | RawTool::Out => {
+                    if let Some(observations) = self.tools.get_mut(tool) {
+                        observations.down = false;
+                        observations.last_hover = None;
+                    }
+                }
+                RawTool::Removed => {
+                    self.tools.remove(tool);
+                }
+                _ => (),
+            }
+        }
+    }
+    pub(crate) fn fingerprint(&self, tool: &InternalID) -> Option<Fingerprint> {
+        self.tools.get(tool)?.fingerprint()
+    }
+}
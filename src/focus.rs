@@ -0,0 +1,136 @@
+//! Opt-in synthesis of tool `Out`s when the window loses focus or the pointer is locked, applied by the
+//! [`Manager`](crate::Manager) during each pump. See [`Builder::out_on_focus_loss`](crate::builder::Builder::out_on_focus_loss)
+//! and [`Builder::pointer_lock`](crate::builder::Builder::pointer_lock).
+
+use crate::{
+    builder::{OutOnFocusLoss, PointerLock},
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+};
+
+/// What we know of each in-proximity tool, as reported by the platform.
+#[derive(Clone, Copy, Debug)]
+struct Proximity {
+    down: bool,
+}
+
+pub(crate) struct FocusOut {
+    policy: OutOnFocusLoss,
+    lock_policy: PointerLock,
+    /// Whether the pointer is locked with [`PointerLock::Suspend`], during which tools coming in are detached too.
+    suspended: bool,
+    /// Tools currently in proximity.
+    tools: std::collections::HashMap<InternalID, Proximity>,
+    /// Tools we've sent out on the platform's behalf. The platform still believes these to be in, so
+    /// their events are swallowed until the platform agrees with us by sending its own `Out`.
+    detached: std::collections::HashSet<InternalID>,
+}
+impl FocusOut {
+    pub(crate) fn new(policy: OutOnFocusLoss, lock_policy: PointerLock) -> Self {
+        Self {
+            policy,
+            lock_policy,
+            suspended: false,
+            tools: std::collections::HashMap::new(),
+            detached: std::collections::HashSet::new(),
+        }
+    }
+    /// Observe a pump's worth of events, forwarding them into `into` with any synthesized events inserted.
+    pub(crate) fn process(
+        &mut self,
+        events: impl IntoIterator<Item = RawEvent<InternalID>>,
+        into: &mut Vec<RawEvent<InternalID>>,
+    ) {
+        for event in events {
+            match &event {
+                RawEvent::Tool {
+                    tool,
+                    event: tool_event,
+                } => {
+                    if self.suspended && matches!(tool_event, RawTool::In { .. }) {
+                        self.detached.insert(tool.clone());
+                        continue;
+                    }
+                    if self.detached.contains(tool) {
+                        match tool_event {
+                            RawTool::Out => {
+                                self.detached.remove(tool);
+                            }
+                            RawTool::Removed => {
+                                self.detached.remove(tool);
+                                into.push(event);
+                            }
+                            // Already told the client it's out, keep quiet.
+                            _ => (),
+                        }
+                        continue;
+                    }
+                    match tool_event {
+                        RawTool::In { .. } => {
+                            self.tools.insert(tool.clone(), Proximity { down: false });
+                        }
+                        RawTool::Down => {
+                            if let Some(proximity) = self.tools.get_mut(tool) {
+                                proximity.down = true;
+                            }
+                        }
+                        RawTool::Up => {
+                            if let Some(proximity) = self.tools.get_mut(tool) {
+                                proximity.down = false;
+                            }
+                        }
+                        RawTool::Out | RawTool::Removed => {
+                            self.tools.remove(tool);
+                        }
+                        _ => (),
+                    }
+                    into.push(event);
+                }
+                RawEvent::WindowFocus { focused: false } => {
+                    into.push(event);
+                    self.send_out(self.policy, into);
+                }
+                RawEvent::PointerLock { locked } => {
+                    let locked = *locked;
+                    into.push(event);
+                    if self.lock_policy == PointerLock::Suspend {
+                        self.suspended = locked;
+                        if locked {
+                            self.send_out(OutOnFocusLoss::Always, into);
+                        }
+                    }
+                }
+                _ => into.push(event),
+            }
+        }
+    }
+    /// Send the applicable tools out, according to the given policy.
+    fn send_out(&mut self, policy: OutOnFocusLoss, into: &mut Vec<RawEvent<InternalID>>) {
+        self.tools.retain(|tool, proximity| {
+            let send_out = match policy {
+                OutOnFocusLoss::Always => true,
+                OutOnFocusLoss::UnlessDown => !proximity.down,
+                OutOnFocusLoss::Never => false,
+            };
+            if !send_out {
+                return true;
+            }
+            if proximity.down {
+                into.push(RawEvent::Tool {
+                    tool: tool.clone(),
+                    event: RawTool::Up,
+                });
+            }
+            into.push(RawEvent::Tool {
+                tool: tool.clone(),
+                event: RawTool::Out,
+            });
+            into.push(RawEvent::Tool {
+                tool: tool.clone(),
+                event: RawTool::Frame(None),
+            });
+            self.detached.insert(tool.clone());
+            false
+        });
+    }
+}
@@ -7,6 +7,8 @@
 //! such as `eframe` and `winit` through the [`raw_window_handle`](https://crates.io/crates/raw-window-handle) crate.
 //!
 //! To get started, create a [`Builder`].
+//! `winit` users may also enable the `winit` feature for some shortcuts, see the `winit` module. Likewise, the `egui`
+//! feature provides scrolling and zooming with pad rings and strips, see the `egui` module.
 //!
 //! ## Supported platforms
 //! See the [`Backend`] enum and [`README.md`](https://github.com/Fuzzyzilla/octotablet/blob/master/README.md)
@@ -41,13 +43,38 @@
 mod platform;
 use platform::{InternalID, PlatformImpl};
 
+mod announce;
 pub mod axis;
 pub mod builder;
+#[cfg(custom_backend)]
+pub mod custom;
+mod dedup;
+#[cfg(egui_helpers)]
+pub mod egui;
 pub mod events;
+mod filter;
+mod fingerprint;
+mod focus;
+pub mod mapping;
+#[cfg(mock_backend)]
+pub mod mock;
 pub mod pad;
+pub mod predict;
+mod pressure;
+mod quantize;
+pub mod region;
+#[cfg(record_replay)]
+pub mod replay;
+pub mod snapshot;
+pub mod summary;
+pub mod support;
 pub mod tablet;
 pub mod tool;
+pub mod touch;
 pub mod util;
+mod watchdog;
+#[cfg(winit_helpers)]
+pub mod winit;
 pub use builder::Builder;
 use events::Events;
 
@@ -66,11 +93,12 @@ enum Backing {
     // that it is valid as long as this object lives.
     Raw,
 }
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 
-/// List of supported backends. This is not affected by enabled features.
+/// List of supported backends. This is not affected by enabled features, see [`compiled_backends`] for that.
 // Some are never constructed due to disabled features/target platform.
 #[allow(dead_code)]
+#[non_exhaustive]
 pub enum Backend {
     /// [`tablet_unstable_v2`](https://wayland.app/protocols/tablet-unstable-v2)
     ///
@@ -82,6 +110,103 @@ pub enum Backend {
     /// such as stippling motions resulting in lost clicks or some motions being interpreted as scrolling or flicking gestures.
     /// Through use of this interface, this gesture recognition is bypassed to the greatest extent possible.
     WindowsInkRealTimeStylus,
+    /// A user-provided custom backend, see `custom::CustomBackend` under the `custom-backend` feature.
+    Custom,
+    /// No backend at all, with never a device nor an event. Used in place of an unsupported one if
+    /// [`Builder::null_fallback`] is set.
+    Null,
+}
+impl Backend {
+    /// Whether this backend was compiled in, see [`compiled_backends`].
+    #[must_use]
+    pub fn is_compiled(self) -> bool {
+        compiled_backends().contains(&self)
+    }
+}
+/// Query the backends compiled into this build, as determined by enabled features and the target platform.
+///
+/// A backend missing from this list can never be used without rebuilding, whereas one that is present may still be
+/// unavailable at runtime (e.g. the compositor doesn't support it).
+#[must_use]
+pub fn compiled_backends() -> &'static [Backend] {
+    &[
+        #[cfg(wl_tablet)]
+        Backend::WaylandTabletUnstableV2,
+        #[cfg(ink_rts)]
+        Backend::WindowsInkRealTimeStylus,
+        #[cfg(custom_backend)]
+        Backend::Custom,
+        Backend::Null,
+    ]
+}
+/// A protocol version negotiated with the server, see [`Manager::protocol_versions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolVersion {
+    /// The highest version supported by the server.
+    pub advertised: u32,
+    /// The version in use, the lesser of [`advertised`](Self::advertised) and the highest supported by this crate.
+    pub bound: u32,
+}
+/// Versions of the protocols in use by the backend, see [`Manager::protocol_versions`].
+/// `None` fields are those the server does not provide (or has since removed).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ProtocolVersions {
+    /// `zwp_tablet_manager_v2`, which all other tablet objects inherit their version from.
+    pub tablet_manager: Option<ProtocolVersion>,
+    /// `wl_seat`
+    pub seat: Option<ProtocolVersion>,
+}
+/// Details of the backend in use, see [`Manager::backend_info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BackendInfo {
+    /// See [`Backend::WaylandTabletUnstableV2`].
+    Wayland(WaylandInfo),
+    /// See [`Backend::WindowsInkRealTimeStylus`].
+    Ink(InkInfo),
+    /// See [`Backend::Custom`]. The backend is yours, you already know all about it!
+    Custom,
+    /// See [`Backend::Null`]. Nothing to tell!
+    Null,
+}
+/// Wayland details, see [`BackendInfo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WaylandInfo {
+    /// Same as [`Manager::protocol_versions`].
+    pub versions: ProtocolVersions,
+    /// Whether pad rings and strips can be reported, which is the case for every version of the tablet protocol
+    /// (so long as the compositor offers it at all).
+    pub strips: bool,
+    /// Whether pad [dials](pad::Dial) can be reported, which needs version 2 of the tablet protocol.
+    pub dials: bool,
+    /// How many messages from the compositor made no sense (unknown enum values, references to devices never
+    /// announced, ...) and were skipped. Nonzero values point at a compositor bug - if
+    /// [debug history](Builder::debug_history) is enabled, the [dump](Manager::debug_dump) notes which and why.
+    pub skipped_messages: u64,
+}
+/// Windows Ink details, see [`BackendInfo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InkInfo {
+    /// Whether the manager's thread was joined to the multithreaded COM apartment, rather than finding itself
+    /// already in a single-threaded one. Events are collected asynchronously by a stylus plugin either way.
+    pub multithreaded_apartment: bool,
+    /// Whether the `RealTimeStylus` is in "all tablets mode", emulating a tool from the mouse. See
+    /// [`Builder::emulate_tool_from_mouse`].
+    pub all_tablets_mode: bool,
+}
+/// The user's pointer accessibility settings, see [`Manager::accessibility`].
+/// `None` fields are those the platform has no (known) way to express.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Accessibility {
+    /// Preferred nominal cursor size, in logical pixels. Platform defaults vary, commonly 24 or 32 - draw an own pen
+    /// cursor scaled by `cursor_size / default` to stay in proportion with the user's choice.
+    pub cursor_size: Option<u32>,
+    /// Whether the user has asked for a high contrast theme, in which case subtle brush outlines may be hard to see.
+    pub high_contrast: Option<bool>,
 }
 /// Errors that may occur during even pumping.
 #[derive(thiserror::Error, Debug)]
@@ -89,27 +214,576 @@ pub enum PumpError {
     #[cfg(wl_tablet)]
     #[error(transparent)]
     WaylandDispatch(#[from] wayland_client::DispatchError),
+    /// The Wayland connection has died, such as from the compositor crashing. This is fatal - the manager will not
+    /// touch the connection again, and every further pump reports this same error.
+    ///
+    /// Drop the manager, and if the app intends to carry on, build a new one once the toolkit has reconnected.
+    #[cfg(wl_tablet)]
+    #[error("wayland connection lost: {0}")]
+    WaylandConnectionLost(wayland_client::backend::WaylandError),
+    /// Reported by a [custom backend](crate::custom::CustomBackend).
+    #[cfg(custom_backend)]
+    #[error(transparent)]
+    Custom(custom::Error),
+    /// A pump was started while another was still underway, or after one panicked part way through. Nothing was
+    /// touched, as the per-pump state is in an unknown condition.
+    ///
+    /// The borrow checker rules out the first in safe code, though foreign code run during a pump (a custom backend,
+    /// say) may well be up to something. The second happens when a panic is caught and the manager is kept around -
+    /// every further pump reports this, and the manager should be dropped.
+    #[error("pumped while already pumping, or after a pump panicked")]
+    Reentrant,
+}
+
+/// Reasons pad feedback couldn't be set, see [`Manager::set_button_feedback`].
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeedbackError {
+    /// The backend has no way to pass feedback on.
+    #[error("pad feedback is not supported by this backend")]
+    Unsupported,
+    /// The pad, button, ring, or strip isn't known to the backend, such as if it was removed.
+    #[error("no such pad control")]
+    UnknownControl,
+    /// The control's group hasn't reported its mode yet, which the feedback must refer to. Try again once a
+    /// [`Mode`](events::PadGroupEvent::Mode) event has come in.
+    #[error("pad group has not reported a mode yet")]
+    NoModeYet,
+}
+
+/// Identifies one of the windows a [`Manager`] reports events for, see [`Manager::add_window`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WindowId(pub(crate) u64);
+impl WindowId {
+    /// The window the manager was built for.
+    pub const MAIN: Self = Self(0);
+}
+
+/// Reasons a window couldn't be added, see [`Manager::add_window`].
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum AddWindowError {
+    /// The backend can only report events for the window it was built for.
+    #[error("backend doesn't support more than one window")]
+    Unsupported,
+    /// The window isn't of the same kind as the one the manager was built for, such as an X11 window given to a
+    /// Wayland manager.
+    #[error("window handle is of a different platform than the manager")]
+    ForeignWindow,
+    /// Failed to acquire a window handle
+    #[error("{0:?}")]
+    HandleError(raw_window_handle::HandleError),
+}
+impl From<raw_window_handle::HandleError> for AddWindowError {
+    fn from(value: raw_window_handle::HandleError) -> Self {
+        Self::HandleError(value)
+    }
 }
 
 /// Maintains a connection to the OS's tablet server. This is the main
 /// entry point for enumerating hardware and listening for events.
+///
+/// # Threading
+/// The manager is `!Send`, and must be pumped and dropped on the same thread it was built on. This need
+/// not be the thread that owns the window, however! The [`Builder`] is `Send`, so an app with a dedicated
+/// input thread may move it there before building.
+///
+/// Debug builds check this at runtime too, panicking if the manager is pumped or queried from the wrong thread.
+///
+/// # Reentrancy
+/// Pumps never overlap, a pump started within another fails with [`PumpError::Reentrant`] without touching any state.
+/// Events borrow the manager, so in safe code it's impossible to pump again while still holding on to them.
+///
+/// # Platform notes
+/// * Windows Ink: The window may be owned by any thread. Events are collected asynchronously and handed to the manager
+///   at the next pump, which requires both the window's thread and the building thread to keep dispatching window messages
+///   as usual. If the building thread is not yet part of a COM apartment, it joins the multithreaded apartment for the lifetime
+///   of the manager.
 pub struct Manager {
-    pub(crate) internal: platform::PlatformManager,
-    // `_backing` MUST BE LAST IN DECLARATION ORDER!
-    // the other fields may rely on the lifetime guarantees granted by the contents
-    // of this `Backing`, and it's guaranteed that drop order == declaration order.
-    // Never thought I'd end up in a situation like this in Rust :P
-    pub(crate) _backing: Backing,
+    /// The backend, along with the handles it may borrow from, kept alive until after it's dropped.
+    pub(crate) internal: util::Backed<platform::PlatformManager, Backing>,
+    /// The last pump's events, after the crate's own processing.
+    pub(crate) events: Vec<events::raw::Event<InternalID>>,
+    /// Events taken from the platform but not yet handed out, see [`Manager::pump_budgeted`].
+    pub(crate) backlog: std::collections::VecDeque<events::raw::Event<InternalID>>,
+    /// Present if enabled by [`Builder::auto_range_pressure`].
+    pub(crate) auto_range_pressure: Option<pressure::AutoRange>,
+    /// Present if enabled by [`Builder::normalize_pressure_to_tip`].
+    pub(crate) tip_normalize: Option<pressure::TipNormalize>,
+    /// Present if enabled by [`Builder::synthesize_timestamps`], the zero-point of synthetic timestamps.
+    pub(crate) synthetic_epoch: Option<std::time::Instant>,
+    /// As set by [`Builder::distance_curve`].
+    pub(crate) distance_curve: axis::Curve,
+    /// As set by [`Builder::pressure_curve`] or [`Manager::set_pressure_curve`].
+    pub(crate) pressure_curve: mapping::PressureCurve,
+    /// Present if [`Builder::out_on_focus_loss`] asks for anything.
+    pub(crate) focus_out: Option<focus::FocusOut>,
+    /// Present if enabled by [`Builder::fingerprint_tools`].
+    pub(crate) fingerprints: Option<fingerprint::Tracker>,
+    /// Present if enabled by [`Builder::quantize`].
+    pub(crate) quantize: Option<quantize::Dequantizer>,
+    /// Present if enabled by [`Builder::smoothing`].
+    pub(crate) filters: Option<filter::Filters>,
+    /// Present if enabled by [`Builder::stuck_down_timeout`].
+    pub(crate) watchdog: Option<watchdog::Watchdog>,
+    /// Present if enabled by [`Builder::suppress_duplicate_poses`].
+    pub(crate) dedup: Option<dedup::Dedup>,
+    /// See [`Builder::axes`].
+    pub(crate) axes: axis::AvailableAxes,
+    /// As set by [`Manager::set_hit_region`].
+    pub(crate) hit_region: region::HitRegion,
+    /// Fills in any [`ToolEvent::Added`](events::ToolEvent::Added) the platform skipped.
+    pub(crate) announcer: announce::Announcer,
+    /// See [`Manager::devices_epoch`].
+    pub(crate) devices_epoch: u64,
+    /// Whether the last backend pump removed a device, which will be gone from the lists after the next.
+    pub(crate) removal_pending: bool,
+    /// See [`Manager::pointer_locked`].
+    pub(crate) pointer_locked: bool,
+    /// A lock state change yet to be reported in the event stream, held until the next backend pump to keep it
+    /// in order with the tool events around it.
+    pub(crate) pointer_lock_change: Option<bool>,
+    /// See [`Events::pumped_at`].
+    pub(crate) pumped_at: std::time::Instant,
+    /// Set for the duration of a pump, see [`PumpError::Reentrant`].
+    pub(crate) pumping: bool,
+    /// See [`Manager::summary`].
+    pub(crate) summary: summary::Tracker,
+    /// The last pump's [`Manager::snapshot`], once asked for.
+    pub(crate) snapshot: std::cell::OnceCell<snapshot::Snapshot>,
+    /// The ID to give the next [`Manager::add_window`].
+    pub(crate) next_window: u64,
+    pub(crate) config: builder::Config,
+    pub(crate) affinity: util::ThreadAffinity,
 }
 impl Manager {
     /// Dispatch pending events, updating hardware reports and returning an [`IntoIterator`] containing the events.
     ///
     /// This will not wait for new events, and will return immediately with empty events if there is nothing to do.
+    ///
+    /// If a previous [`Manager::pump_budgeted`] left events behind, those are returned instead, and the platform is
+    /// not dispatched until they've all been handed out.
     #[allow(clippy::missing_errors_doc)]
     pub fn pump(&mut self) -> Result<Events<'_>, PumpError> {
-        self.internal.pump()?;
+        self.affinity.check("pump");
+        self.guarded(|this| {
+            this.events.clear();
+            this.refill_backlog()?;
+            this.events.extend(this.backlog.drain(..));
+            this.process_events(0);
+            Ok(())
+        })?;
         Ok(Events { manager: &*self })
     }
+    /// Like [`Manager::pump`], but stops ingesting events once `max` has elapsed, leaving the rest for the next call.
+    /// At least one event is always ingested if there are any, such that progress is always made. The returned `bool`
+    /// is `true` if the budget ran out with events left over.
+    ///
+    /// This prevents floods of events (device storms, high report rate hardware, ...) from blowing a frame deadline.
+    /// Hardware reports are not updated until all left over events have been handed out, so that events never refer
+    /// to devices which have since gone away.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn pump_budgeted(
+        &mut self,
+        max: std::time::Duration,
+    ) -> Result<(Events<'_>, bool), PumpError> {
+        self.affinity.check("pump_budgeted");
+        let start = std::time::Instant::now();
+        self.guarded(|this| {
+            this.events.clear();
+            this.refill_backlog()?;
+            while let Some(event) = this.backlog.pop_front() {
+                this.events.push(event);
+                this.process_events(this.events.len() - 1);
+                if start.elapsed() >= max {
+                    break;
+                }
+            }
+            Ok(())
+        })?;
+        let exhausted = !self.backlog.is_empty();
+        Ok((Events { manager: &*self }, exhausted))
+    }
+    /// Run a pump, refusing to if another is already underway. See [`PumpError::Reentrant`].
+    fn guarded(
+        &mut self,
+        pump: impl FnOnce(&mut Self) -> Result<(), PumpError>,
+    ) -> Result<(), PumpError> {
+        if std::mem::replace(&mut self.pumping, true) {
+            return Err(PumpError::Reentrant);
+        }
+        self.snapshot.take();
+        let result = pump(self);
+        // Left set if `pump` panics, on purpose!
+        self.pumping = false;
+        result
+    }
+    /// Dispatch the platform for more events, if all previous ones have been handed out.
+    fn refill_backlog(&mut self) -> Result<(), PumpError> {
+        if self.backlog.is_empty() {
+            self.internal.pump()?;
+            self.pumped_at = std::time::Instant::now();
+            if let Some(locked) = self.pointer_lock_change.take() {
+                self.backlog
+                    .push_back(events::raw::Event::PointerLock { locked });
+            }
+            self.backlog.extend(self.internal.raw_events());
+            self.announcer.process(&mut self.backlog);
+            if let Some(watchdog) = &mut self.watchdog {
+                watchdog.process(&mut self.backlog, self.pumped_at);
+            }
+            // Removed devices linger until the pump after, so that's a change too.
+            let mut changed = std::mem::take(&mut self.removal_pending);
+            for removal in self
+                .backlog
+                .iter()
+                .filter_map(events::raw::Event::is_removal)
+            {
+                changed = true;
+                self.removal_pending |= removal;
+            }
+            if changed {
+                self.devices_epoch += 1;
+            }
+        }
+        Ok(())
+    }
+    /// Run the crate's own processing on newly ingested events, `self.events[from..]`.
+    fn process_events(&mut self, mut from: usize) {
+        // Goes first, so the events it synthesizes get the same treatment as the rest.
+        if let Some(focus_out) = &mut self.focus_out {
+            let observed: Vec<_> = self.events.drain(from..).collect();
+            focus_out.process(observed, &mut self.events);
+        }
+        // Keeps watch even without a region, so tools already in proximity can be judged once one is set.
+        let observed: Vec<_> = self.events.drain(from..).collect();
+        self.hit_region.process(observed, &mut self.events);
+        let events = &mut self.events[from..];
+        // Backends may well report more than asked for, what's unwanted shouldn't be seen by anyone.
+        if self.axes != axis::AvailableAxes::all() {
+            events::retain_axes(events, self.axes);
+        }
+        // Before anything else touches the pressures, we're interested in what the hardware said.
+        if let Some(fingerprints) = &mut self.fingerprints {
+            fingerprints.process(events);
+        }
+        if let Some(tip_normalize) = &mut self.tip_normalize {
+            tip_normalize.process(events, self.internal.tools());
+        }
+        if let Some(auto_range) = &mut self.auto_range_pressure {
+            auto_range.process(events);
+        }
+        if !self.pressure_curve.is_linear() {
+            mapping::apply(events, &self.pressure_curve);
+        }
+        if self.distance_curve != axis::Curve::Linear {
+            events::apply_distance_curve(events, self.internal.tools(), self.distance_curve);
+        }
+        if let Some(quantize) = &mut self.quantize {
+            quantize.process(events, self.internal.tools());
+        }
+        if let Some(epoch) = self.synthetic_epoch {
+            events::synthesize_timestamps(events, epoch.elapsed());
+        }
+        // After timestamps are filled in, if they're to be.
+        if let Some(filters) = &mut self.filters {
+            filters.process(&mut self.events, from);
+        }
+        // After anything that might make identical poses differ, or differing poses identical.
+        if let Some(dedup) = &mut self.dedup {
+            let removed = dedup.process(&mut self.events, from, std::time::Instant::now());
+            if !removed.is_empty() {
+                // Frames still open may span the removed ones.
+                if let Some(filters) = &mut self.filters {
+                    filters.removed(&removed);
+                }
+                // Some may be of earlier batches, already summarized - harmlessly, as they repeat what's kept.
+                from = dedup::shifted(from, &removed);
+            }
+        }
+        // Last, so it sees what the client sees.
+        self.summary.process(&self.events[from..]);
+    }
+    /// The events of the last [`Manager::pump`], keyed by ID rather than referring to the devices themselves. Unlike
+    /// the events returned by the pump, these don't borrow the manager, so devices may be looked up (with e.g.
+    /// [`Manager::tool`]) only as and when needed - handy for ECS-style architectures.
+    ///
+    /// Since the devices aren't looked up, events are reported even for devices missing from the hardware reports,
+    /// which the pump's own events would have skipped.
+    #[must_use]
+    pub fn raw_events(&self) -> Vec<events::OwnedEvent> {
+        let pads = self.pads();
+        self.events
+            .iter()
+            .map(|event| events::OwnedEvent::from_raw(event, pads))
+            .collect()
+    }
+    /// The latest known state of every tool and pad, as of the last [`Manager::pump`]: which tools are in and down,
+    /// where they were last seen, which buttons are held, and where the rings and strips are at. See the
+    /// [`summary`] module.
+    ///
+    /// Kept up to date by this crate from the events, so works the same on every backend.
+    #[must_use]
+    pub fn summary(&self) -> summary::Summary<'_> {
+        summary::Summary { manager: self }
+    }
+    /// A copy of the devices and events as of the last [`Manager::pump`], which may be sent to and read from other
+    /// threads. See the [`snapshot`] module.
+    ///
+    /// Made on the first call after each pump, later calls hand out clones of the same one for the price of a
+    /// reference count.
+    #[must_use]
+    pub fn snapshot(&self) -> snapshot::Snapshot {
+        self.snapshot
+            .get_or_init(|| snapshot::Snapshot::of(self))
+            .clone()
+    }
+    /// Whether the last [`Manager::pump`] had anything to report - any event, including any device being
+    /// added or removed. Useful for deciding whether to request a redraw.
+    ///
+    /// Equivalent to `!events.is_empty()` on the events returned by that pump, for when they're no longer at hand.
+    #[must_use]
+    pub fn had_activity(&self) -> bool {
+        !self.events.is_empty()
+    }
+    /// Replace the [pressure curve](Builder::pressure_curve), taking effect from the next [`Manager::pump`].
+    pub fn set_pressure_curve(&mut self, curve: mapping::PressureCurve) {
+        self.pressure_curve = curve;
+    }
+    /// Query the pressure curve in effect, see [`Manager::set_pressure_curve`].
+    #[must_use]
+    pub fn pressure_curve(&self) -> &mapping::PressureCurve {
+        &self.pressure_curve
+    }
+    /// Query the pressure currently mapped to `1.0` for the given tool, as learned by [`Builder::auto_range_pressure`].
+    /// Save this alongside the tool's [hardware id](tool::Tool::hardware_id) to restore it on the next run with
+    /// [`Manager::set_pressure_ceiling`].
+    ///
+    /// `None` if auto-ranging is disabled. Tools that have not yet been observed report `1.0`.
+    #[must_use]
+    pub fn pressure_ceiling(&self, tool: &tool::ID) -> Option<f32> {
+        self.auto_range_pressure
+            .as_ref()
+            .map(|auto_range| auto_range.ceiling(&tool.0))
+    }
+    /// Restore a pressure ceiling previously queried with [`Manager::pressure_ceiling`], replacing what has been learned
+    /// so far for this tool. Clamped to a sane range, `NaN` is ignored.
+    ///
+    /// Does nothing if auto-ranging is disabled.
+    pub fn set_pressure_ceiling(&mut self, tool: &tool::ID, ceiling: f32) {
+        if let Some(auto_range) = &mut self.auto_range_pressure {
+            auto_range.set_ceiling(tool.0.clone(), ceiling);
+        }
+    }
+    /// Query the pressure currently mapped to `0.0` for the given tool, as used by
+    /// [`Builder::normalize_pressure_to_tip`].
+    ///
+    /// `None` if normalization is disabled, or if the threshold isn't known yet - the tool has yet to go down.
+    #[must_use]
+    pub fn tip_threshold(&self, tool: &tool::ID) -> Option<f32> {
+        self.tip_normalize
+            .as_ref()
+            .and_then(|tip_normalize| tip_normalize.threshold(&tool.0, self.internal.tools()))
+    }
+    /// Query the heuristic [fingerprint](tool::Fingerprint) built up for the given tool so far.
+    ///
+    /// `None` if fingerprinting is disabled, or the tool hasn't yet been used enough to say anything.
+    #[must_use]
+    pub fn tool_fingerprint(&self, tool: &tool::ID) -> Option<tool::Fingerprint> {
+        self.fingerprints.as_ref()?.fingerprint(&tool.0)
+    }
+    /// Query the position of the OS cursor as of the last [`Manager::pump`], in the same coordinate space as
+    /// [`Pose::position`](axis::Pose::position). `None` if not observable or not over the window.
+    ///
+    /// The OS may smooth or accelerate the cursor relative to the raw tool data, causing strokes drawn from poses
+    /// to not line up with the cursor. This allows an application to choose with which of the two to draw.
+    ///
+    /// # Platform support
+    /// * Windows Ink - Anywhere on the desktop with [`CoordinateSpace::Screen`](builder::CoordinateSpace::Screen).
+    #[must_use]
+    pub fn cursor_position(&self) -> Option<[f32; 2]> {
+        self.affinity.check("cursor_position");
+        self.internal.cursor_position()
+    }
+    /// Set whether the system cursor should be hidden while a tool is down, to keep it from flickering over the
+    /// application's own brush preview. Takes effect from the next [`Manager::pump`].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Platform support
+    /// * Wayland - Once hidden, the cursor stays hidden until the tool leaves proximity or the application sets a
+    ///   cursor for it, as the protocol offers no way to restore the compositor's default.
+    /// * Windows Ink - Hidden on the thread calling [`Manager::pump`], as per `ShowCursor`. This should be the thread owning
+    ///   the window for it to have any effect.
+    pub fn hide_cursor_while_down(&mut self, hide: bool) {
+        self.affinity.check("hide_cursor_while_down");
+        self.internal.hide_cursor_while_down(hide);
+    }
+    /// Restrict tool events to the given part of the window, such as to keep a canvas from reacting to tools hovering
+    /// over the surrounding toolbars. `None` lifts the restriction, which is the default. Takes effect from the next
+    /// [`Manager::pump`].
+    ///
+    /// Tools outside the region are reported as [`Out`](events::ToolEvent::Out), and come back [`In`](events::ToolEvent::In)
+    /// upon returning. Strokes begun inside carry on outside until the tool is lifted, much like a pointer grab, and
+    /// strokes begun outside are ignored until lifted in turn.
+    ///
+    /// # Platform support
+    /// * Windows Ink - Also passed on to `SetWindowInputRectangle`, for the system to skip the work to begin with.
+    /// * Otherwise, this is purely crate-side.
+    pub fn set_hit_region(&mut self, region: Option<region::Rect>) {
+        self.affinity.check("set_hit_region");
+        self.hit_region.set(region);
+        self.internal.set_hit_region(region);
+    }
+    /// Report events for another window alongside the one the manager was built for, such that a multi-window app
+    /// needs only the one connection. Which window events are for is told by [`Event::window_id`](events::Event::window_id).
+    ///
+    /// # Safety
+    /// As with [`Builder::build_raw`], the window must remain valid for as long as the manager is alive - or until
+    /// it's taken back off with [`Manager::remove_window`].
+    ///
+    /// # Errors
+    /// See [`AddWindowError`].
+    ///
+    /// # Platform support
+    /// * Wayland - Any surface of the same connection.
+    /// * Windows Ink - Unsupported, as each window has its own `RealTimeStylus`. Build a manager per window.
+    /// * Custom backends - Unsupported.
+    pub unsafe fn add_window(
+        &mut self,
+        window: &impl raw_window_handle::HasWindowHandle,
+    ) -> Result<WindowId, AddWindowError> {
+        self.affinity.check("add_window");
+        let id = WindowId(self.next_window);
+        self.internal
+            .add_window(window.window_handle()?.as_raw(), id)?;
+        self.next_window += 1;
+        Ok(id)
+    }
+    /// Stop reporting events for a window given to [`Manager::add_window`], after which it may be closed. Events
+    /// already pumped may still name it. Does nothing for windows not added, including [`WindowId::MAIN`] which lives
+    /// as long as the manager.
+    pub fn remove_window(&mut self, id: WindowId) {
+        self.affinity.check("remove_window");
+        if id != WindowId::MAIN {
+            self.internal.remove_window(id);
+        }
+    }
+    /// Describe what the given pad button does in its group's current mode, such as "Undo", for the system to show
+    /// on the pad's display or an on-screen overlay. Descriptions are forgotten upon every mode switch, so this is
+    /// best called in response to a [`Mode`](events::PadGroupEvent::Mode) event. See also [`pad::Group::feedback`].
+    ///
+    /// # Errors
+    /// See [`FeedbackError`].
+    ///
+    /// # Platform support
+    /// * Wayland - Shown at the compositor's discretion.
+    /// * Windows Ink - Unsupported, as pads aren't reported at all.
+    pub fn set_button_feedback(
+        &mut self,
+        pad: &pad::ID,
+        button_idx: u32,
+        description: &str,
+    ) -> Result<(), FeedbackError> {
+        self.affinity.check("set_button_feedback");
+        self.internal.set_feedback(
+            platform::FeedbackTarget::Button {
+                pad: &pad.0,
+                button_idx,
+            },
+            description,
+        )
+    }
+    /// Describe what the given ring does in its group's current mode. See [`Manager::set_button_feedback`].
+    ///
+    /// # Errors
+    /// See [`FeedbackError`].
+    pub fn set_ring_feedback(
+        &mut self,
+        ring: &pad::ring::ID,
+        description: &str,
+    ) -> Result<(), FeedbackError> {
+        self.affinity.check("set_ring_feedback");
+        self.internal
+            .set_feedback(platform::FeedbackTarget::Ring(&ring.0), description)
+    }
+    /// Describe what the given strip does in its group's current mode. See [`Manager::set_button_feedback`].
+    ///
+    /// # Errors
+    /// See [`FeedbackError`].
+    pub fn set_strip_feedback(
+        &mut self,
+        strip: &pad::strip::ID,
+        description: &str,
+    ) -> Result<(), FeedbackError> {
+        self.affinity.check("set_strip_feedback");
+        self.internal
+            .set_feedback(platform::FeedbackTarget::Strip(&strip.0), description)
+    }
+    /// Tell the manager whether the application has locked (or grabbed, or confined, or clipped) the pointer, for it
+    /// to apply the [`Builder::pointer_lock`] policy. Tablets are unaffected by pointer lock on every platform, so
+    /// there's no finding out otherwise.
+    ///
+    /// The change is reported as an [`Event::PointerLock`](events::Event::PointerLock) as part of a following pump,
+    /// and does nothing if the state is unchanged.
+    pub fn set_pointer_locked(&mut self, locked: bool) {
+        if self.pointer_locked != locked {
+            self.pointer_locked = locked;
+            // Changing it back before it was ever reported cancels out.
+            self.pointer_lock_change = match self.pointer_lock_change {
+                Some(_) => None,
+                None => Some(locked),
+            };
+        }
+    }
+    /// Query the pointer lock state as last set with [`Manager::set_pointer_locked`]. Defaults to `false`.
+    #[must_use]
+    pub fn pointer_locked(&self) -> bool {
+        self.pointer_locked
+    }
+    /// Query the offset of the OS cursor from the given pose, `cursor - pose`. See [`Manager::cursor_position`].
+    ///
+    /// This is most meaningful for the last pose of the last pump, as the cursor is only sampled once per pump.
+    #[must_use]
+    pub fn cursor_divergence(&self, pose: &axis::Pose) -> Option<[f32; 2]> {
+        let [cx, cy] = self.cursor_position()?;
+        let [px, py] = pose.position;
+        Some([cx - px, cy - py])
+    }
+    /// Query the versions of the protocols in use, useful for logging why some features may be absent.
+    /// `None` if the backend has no such concept.
+    ///
+    /// # Platform support
+    /// * Wayland
+    #[must_use]
+    pub fn protocol_versions(&self) -> Option<ProtocolVersions> {
+        self.internal.protocol_versions()
+    }
+    /// Query details specific to the backend in use, for adapting to what it can and can't do beyond what
+    /// [`Manager::backed`] tells. See also the [`support`] module for what each backend is capable of in general.
+    #[must_use]
+    pub fn backend_info(&self) -> BackendInfo {
+        self.internal.backend_info()
+    }
+    /// Query the system's pointer accessibility settings, for applications drawing their own pen cursor which
+    /// should follow the user's choices like the system cursor does. Read anew on each call, so keep an eye on it
+    /// every so often if changes should be picked up.
+    ///
+    /// # Platform support
+    /// * Wayland - cursor size only, from the `XCURSOR_SIZE` convention shared by compositors and toolkits.
+    /// * Windows Ink - both, from the mouse pointer accessibility settings.
+    #[must_use]
+    pub fn accessibility(&self) -> Accessibility {
+        self.internal.accessibility()
+    }
+    /// Dump the most recent raw platform messages, oldest first and one per line, as retained by [`Builder::debug_history`].
+    /// `None` if disabled or unsupported by the backend.
+    ///
+    /// The format is unspecified and only intended for humans to read.
+    #[must_use]
+    pub fn debug_dump(&self) -> Option<String> {
+        self.internal.debug_dump()
+    }
     /// Query the precision of [timestamps](events::FrameTimestamp) provided along with axis events, if any.
     /// This does *not* represent the polling rate. `None` if timestamps are not collected.
     ///
@@ -121,12 +795,21 @@ impl Manager {
     /// Query the API currently in use. May give some hints as to the capabilities and limitations.
     #[must_use]
     pub fn backed(&self) -> Backend {
-        match self.internal {
-            #[cfg(wl_tablet)]
-            platform::PlatformManager::Wayland(_) => Backend::WaylandTabletUnstableV2,
-            #[cfg(ink_rts)]
-            platform::PlatformManager::Ink(_) => Backend::WindowsInkRealTimeStylus,
-        }
+        platform::backend_of(&self.internal)
+    }
+    /// Query the configuration in effect, as requested from the [`Builder`] and as honored by the backend.
+    /// Some options are silently ignored by some backends, this allows an application to find out what it actually got.
+    #[must_use]
+    pub fn config(&self) -> builder::Config {
+        self.config
+    }
+    /// A counter that changes whenever the set of [tablets](Manager::tablets), [pads](Manager::pads), or
+    /// [tools](Manager::tools) does, for cheaply noticing that data derived from them has gone stale.
+    ///
+    /// Only the set of devices is tracked - changes within a device, such as a [pad's tablet](pad::Pad::tablet), are not.
+    #[must_use]
+    pub fn devices_epoch(&self) -> u64 {
+        self.devices_epoch
     }
     /// Access pad information. Pads are the physical object that you draw on,
     /// and may have touch support, an inbuilt display, lights, buttons, rings, and/or sliders.
@@ -138,8 +821,22 @@ impl Manager {
     /// * Wayland only.
     #[must_use]
     pub fn pads(&self) -> &[pad::Pad] {
+        self.affinity.check("pads");
         self.internal.pads()
     }
+    /// Access the pads currently belonging to the given tablet, in the same order as [`Manager::pads`].
+    /// See [`Pad::tablet`](pad::Pad::tablet) for caveats.
+    ///
+    /// # Platform support
+    /// * Wayland only.
+    pub fn pads_for<'a>(
+        &'a self,
+        tablet: &'a tablet::ID,
+    ) -> impl Iterator<Item = &'a pad::Pad> + 'a {
+        self.pads()
+            .iter()
+            .filter(move |pad| pad.tablet.as_ref() == Some(&tablet.0))
+    }
     /// Access tool information. Tools are styluses or other hardware that
     /// communicate with one or more pads, and are responsible for reporting movements, pressure, etc.,
     /// and may have multiple buttons. Hardware reports are updated on each call to [`Manager::pump`].
@@ -147,6 +844,7 @@ impl Manager {
     /// Tools are ordered arbitrarily.
     #[must_use]
     pub fn tools(&self) -> &[tool::Tool] {
+        self.affinity.check("tools");
         self.internal.tools()
     }
     /// A tablet is the entry point for interactive devices, and the top level of the hierarchy
@@ -155,6 +853,95 @@ impl Manager {
     /// Tablets are ordered arbitrarily.
     #[must_use]
     pub fn tablets(&self) -> &[tablet::Tablet] {
+        self.affinity.check("tablets");
         self.internal.tablets()
     }
+    /// Look up a tool by ID, e.g. from an [`OwnedEvent`](events::OwnedEvent). `None` if it has since been removed.
+    #[must_use]
+    pub fn tool(&self, id: &tool::ID) -> Option<&tool::Tool> {
+        self.tools().iter().find(|tool| tool.internal_id == id.0)
+    }
+    /// Find the other end of the physical pen the given tool belongs to - the eraser of a pen's tip, or the tip of
+    /// an eraser - for e.g. linking their settings. `None` if the other end hasn't been seen yet, or the two can't be
+    /// told apart from unrelated tools.
+    ///
+    /// # Platform support
+    /// * Wayland - for hardware reporting a [`HardwareID`](tool::HardwareID), which both ends share.
+    /// * Windows Ink - never, the ends are separate cursors with nothing relating them.
+    /// * Custom backends - for tools sharing a [`HardwareID`](tool::HardwareID), the one an eraser and the other not.
+    #[must_use]
+    pub fn paired_tool(&self, id: &tool::ID) -> Option<&tool::Tool> {
+        let tool = self.tool(id)?;
+        let hardware_id = tool.hardware_id?;
+        let is_eraser = |tool: &tool::Tool| tool.tool_type == Some(tool::Type::Eraser);
+        self.tools().iter().find(|other| {
+            other.internal_id != tool.internal_id
+                && other.hardware_id == Some(hardware_id)
+                && is_eraser(other) != is_eraser(tool)
+        })
+    }
+    /// Look up a tablet by ID, e.g. from an [`OwnedEvent`](events::OwnedEvent). `None` if it has since been removed.
+    #[must_use]
+    pub fn tablet(&self, id: &tablet::ID) -> Option<&tablet::Tablet> {
+        self.tablets()
+            .iter()
+            .find(|tablet| tablet.internal_id == id.0)
+    }
+    /// Look up a pad by ID, e.g. from an [`OwnedEvent`](events::OwnedEvent). `None` if it has since been removed.
+    #[must_use]
+    pub fn pad(&self, id: &pad::ID) -> Option<&pad::Pad> {
+        self.pads().iter().find(|pad| pad.internal_id == id.0)
+    }
+    /// Look up a pad group by ID, along with the pad it belongs to. `None` if it has since been removed.
+    #[must_use]
+    pub fn group(&self, id: &pad::group::ID) -> Option<(&pad::Pad, &pad::Group)> {
+        self.pads().iter().find_map(|pad| {
+            pad.groups
+                .iter()
+                .find(|group| group.internal_id == id.0)
+                .map(|group| (pad, group))
+        })
+    }
+    /// Look up a ring by ID, along with the group it belongs to. `None` if it has since been removed.
+    #[must_use]
+    pub fn ring(&self, id: &pad::ring::ID) -> Option<(&pad::Group, &pad::Ring)> {
+        self.pads()
+            .iter()
+            .flat_map(|pad| &pad.groups)
+            .find_map(|group| {
+                group
+                    .rings
+                    .iter()
+                    .find(|ring| ring.internal_id == id.0)
+                    .map(|ring| (group, ring))
+            })
+    }
+    /// Look up a strip by ID, along with the group it belongs to. `None` if it has since been removed.
+    #[must_use]
+    pub fn strip(&self, id: &pad::strip::ID) -> Option<(&pad::Group, &pad::Strip)> {
+        self.pads()
+            .iter()
+            .flat_map(|pad| &pad.groups)
+            .find_map(|group| {
+                group
+                    .strips
+                    .iter()
+                    .find(|strip| strip.internal_id == id.0)
+                    .map(|strip| (group, strip))
+            })
+    }
+    /// Look up a dial by ID, along with the group it belongs to. `None` if it has since been removed.
+    #[must_use]
+    pub fn dial(&self, id: &pad::dial::ID) -> Option<(&pad::Group, &pad::Dial)> {
+        self.pads()
+            .iter()
+            .flat_map(|pad| &pad.groups)
+            .find_map(|group| {
+                group
+                    .dials
+                    .iter()
+                    .find(|dial| dial.internal_id == id.0)
+                    .map(|dial| (group, dial))
+            })
+    }
 }
@@ -0,0 +1,122 @@
+//! Reshaping of tool pressure before it reaches the client, as set by [`Builder::pressure_curve`](crate::Builder::pressure_curve)
+//! or [`Manager::set_pressure_curve`](crate::Manager::set_pressure_curve).
+//!
+//! Pretty much every drawing app ends up wanting this, and it's best done in one place, right after the
+//! pressure has been normalized.
+
+use crate::{
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+    util::NicheF32,
+};
+
+/// A response curve for [`Pose::pressure`](crate::axis::Pose::pressure), mapping `[0, 1]` onto `[0, 1]`.
+///
+/// Stages are applied in order: the dead zone, then the gamma, then the control points. The default curve leaves
+/// pressure as reported.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PressureCurve {
+    dead_zone: f32,
+    gamma: f32,
+    /// Sorted by input, endpoints excluded.
+    points: Vec<[f32; 2]>,
+}
+impl Default for PressureCurve {
+    fn default() -> Self {
+        Self {
+            dead_zone: 0.0,
+            gamma: 1.0,
+            points: Vec::new(),
+        }
+    }
+}
+impl PressureCurve {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Report pressures at or below `threshold` as zero, stretching the rest to fill the range. Handy for pens that
+    /// never quite report zero, or for ignoring the weight of a resting hand.
+    ///
+    /// Clamped to `[0, 1)`, NaN disables the dead zone.
+    #[must_use]
+    pub fn dead_zone(self, threshold: f32) -> Self {
+        Self {
+            dead_zone: if threshold > 0.0 {
+                threshold.min(1.0 - f32::EPSILON)
+            } else {
+                // Also catches NaN.
+                0.0
+            },
+            ..self
+        }
+    }
+    /// Raise pressures to the given power. Less than one makes a lighter touch reach higher pressures, greater
+    /// than one a heavier touch. Non-positive or NaN powers are treated as one.
+    #[must_use]
+    pub fn gamma(self, gamma: f32) -> Self {
+        Self {
+            gamma: if gamma > 0.0 { gamma } else { 1.0 },
+            ..self
+        }
+    }
+    /// Shape the curve by `[input, output]` control points, linearly interpolated between. `[0, 0]` and `[1, 1]` are
+    /// always implied as the endpoints, though they may be overridden by giving points at those inputs.
+    ///
+    /// Points are clamped to `[0, 1]` and may be given in any order. Those with a NaN component are ignored.
+    #[must_use]
+    pub fn points(self, points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        let mut points: Vec<_> = points
+            .into_iter()
+            .filter(|[x, y]| !x.is_nan() && !y.is_nan())
+            .map(|point| point.map(|v| v.clamp(0.0, 1.0)))
+            .collect();
+        points.sort_by(|[a, _], [b, _]| a.total_cmp(b));
+        Self { points, ..self }
+    }
+    /// Whether this curve leaves pressure as reported.
+    #[must_use]
+    pub fn is_linear(&self) -> bool {
+        self == &Self::default()
+    }
+    /// Reshape a pressure in `[0, 1]`. Values outside that range are clamped first.
+    #[must_use]
+    pub fn apply(&self, pressure: f32) -> f32 {
+        let pressure = pressure.clamp(0.0, 1.0);
+        let pressure = ((pressure - self.dead_zone) / (1.0 - self.dead_zone)).max(0.0);
+        let pressure = pressure.powf(self.gamma);
+        self.interpolate(pressure)
+    }
+    /// Piecewise linear through the control points.
+    fn interpolate(&self, x: f32) -> f32 {
+        let mut low = [0.0, 0.0];
+        for &high in self.points.iter().chain(std::iter::once(&[1.0, 1.0])) {
+            if x <= high[0] {
+                let span = high[0] - low[0];
+                if span <= 0.0 {
+                    return high[1];
+                }
+                return low[1] + (x - low[0]) / span * (high[1] - low[1]);
+            }
+            low = high;
+        }
+        // NaN, which a `NicheF32` never holds.
+        low[1]
+    }
+}
+
+/// Rewrite the pressures of a pump's worth of events according to the curve.
+pub(crate) fn apply(events: &mut [RawEvent<InternalID>], curve: &PressureCurve) {
+    for event in events {
+        let RawEvent::Tool {
+            event: RawTool::Pose(pose),
+            ..
+        } = event
+        else {
+            continue;
+        };
+        if let Some(pressure) = pose.pressure.get() {
+            pose.pressure = NicheF32::new_some(curve.apply(pressure)).unwrap_or(NicheF32::NONE);
+        }
+    }
+}
@@ -0,0 +1,303 @@
+//! # Mock backend
+//!
+//! A headless backend for testing code that consumes this crate, driven by hand through a [`Controller`] rather than
+//! by any real hardware. Build one with [`Builder::build_mock`](crate::Builder::build_mock), tell the controller what
+//! to report, and see what [`Manager::pump`](crate::Manager::pump) comes up with.
+//!
+//! Everything sent through the controller shows up on the next pump, in the order it was sent. Devices and events
+//! are described with the helpers and types of the [`custom`](crate::custom) module, which this is built upon - as
+//! such, the manager reports itself as a [`Backend::Custom`](crate::Backend::Custom), and the same rules about
+//! device IDs apply.
+//!
+//! ```
+//! use octotablet::{custom, events::{Event, ToolEvent}};
+//! let (mut manager, controller) = octotablet::Builder::default().build_mock();
+//! controller.add_tablet(custom::tablet(0));
+//! controller.add_tool(custom::tool(0));
+//! controller.stroke(0, 0, [[0.0, 0.0], [10.0, 5.0]].map(|position| octotablet::axis::Pose {
+//!     position,
+//!     ..Default::default()
+//! }));
+//!
+//! let downs = manager
+//!     .pump()
+//!     .unwrap()
+//!     .into_iter()
+//!     .filter(|event| matches!(event, Event::Tool { event: ToolEvent::Down, .. }))
+//!     .count();
+//! assert_eq!(downs, 1);
+//! ```
+//!
+//! The crate's own processing applies as usual, however the manager is pumped:
+//! ```
+//! use octotablet::{custom, events::{Event, ToolEvent}};
+//! let (mut manager, controller) = octotablet::Builder::default()
+//!     .suppress_duplicate_poses(true)
+//!     .build_mock();
+//! controller.add_tablet(custom::tablet(0));
+//! controller.add_tool(custom::tool(0));
+//! controller.stroke(0, 0, [octotablet::axis::Pose::default(); 6]);
+//!
+//! let (events, _) = manager.pump_budgeted(std::time::Duration::from_secs(3600)).unwrap();
+//! let poses = events
+//!     .into_iter()
+//!     .filter(|event| matches!(event, Event::Tool { event: ToolEvent::Pose(_), .. }))
+//!     .count();
+//! assert_eq!(poses, 1);
+//! ```
+
+use crate::{
+    axis::Pose,
+    custom::{self, CustomBackend, Event, ToolEvent},
+    pad::Pad,
+    tablet::Tablet,
+    tool::Tool,
+};
+
+/// Things for the backend to do upon the next pump, in order.
+enum Command {
+    AddTablet(Tablet),
+    AddTool(Tool),
+    AddPad(Pad),
+    RemoveTablet(u64),
+    RemoveTool(u64),
+    RemovePad(u64),
+    Event(Event),
+    Fail(custom::Error),
+    CursorPosition(Option<[f32; 2]>),
+}
+
+/// Handle for telling a mock backend what to report. Cheap to clone, and may be sent to other threads.
+///
+/// Once the [`Manager`](crate::Manager) is dropped, everything sent is silently ignored.
+#[derive(Clone)]
+pub struct Controller {
+    commands: std::sync::mpsc::Sender<Command>,
+}
+impl std::fmt::Debug for Controller {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Controller").finish_non_exhaustive()
+    }
+}
+impl Controller {
+    fn send(&self, command: Command) {
+        // Nobody left to report to, that's fine.
+        let _ = self.commands.send(command);
+    }
+    /// Plug in a tablet, made with [`custom::tablet`]. Reports [`Added`](crate::events::TabletEvent::Added).
+    pub fn add_tablet(&self, tablet: Tablet) {
+        self.send(Command::AddTablet(tablet));
+    }
+    /// Plug in a tool, made with [`custom::tool`]. Reports [`Added`](crate::events::ToolEvent::Added).
+    pub fn add_tool(&self, tool: Tool) {
+        self.send(Command::AddTool(tool));
+    }
+    /// Plug in a pad, made with [`custom::pad`]. Reports [`Added`](crate::events::PadEvent::Added).
+    pub fn add_pad(&self, pad: Pad) {
+        self.send(Command::AddPad(pad));
+    }
+    /// Unplug the tablet with the given ID. Reports [`Removed`](crate::events::TabletEvent::Removed), with the
+    /// tablet sticking around until the pump after.
+    pub fn remove_tablet(&self, id: u64) {
+        self.send(Command::RemoveTablet(id));
+    }
+    /// Unplug the tool with the given ID. See [`Controller::remove_tablet`].
+    pub fn remove_tool(&self, id: u64) {
+        self.send(Command::RemoveTool(id));
+    }
+    /// Unplug the pad with the given ID. See [`Controller::remove_tablet`].
+    pub fn remove_pad(&self, id: u64) {
+        self.send(Command::RemovePad(id));
+    }
+    /// Report an event as-is. Device `Added` and `Removed` events are better sent through the methods above, which
+    /// keep the device lists in sync with them.
+    pub fn push(&self, event: Event) {
+        self.send(Command::Event(event));
+    }
+    /// Report several events as-is, in order. See [`Controller::push`].
+    pub fn extend(&self, events: impl IntoIterator<Item = Event>) {
+        events.into_iter().for_each(|event| self.push(event));
+    }
+    /// Report a whole stroke of the given tool over the given tablet - in, down, one frame per pose, up, and out.
+    ///
+    /// ```
+    /// use octotablet::{custom, events::{Event, ToolEvent}, region::Rect};
+    /// let (mut manager, controller) = octotablet::Builder::default().build_mock();
+    /// // Frames are held back until they're known to be inside, so this checks they all get closed.
+    /// manager.set_hit_region(Some(Rect { min: [0.0; 2], max: [100.0; 2] }));
+    /// controller.add_tablet(custom::tablet(0));
+    /// controller.add_tool(custom::tool(0));
+    /// controller.stroke(0, 0, [[1.0, 1.0], [2.0, 2.0]].map(|position| octotablet::axis::Pose {
+    ///     position,
+    ///     ..Default::default()
+    /// }));
+    ///
+    /// let seen: Vec<_> = manager
+    ///     .pump()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .filter_map(|event| match event {
+    ///         Event::Tool { event, .. } => Some(match event {
+    ///             ToolEvent::Added => "added",
+    ///             ToolEvent::In { .. } => "in",
+    ///             ToolEvent::Down => "down",
+    ///             ToolEvent::Pose(_) => "pose",
+    ///             ToolEvent::Frame(_) => "frame",
+    ///             ToolEvent::Up => "up",
+    ///             ToolEvent::Out => "out",
+    ///             _ => "other",
+    ///         }),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// assert_eq!(
+    ///     seen,
+    ///     ["added", "in", "down", "pose", "frame", "pose", "frame", "up", "frame", "out", "frame"],
+    /// );
+    /// ```
+    pub fn stroke(&self, tool: u64, tablet: u64, poses: impl IntoIterator<Item = Pose>) {
+        let tool_event = |event| Event::Tool { tool, event };
+        self.push(tool_event(ToolEvent::In { tablet }));
+        self.push(tool_event(ToolEvent::Down));
+        for pose in poses {
+            self.push(tool_event(ToolEvent::Pose(pose)));
+            self.push(tool_event(ToolEvent::Frame(None)));
+        }
+        // Every backend closes these with a frame too, the crate holds back what isn't.
+        self.push(tool_event(ToolEvent::Up));
+        self.push(tool_event(ToolEvent::Frame(None)));
+        self.push(tool_event(ToolEvent::Out));
+        self.push(tool_event(ToolEvent::Frame(None)));
+    }
+    /// Make a pump fail with the given error, as a [`PumpError::Custom`](crate::PumpError::Custom). Anything sent
+    /// before this is still reported first, with the failure left for the pump after if need be, and anything sent
+    /// after is left for the pumps following the failure.
+    pub fn fail_pump(&self, error: impl Into<custom::Error>) {
+        self.send(Command::Fail(error.into()));
+    }
+    /// Set what [`Manager::cursor_position`](crate::Manager::cursor_position) reports, starting from the next pump.
+    pub fn set_cursor_position(&self, position: Option<[f32; 2]>) {
+        self.send(Command::CursorPosition(position));
+    }
+}
+
+/// Create a connected backend and controller.
+pub(crate) fn new() -> (impl CustomBackend, Controller) {
+    let (commands, receiver) = std::sync::mpsc::channel();
+    (
+        Backend {
+            commands: receiver,
+            tablets: Vec::new(),
+            tools: Vec::new(),
+            pads: Vec::new(),
+            removed: Vec::new(),
+            failure: None,
+            cursor_position: None,
+        },
+        Controller { commands },
+    )
+}
+
+/// The kind of device pending removal.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DeviceKind {
+    Tablet,
+    Tool,
+    Pad,
+}
+
+struct Backend {
+    commands: std::sync::mpsc::Receiver<Command>,
+    tablets: Vec<Tablet>,
+    tools: Vec<Tool>,
+    pads: Vec<Pad>,
+    /// Devices reported removed last pump, to be dropped this pump.
+    removed: Vec<(DeviceKind, u64)>,
+    /// A failure sent after events, to be reported once those are.
+    failure: Option<custom::Error>,
+    cursor_position: Option<[f32; 2]>,
+}
+impl CustomBackend for Backend {
+    fn pump(&mut self, events: &mut Vec<Event>) -> Result<(), custom::Error> {
+        use crate::events::TabletEvent;
+        use custom::PadEvent;
+        if let Some(error) = self.failure.take() {
+            return Err(error);
+        }
+        for (kind, id) in std::mem::take(&mut self.removed) {
+            let is_kept = |internal_id: &crate::InternalID| *internal_id.unwrap_custom() != id;
+            match kind {
+                DeviceKind::Tablet => self.tablets.retain(|tablet| is_kept(&tablet.internal_id)),
+                DeviceKind::Tool => self.tools.retain(|tool| is_kept(&tool.internal_id)),
+                DeviceKind::Pad => self.pads.retain(|pad| is_kept(&pad.internal_id)),
+            }
+        }
+        while let Ok(command) = self.commands.try_recv() {
+            match command {
+                Command::AddTablet(tablet) => {
+                    events.push(Event::Tablet {
+                        tablet: *tablet.internal_id.unwrap_custom(),
+                        event: TabletEvent::Added,
+                    });
+                    self.tablets.push(tablet);
+                }
+                Command::AddTool(tool) => {
+                    events.push(Event::Tool {
+                        tool: *tool.internal_id.unwrap_custom(),
+                        event: ToolEvent::Added,
+                    });
+                    self.tools.push(tool);
+                }
+                Command::AddPad(pad) => {
+                    events.push(Event::Pad {
+                        pad: *pad.internal_id.unwrap_custom(),
+                        event: PadEvent::Added,
+                    });
+                    self.pads.push(pad);
+                }
+                Command::RemoveTablet(tablet) => {
+                    events.push(Event::Tablet {
+                        tablet,
+                        event: TabletEvent::Removed,
+                    });
+                    self.removed.push((DeviceKind::Tablet, tablet));
+                }
+                Command::RemoveTool(tool) => {
+                    events.push(Event::Tool {
+                        tool,
+                        event: ToolEvent::Removed,
+                    });
+                    self.removed.push((DeviceKind::Tool, tool));
+                }
+                Command::RemovePad(pad) => {
+                    events.push(Event::Pad {
+                        pad,
+                        event: PadEvent::Removed,
+                    });
+                    self.removed.push((DeviceKind::Pad, pad));
+                }
+                Command::Event(event) => events.push(event),
+                // Failing now would lose the events so far.
+                Command::Fail(error) if events.is_empty() => return Err(error),
+                Command::Fail(error) => {
+                    self.failure = Some(error);
+                    break;
+                }
+                Command::CursorPosition(position) => self.cursor_position = position,
+            }
+        }
+        Ok(())
+    }
+    fn pads(&self) -> &[Pad] {
+        &self.pads
+    }
+    fn tools(&self) -> &[Tool] {
+        &self.tools
+    }
+    fn tablets(&self) -> &[Tablet] {
+        &self.tablets
+    }
+    fn cursor_position(&self) -> Option<[f32; 2]> {
+        self.cursor_position
+    }
+}
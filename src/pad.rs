@@ -1,7 +1,7 @@
 //! # Pads
 //!
 //! Pads represent collections of additional controls that a tablet provides on its surface, including
-//! buttons, mode toggles, sliders, wheels, etc. There are typically zero or one pads per [tablet](crate::tablet).
+//! buttons, mode toggles, sliders, wheels, dials, etc. There are typically zero or one pads per [tablet](crate::tablet).
 //!
 //! A single pad may be further divided into "groups" if several physical clusters of interactables are
 //! present - for example, on the *Wacom Cintiq 22HD* a left and right group may be reported. These groups may have
@@ -14,11 +14,13 @@
 //! emulate keypresses in the driver in response to button clicks, which is transparent to the client and thus not able to be
 //! reported by this crate.
 
+pub use dial::Dial;
 pub use group::Group;
 pub use ring::Ring;
 pub use strip::Strip;
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Pad {
     pub(crate) internal_id: crate::InternalID,
     /// How many buttons total are on this pad? Buttons may be further reserved by groups, see [`Group::buttons`] for associating
@@ -27,8 +29,30 @@ pub struct Pad {
     /// Groups within this pad. Always at least one.
     // (todo: make that a type-level guarantee)
     pub groups: Vec<Group>,
+    pub(crate) tablet: Option<crate::InternalID>,
 }
 crate::util::macro_bits::impl_get_id!(ID for Pad);
+impl Pad {
+    /// The tablet this pad currently belongs to, as last announced by its
+    /// [`Enter`](crate::events::PadEvent::Enter) and [`Exit`](crate::events::PadEvent::Exit) events.
+    /// See also [`Manager::pads_for`](crate::Manager::pads_for).
+    ///
+    /// Pads may come and go from tablets dynamically, and a freshly [added](crate::events::PadEvent::Added) pad
+    /// typically won't know its tablet until the next pump. `None` when not (or not yet) associated with any tablet.
+    #[must_use]
+    pub fn tablet(&self) -> Option<crate::tablet::ID> {
+        self.tablet.clone().map(crate::tablet::ID)
+    }
+    /// A copy of this pad, minus the [feedback](Group::feedback) callbacks which can't be copied.
+    pub(crate) fn snapshot(&self) -> Self {
+        Self {
+            internal_id: self.internal_id.clone(),
+            total_buttons: self.total_buttons,
+            groups: self.groups.iter().map(Group::snapshot).collect(),
+            tablet: self.tablet.clone(),
+        }
+    }
+}
 // Submodules for nicer ID names.
 pub mod group {
     /// The type of interactable being queried in a [`FeedbackFn`]
@@ -43,21 +67,41 @@ pub mod group {
 
     /// After the group switches to the given mode index, provide feedback to the OS as to the roles
     /// of buttons, sliders, and rings within the group. Called potentially many times per mode switch.
-    pub type FeedbackFn = dyn FnMut(&Group, u32, FeedbackElement<'_>) -> String;
+    ///
+    /// `Send` and `Sync`, so that pads may be [sent](crate::snapshot) to other threads like the rest of the devices.
+    pub type FeedbackFn = dyn FnMut(&Group, u32, FeedbackElement<'_>) -> String + Send + Sync;
 
     /// A Pad reports one or more Groups. See the [pad module docs](crate::pad) for more info.
+    #[non_exhaustive]
     pub struct Group {
         pub(crate) internal_id: crate::InternalID,
         /// How many mode layers does this group cycle through?
         /// If None, the group does not expose the ability to shift through modes.
         pub mode_count: Option<std::num::NonZeroU32>,
+        /// How many LEDs the hardware has for showing the current mode, `Some(0)` if it has none. This is often,
+        /// but not always, the same as the [`mode_count`](Group::mode_count) - groups lacking indicators of
+        /// their own may be worth drawing an on-screen mode indicator for.
+        ///
+        /// `None` is unknown.
+        ///
+        /// # Platform support
+        /// * Always `None` on built-in backends, as no platform's tablet protocol reports it.
+        /// * Custom backends - whatever the backend fills in.
+        pub mode_indicators: Option<u32>,
         /// Sorted list of the pad button indices that are owned by this group.
         /// This is some subset of the [buttons reported by the Pad](super::Pad::total_buttons).
         pub buttons: Vec<u32>,
-        /// The set of rings belonging to this group.
+        /// The set of rings belonging to this group, in hardware order (see [`Ring::index_in_group`](super::Ring::index_in_group)).
         pub rings: Vec<super::Ring>,
-        /// The set of strips belonging to this strip.
+        /// The set of strips belonging to this group, in hardware order (see [`Strip::index_in_group`](super::Strip::index_in_group)).
         pub strips: Vec<super::Strip>,
+        /// The set of dials belonging to this group, in hardware order (see [`Dial::index_in_group`](super::Dial::index_in_group)).
+        ///
+        /// # Platform support
+        /// * Wayland - Only from version 2 of the tablet protocol, empty otherwise.
+        /// * Windows Ink - Never, pads aren't reported at all.
+        /// * Custom backends - Whatever the backend fills in.
+        pub dials: Vec<super::Dial>,
         /// Called synchronously for each group element (buttons, rings, and strips) after a modeswitch on supporting platforms.
         /// Provides new description text for the roles of each element, which may be shown by on-screen displays or other means.
         ///
@@ -70,15 +114,32 @@ pub mod group {
             let mut this = fmt.debug_struct("PadGroup");
             this.field("internal_id", &self.internal_id);
             this.field("mode_count", &self.mode_count);
+            this.field("mode_indicators", &self.mode_indicators);
             this.field("buttons", &self.buttons);
             this.field("rings", &self.rings);
             this.field("strips", &self.strips);
+            this.field("dials", &self.dials);
             // !Debug, so just opaquely show whether it's some or None
             this.field("feeback", &self.feedback.as_ref().map(|_| "..."));
             this.finish()
         }
     }
     crate::util::macro_bits::impl_get_id!(ID for Group);
+    impl Group {
+        /// A copy of this group without its [feedback](Group::feedback).
+        pub(crate) fn snapshot(&self) -> Self {
+            Self {
+                internal_id: self.internal_id.clone(),
+                mode_count: self.mode_count,
+                mode_indicators: self.mode_indicators,
+                buttons: self.buttons.clone(),
+                rings: self.rings.clone(),
+                strips: self.strips.clone(),
+                dials: self.dials.clone(),
+                feedback: None,
+            }
+        }
+    }
 }
 
 /// The cause of a ring or strip interaction.
@@ -90,21 +151,63 @@ pub enum TouchSource {
 }
 pub mod ring {
     /// A continuous circular touch-sensitive area or scrollwheel, reporting absolute position in radians clockwise from "logical north."
-    #[derive(Debug)]
+    ///
+    /// North is that of the hardware, which for tablets turned around (such as for left-handed use) is not the user's.
+    /// See [`Tablet::rotation`](crate::tablet::Tablet::rotation).
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct Ring {
         pub(crate) internal_id: crate::InternalID,
         /// Granularity of the reported angle, if known.
         pub granularity: Option<crate::axis::Granularity>,
+        pub(crate) index_in_group: usize,
     }
     crate::util::macro_bits::impl_get_id!(ID for Ring);
+    impl Ring {
+        /// The position of this ring within its [group's](crate::pad::Group::rings) rings, as ordered by the hardware.
+        /// This is stable across runs with the same device, suitable for labeling e.g. "Ring 1" and "Ring 2".
+        #[must_use]
+        pub fn index_in_group(&self) -> usize {
+            self.index_in_group
+        }
+    }
 }
 pub mod strip {
     /// A touch-sensitive strip or slider, reporting absolute position in `0..=1` where 0 is "logical top/left."
-    #[derive(Debug)]
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct Strip {
         pub(crate) internal_id: crate::InternalID,
         /// Granularity of the reported linear position, if known.
         pub granularity: Option<crate::axis::Granularity>,
+        pub(crate) index_in_group: usize,
     }
     crate::util::macro_bits::impl_get_id!(ID for Strip);
+    impl Strip {
+        /// The position of this strip within its [group's](crate::pad::Group::strips) strips, as ordered by the hardware.
+        /// This is stable across runs with the same device, suitable for labeling e.g. "Strip 1" and "Strip 2".
+        #[must_use]
+        pub fn index_in_group(&self) -> usize {
+            self.index_in_group
+        }
+    }
+}
+pub mod dial {
+    /// A relative knob, reporting how far it was turned rather than where it is, like the dial on some
+    /// *Wacom Intuos Pro* models. See [`DialEvent`](crate::events::DialEvent).
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    pub struct Dial {
+        pub(crate) internal_id: crate::InternalID,
+        pub(crate) index_in_group: usize,
+    }
+    crate::util::macro_bits::impl_get_id!(ID for Dial);
+    impl Dial {
+        /// The position of this dial within its [group's](crate::pad::Group::dials) dials, as ordered by the hardware.
+        /// This is stable across runs with the same device, suitable for labeling e.g. "Dial 1" and "Dial 2".
+        #[must_use]
+        pub fn index_in_group(&self) -> usize {
+            self.index_in_group
+        }
+    }
 }
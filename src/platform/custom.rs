@@ -0,0 +1,202 @@
+//! Adaptor from a user-provided [`CustomBackend`] to the internal platform interface.
+//!
+//! Within this module, it is sound to assume `cfg(custom_backend) == true`.
+
+use crate::{
+    custom::{self, CustomBackend},
+    events::raw as raw_events,
+};
+
+pub type ID = u64;
+/// Newtyped, as to not collide with the other backend's IDs in the `From` impls.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ButtonID(pub(crate) u32);
+
+pub struct Manager {
+    backend: Box<dyn CustomBackend>,
+    /// See [`crate::Builder::capture_touch`].
+    capture_touch: bool,
+    /// See [`crate::Builder::report_raw_axes`].
+    report_raw_axes: bool,
+    /// Events as reported by the backend. Kept around to re-use the alloc.
+    pending: Vec<custom::Event>,
+    events: Vec<raw_events::Event<ID>>,
+}
+impl Manager {
+    pub(crate) fn new(
+        backend: Box<dyn CustomBackend>,
+        capture_touch: bool,
+        report_raw_axes: bool,
+    ) -> Self {
+        Self {
+            backend,
+            capture_touch,
+            report_raw_axes,
+            pending: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+fn convert_tool(event: custom::ToolEvent) -> raw_events::ToolEvent<ID> {
+    use custom::ToolEvent;
+    match event {
+        ToolEvent::Added => raw_events::ToolEvent::Added,
+        ToolEvent::Removed => raw_events::ToolEvent::Removed,
+        // Custom backends have no notion of windows.
+        ToolEvent::In { tablet } => raw_events::ToolEvent::In {
+            tablet,
+            window: None,
+        },
+        ToolEvent::Down => raw_events::ToolEvent::Down,
+        ToolEvent::Button { button_id, pressed } => raw_events::ToolEvent::Button {
+            button_id: ButtonID(button_id).into(),
+            pressed,
+        },
+        ToolEvent::Pose(pose) => raw_events::ToolEvent::Pose(pose),
+        ToolEvent::Tracking(pose) => raw_events::ToolEvent::Tracking(pose),
+        ToolEvent::Extras(extras) => raw_events::ToolEvent::Extras(extras),
+        ToolEvent::RawPose(raw) => raw_events::ToolEvent::RawPose(raw),
+        ToolEvent::Frame(time) => raw_events::ToolEvent::Frame(time),
+        ToolEvent::Up => raw_events::ToolEvent::Up,
+        ToolEvent::Out => raw_events::ToolEvent::Out,
+    }
+}
+fn convert_pad(event: custom::PadEvent) -> raw_events::PadEvent<ID> {
+    use custom::{PadEvent, PadGroupEvent};
+    match event {
+        PadEvent::Added => raw_events::PadEvent::Added,
+        PadEvent::Removed => raw_events::PadEvent::Removed,
+        PadEvent::Group { group, event } => raw_events::PadEvent::Group {
+            group,
+            event: match event {
+                PadGroupEvent::Ring { ring, event } => {
+                    raw_events::PadGroupEvent::Ring { ring, event }
+                }
+                PadGroupEvent::Strip { strip, event } => {
+                    raw_events::PadGroupEvent::Strip { strip, event }
+                }
+                PadGroupEvent::Dial { dial, event } => {
+                    raw_events::PadGroupEvent::Dial { dial, event }
+                }
+                PadGroupEvent::Mode(mode) => raw_events::PadGroupEvent::Mode(mode),
+            },
+        },
+        PadEvent::Button {
+            button_idx,
+            pressed,
+        } => raw_events::PadEvent::Button {
+            button_idx,
+            pressed,
+        },
+        PadEvent::Enter { tablet } => raw_events::PadEvent::Enter { tablet },
+        PadEvent::Exit => raw_events::PadEvent::Exit,
+    }
+}
+fn convert(event: custom::Event) -> raw_events::Event<ID> {
+    match event {
+        custom::Event::Tool { tool, event } => raw_events::Event::Tool {
+            tool,
+            event: convert_tool(event),
+        },
+        custom::Event::Tablet { tablet, event } => raw_events::Event::Tablet {
+            tablet,
+            event: match event {
+                crate::events::TabletEvent::Added => raw_events::TabletEvent::Added,
+                crate::events::TabletEvent::Removed => raw_events::TabletEvent::Removed,
+            },
+        },
+        custom::Event::Pad { pad, event } => raw_events::Event::Pad {
+            pad,
+            event: convert_pad(event),
+        },
+        custom::Event::EnumerationComplete => raw_events::Event::EnumerationComplete,
+        custom::Event::WindowFocus { focused } => raw_events::Event::WindowFocus { focused },
+        custom::Event::Touch(touch) => raw_events::Event::Touch(touch),
+        custom::Event::System(system) => raw_events::Event::System(system),
+    }
+}
+
+impl super::PlatformImpl for Manager {
+    fn pump(&mut self) -> Result<(), crate::PumpError> {
+        self.pending.clear();
+        self.backend
+            .pump(&mut self.pending)
+            .map_err(crate::PumpError::Custom)?;
+        self.events.clear();
+        let (capture_touch, report_raw_axes) = (self.capture_touch, self.report_raw_axes);
+        self.events.extend(
+            self.pending
+                .drain(..)
+                .filter(|event| match event {
+                    custom::Event::Touch(_) => capture_touch,
+                    custom::Event::Tool {
+                        event: custom::ToolEvent::RawPose(_),
+                        ..
+                    } => report_raw_axes,
+                    _ => true,
+                })
+                .map(convert),
+        );
+        Ok(())
+    }
+    fn cursor_position(&self) -> Option<[f32; 2]> {
+        self.backend.cursor_position()
+    }
+    fn timestamp_granularity(&self) -> Option<std::time::Duration> {
+        self.backend.timestamp_granularity()
+    }
+    fn protocol_versions(&self) -> Option<crate::ProtocolVersions> {
+        None
+    }
+    fn backend_info(&self) -> crate::BackendInfo {
+        crate::BackendInfo::Custom
+    }
+    fn accessibility(&self) -> crate::Accessibility {
+        self.backend.accessibility()
+    }
+    fn hide_cursor_while_down(&mut self, hide: bool) {
+        self.backend.hide_cursor_while_down(hide);
+    }
+    fn set_hit_region(&mut self, _: Option<crate::region::Rect>) {}
+    fn add_window(
+        &mut self,
+        _: raw_window_handle::RawWindowHandle,
+        _: crate::WindowId,
+    ) -> Result<(), crate::AddWindowError> {
+        Err(crate::AddWindowError::Unsupported)
+    }
+    fn remove_window(&mut self, _: crate::WindowId) {}
+    fn set_feedback(
+        &mut self,
+        target: super::FeedbackTarget<'_>,
+        description: &str,
+    ) -> Result<(), crate::FeedbackError> {
+        use super::FeedbackTarget;
+        let target = match target {
+            FeedbackTarget::Button { pad, button_idx } => custom::Feedback::Button {
+                pad: *pad.unwrap_custom(),
+                button_idx,
+            },
+            FeedbackTarget::Ring(ring) => custom::Feedback::Ring(*ring.unwrap_custom()),
+            FeedbackTarget::Strip(strip) => custom::Feedback::Strip(*strip.unwrap_custom()),
+        };
+        self.backend.set_feedback(target, description)
+    }
+    fn debug_dump(&self) -> Option<String> {
+        // The user owns the backend, they can instrument it however they please.
+        None
+    }
+    fn pads(&self) -> &[crate::pad::Pad] {
+        self.backend.pads()
+    }
+    fn tools(&self) -> &[crate::tool::Tool] {
+        self.backend.tools()
+    }
+    fn tablets(&self) -> &[crate::tablet::Tablet] {
+        self.backend.tablets()
+    }
+    fn raw_events(&self) -> super::RawEventsIter<'_> {
+        super::RawEventsIter::Custom(self.events.iter())
+    }
+}
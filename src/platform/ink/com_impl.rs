@@ -5,7 +5,7 @@ use windows::Win32::Foundation::{E_FAIL, E_INVALIDARG, E_POINTER, POINT};
 use windows::Win32::System::Com as com;
 use windows::Win32::UI::TabletPC as tablet_pc;
 
-use super::{fetch_himetric_to_logical_pixel, ButtonID, Plugin, StylusPhase};
+use super::{ButtonID, Plugin, StylusPhase};
 
 use std::{panic::AssertUnwindSafe, sync::PoisonError};
 
@@ -65,10 +65,11 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
     fn StylusOutOfRange(
         &self,
         rts: Option<&tablet_pc::IRealTimeStylus>,
-        _tcid: u32,
+        tcid: u32,
         sid: u32,
     ) -> WinResult<()> {
         self.panic_wrapper(AssertUnwindSafe(|| {
+            self.log(|| format!("StylusOutOfRange(tcid: {tcid}, sid: {sid})"));
             self.poison_bail()?;
             // Should only ever be called with the RTS we made.
             if rts != Some(&self.rts) {
@@ -79,6 +80,9 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
                 .lock()
                 .unwrap_or_else(PoisonError::into_inner);
 
+            // Fingers aren't tools, and going out of range is just lifting.
+            lock.lift_touch(sid);
+
             // Get the full ID of this (sid aint enough!)
             let Some(tool) = lock.get_tool(sid) else {
                 return Ok(());
@@ -146,6 +150,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
                     _ => return Err(E_INVALIDARG.into()),
                 }
             };
+            self.log(|| format!("RealTimeStylusEnabled(tcids: {tcids:?})"));
             let mut lock = self
                 .shared_frame
                 .lock()
@@ -156,6 +161,16 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
                     lock.append_tablet(&self.rts, &tablet, tcid);
                 }
             }
+            // Every tablet is reported at once here, so that's the whole initial burst.
+            // (Styluses are only ever introduced upon first use.)
+            // Relaxed is ok, no memory accesses are synchronized by this atomic.
+            if !self
+                .announced
+                .swap(true, std::sync::atomic::Ordering::Relaxed)
+            {
+                lock.events
+                    .push(crate::events::raw::Event::EnumerationComplete);
+            }
 
             poison.disarm();
             Ok(())
@@ -197,6 +212,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
 
             // This is not an optional field, according to spec.
             let stylus_info = unsafe { stylus_info.as_ref() }.ok_or(E_POINTER)?;
+            self.log(|| format!("StylusDown({stylus_info:?}, props: {props:?})"));
             let mut lock = self
                 .shared_frame
                 .lock()
@@ -246,6 +262,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
 
             // This is not an optional field, according to spec.
             let stylus_info = unsafe { stylus_info.as_ref() }.ok_or(E_POINTER)?;
+            self.log(|| format!("StylusUp({stylus_info:?}, props: {props:?})"));
             let mut lock = self
                 .shared_frame
                 .lock()
@@ -275,6 +292,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
                 return Err(E_POINTER.into());
             }
             let button_guid = unsafe { *button_guid };
+            self.log(|| format!("StylusButtonDown(sid: {sid}, button: {button_guid:?})"));
 
             let mut lock = self
                 .shared_frame
@@ -313,6 +331,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
                 return Err(E_POINTER.into());
             }
             let button_guid = unsafe { *button_guid };
+            self.log(|| format!("StylusButtonUp(sid: {sid}, button: {button_guid:?})"));
 
             let mut lock = self
                 .shared_frame
@@ -384,6 +403,11 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
 
             // This is not an optional field, according to spec.
             let stylus_info = unsafe { stylus_info.as_ref() }.ok_or(E_POINTER)?;
+            self.log(|| {
+                format!(
+                    "InAirPackets({stylus_info:?}, num_packets: {num_packets}, props: {props:?})"
+                )
+            });
             let mut lock = self
                 .shared_frame
                 .lock()
@@ -436,6 +460,9 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
 
             // This is not an optional field, according to spec.
             let stylus_info = unsafe { stylus_info.as_ref() }.ok_or(E_POINTER)?;
+            self.log(|| {
+                format!("Packets({stylus_info:?}, num_packets: {num_packets}, props: {props:?})")
+            });
             let mut lock = self
                 .shared_frame
                 .lock()
@@ -472,6 +499,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
             let tablet = tablet.ok_or(E_POINTER)?;
 
             let tcid = unsafe { rts.GetTabletContextIdFromTablet(tablet) }?;
+            self.log(|| format!("TabletAdded(tcid: {tcid})"));
 
             let mut lock = self
                 .shared_frame
@@ -490,6 +518,7 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
         tablet_idx: i32,
     ) -> WinResult<()> {
         self.panic_wrapper(AssertUnwindSafe(|| {
+            self.log(|| format!("TabletRemoved(idx: {tablet_idx})"));
             // Poison section - we need to set our internal tablet array to match RTS or bad stuff happens.
             let poison = self.poison_on_drop()?;
 
@@ -512,12 +541,13 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
     fn UpdateMapping(&self, _: Option<&tablet_pc::IRealTimeStylus>) -> WinResult<()> {
         self.panic_wrapper(AssertUnwindSafe(|| {
             // Called on DPI change, need to re-fetch the conversion factor from HIMETRIC to logical pixels.
+            self.log(|| "UpdateMapping".to_owned());
             self.poison_bail()?;
             let mut lock = self
                 .shared_frame
                 .lock()
                 .unwrap_or_else(PoisonError::into_inner);
-            lock.himetric_to_logical_pixel = unsafe { fetch_himetric_to_logical_pixel(lock.hwnd) };
+            unsafe { lock.update_scale_factor() };
 
             Ok(())
         }))
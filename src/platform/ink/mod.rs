@@ -6,21 +6,38 @@
 use std::sync;
 
 use windows::core::{self, Result as WinResult};
-use windows::Win32::Foundation::{E_FAIL, HANDLE_PTR, HWND};
+use windows::Win32::Foundation::{E_FAIL, HANDLE_PTR, HWND, RECT, RPC_E_CHANGED_MODE};
 use windows::Win32::System::Com as com;
 use windows::Win32::UI::TabletPC as tablet_pc;
 
+use crate::builder::CoordinateSpace;
+
 const HIMETRIC_PER_INCH: f32 = 2540.0;
 
 mod com_impl;
 mod packet;
 
+/// The DPI at which logical pixels are physical pixels.
+const LOGICAL_DPI: f32 = 96.0;
+
 /// Multiplier to get from HIMETRIC physical units to logical pixel space for the given window. This should be re-called frequently
 /// to handle dynamic DPI (icky, but that's [microsoft's word](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforsystem#remarks) not mine!)
+///
+/// "Logical" here is in the sense of the window's own client pixels, unless asked for [`CoordinateSpace::Logical`]
+/// which sidesteps DPI altogether.
+/// # Safety
+/// `hwnd` must be a valid window handle.
+unsafe fn fetch_himetric_to_logical_pixel(hwnd: HWND, space: CoordinateSpace) -> f32 {
+    if space == CoordinateSpace::Logical {
+        LOGICAL_DPI / HIMETRIC_PER_INCH
+    } else {
+        unsafe { fetch_dpi(hwnd) / HIMETRIC_PER_INCH }
+    }
+}
 /// # Safety
 /// `hwnd` must be a valid window handle.
 #[allow(clippy::cast_precision_loss)]
-unsafe fn fetch_himetric_to_logical_pixel(hwnd: HWND) -> f32 {
+unsafe fn fetch_dpi(hwnd: HWND) -> f32 {
     // The value of this call depends on the *thread's* DPI awareness registration with the system.
     // That's left to the calling thread, but this *should* always work on any of the awareness settings.
 
@@ -34,7 +51,56 @@ unsafe fn fetch_himetric_to_logical_pixel(hwnd: HWND) -> f32 {
     }
 
     // Rounding is ok - in practice, this is really not a large value. Will never be NaN or INF which is the real problem.
-    (dpi as f32) / HIMETRIC_PER_INCH
+    dpi as f32
+}
+
+/// Erasers share the tablet's axes, of which tilt and roll are often not reported by the eraser end itself. These
+/// are only advertised for an eraser once it's seen reporting anything but zero, and stripped from its poses until
+/// then, so that UIs don't offer tilt-dependent options to an eraser that'll never use them.
+fn track_eraser_axes(
+    tool: &mut crate::tool::Tool,
+    tablet: &crate::axis::FullInfo,
+    pose: &mut crate::axis::Pose,
+) {
+    if tool.axes.tilt.is_none() {
+        if pose
+            .tilt
+            .is_some_and(|tilt| tilt.iter().any(|&angle| angle != 0.0))
+        {
+            tool.axes.tilt = tablet.tilt;
+        } else {
+            pose.tilt = None;
+        }
+    }
+    if tool.axes.roll.is_none() {
+        if pose.roll.get().is_some_and(|roll| roll != 0.0) {
+            tool.axes.roll = tablet.roll;
+        } else {
+            pose.roll = crate::util::NicheF32::NONE;
+        }
+    }
+}
+
+/// Shift every position in the events over by `by`, for [`CoordinateSpace::Screen`].
+fn offset_positions(events: &mut [crate::events::raw::Event<ID>], by: [f32; 2]) {
+    use crate::events::raw::{Event, ToolEvent};
+    use crate::touch::TouchEvent;
+    let offset = |position: &mut [f32; 2]| {
+        position[0] += by[0];
+        position[1] += by[1];
+    };
+    for event in events {
+        match event {
+            Event::Tool {
+                event: ToolEvent::Pose(pose) | ToolEvent::Tracking(pose),
+                ..
+            } => offset(&mut pose.position),
+            Event::Touch(TouchEvent::Down(finger) | TouchEvent::Move(finger)) => {
+                offset(&mut finger.position);
+            }
+            _ => (),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -45,6 +111,89 @@ pub enum ID {
     Stylus { cid: u32, cursor_id: Option<i32> },
 }
 
+/// Guess the role of a cursor button from its name. These are named after the HID usages, e.g. "Barrel Switch".
+fn button_role(name: &str) -> Option<crate::tool::ButtonRole> {
+    use crate::tool::ButtonRole;
+    let name = name.to_ascii_lowercase();
+    if name.contains("eraser") || name.contains("invert") {
+        Some(ButtonRole::Eraser)
+    } else if name.contains("tip") {
+        Some(ButtonRole::Primary)
+    } else if name.contains("barrel") {
+        if name.contains("secondary") || name.contains('2') {
+            Some(ButtonRole::Barrel2)
+        } else {
+            Some(ButtonRole::Barrel1)
+        }
+    } else {
+        None
+    }
+}
+
+/// The pointer size from the accessibility settings. This lives only in the registry, and is missing until the user first
+/// touches the slider, in which case the system metrics give the default size.
+fn cursor_size() -> Option<u32> {
+    use windows::Win32::Foundation::ERROR_SUCCESS;
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+    use windows::Win32::UI::WindowsAndMessaging::{GetSystemMetrics, SM_CXCURSOR};
+    let mut size = 0u32;
+    #[allow(clippy::cast_possible_truncation)]
+    let mut len = std::mem::size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            core::w!("Control Panel\\Cursors"),
+            core::w!("CursorBaseSize"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(std::ptr::addr_of_mut!(size).cast()),
+            Some(std::ptr::addr_of_mut!(len)),
+        )
+    };
+    if status == ERROR_SUCCESS && size > 0 {
+        return Some(size);
+    }
+    u32::try_from(unsafe { GetSystemMetrics(SM_CXCURSOR) })
+        .ok()
+        .filter(|&size| size > 0)
+}
+
+/// Whether a high contrast theme is in use.
+fn high_contrast() -> Option<bool> {
+    use windows::Win32::UI::Accessibility::{HCF_HIGHCONTRASTON, HIGHCONTRASTW};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, SPI_GETHIGHCONTRAST, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let mut contrast = HIGHCONTRASTW {
+        cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        SystemParametersInfoW(
+            SPI_GETHIGHCONTRAST,
+            contrast.cbSize,
+            Some(std::ptr::addr_of_mut!(contrast).cast()),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        )
+    }
+    .ok()?;
+    Some(contrast.dwFlags.contains(HCF_HIGHCONTRASTON))
+}
+
+/// Parse a GUID of the form `{6B29FC40-CA47-1067-B31D-00DD010662DA}`, braces optional.
+fn parse_guid(text: &str) -> Option<core::GUID> {
+    let text = text.trim_start_matches('{').trim_end_matches('}');
+    let hex: String = text.chars().filter(|&c| c != '-').collect();
+    // `from_str_radix` is happy with a leading sign, we aren't.
+    if text.len() != 36 || hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u128::from_str_radix(&hex, 16)
+        .ok()
+        .map(core::GUID::from_u128)
+}
+
 #[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct ButtonID(core::GUID);
 impl PartialOrd for ButtonID {
@@ -78,6 +227,9 @@ struct RawTablet {
     // To bridge this gap, this field will be virally spread to any tool that interacts with this tablet.
     axes: crate::axis::FullInfo,
     tcid: u32,
+    /// A touch digitizer, the "styluses" of which are fingers to be reported as touch events.
+    /// Only ever set if touch is being captured.
+    touch: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -98,6 +250,22 @@ impl RawTabletSlot {
             Self::Dummy { tcid } => *tcid,
         }
     }
+    pub fn is_touch(&self) -> bool {
+        matches!(self, Self::Concrete(RawTablet { touch: true, .. }))
+    }
+}
+
+/// How many pumps a tool may stay out of range after the tablet it was last seen on is removed,
+/// before it's presumed gone with it. Ink never tells us about tools going away.
+const TOOL_RETENTION_PUMPS: u32 = 60;
+
+/// Where and when a tool was last seen.
+#[derive(Clone, Copy, Debug)]
+struct ToolPresence {
+    /// The tablet the tool last interacted with.
+    tcid: u32,
+    /// Pumps since the tool was last in range.
+    idle_pumps: u32,
 }
 
 /// The full inner state. Each frame this is cloned out of the plugin for unbounded access by the user.
@@ -105,9 +273,15 @@ struct DataFrame {
     /// Cached value from [`fetch_himetric_to_logical_pixel`], updated when needed.
     hwnd: HWND,
     himetric_to_logical_pixel: f32,
+    /// See [`Builder::coordinate_space`](crate::Builder::coordinate_space).
+    coordinate_space: CoordinateSpace,
     /// Keep track of the current state of styluses. Whether included at all indicates In/Out state.
     stylus_states: std::collections::BTreeMap<ID, StylusPhase>,
     tools: Vec<crate::tool::Tool>,
+    /// Bookkeeping for retiring tools, see [`TOOL_RETENTION_PUMPS`].
+    tool_presence: std::collections::BTreeMap<ID, ToolPresence>,
+    /// Tools which have been queued for destruction after events are consumed.
+    tool_deletions: Vec<ID>,
     /// Indicies of `raw_tablets` which have been queued for destruction after events are consumed.
     /// This is the *actual* subscript into the list, *not* apparent index (index where deleted ones aren't counted)
     /// Must always be sorted and deduplicated.
@@ -118,6 +292,12 @@ struct DataFrame {
     /// User-visible tablets created from [`RawTabletSlot::Concrete`] tablets.
     tablets: Vec<crate::tablet::Tablet>,
     events: Vec<crate::events::raw::Event<ID>>,
+    /// See [`Builder::capture_touch`](crate::Builder::capture_touch).
+    capture_touch: bool,
+    /// See [`Builder::report_raw_axes`](crate::Builder::report_raw_axes).
+    report_raw_axes: bool,
+    /// The `cid`s of fingers currently down on a touch digitizer.
+    touches: std::collections::BTreeSet<u32>,
 }
 impl Clone for DataFrame {
     fn clone(&self) -> Self {
@@ -126,13 +306,19 @@ impl Clone for DataFrame {
             himetric_to_logical_pixel: 0.0,
             // uh oh. Replaced before anything icky occurs :P
             hwnd: HWND(0),
+            coordinate_space: CoordinateSpace::Native,
             // Empty vecs don't alloc, this is ok.
             raw_tablet_deletions: vec![],
             raw_tablets: vec![],
             stylus_states: std::collections::BTreeMap::new(),
             tools: vec![],
+            tool_presence: std::collections::BTreeMap::new(),
+            tool_deletions: vec![],
             tablets: vec![],
             events: vec![],
+            capture_touch: false,
+            report_raw_axes: false,
+            touches: std::collections::BTreeSet::new(),
         };
 
         clone.clone_from(self);
@@ -143,15 +329,25 @@ impl Clone for DataFrame {
         let Self {
             hwnd,
             himetric_to_logical_pixel,
+            coordinate_space,
             stylus_states,
             tools,
+            tool_presence,
+            tool_deletions,
             raw_tablet_deletions,
             raw_tablets,
             tablets,
             events,
+            capture_touch,
+            report_raw_axes,
+            touches,
         } = self;
         *himetric_to_logical_pixel = source.himetric_to_logical_pixel;
         *hwnd = source.hwnd;
+        *coordinate_space = source.coordinate_space;
+        *capture_touch = source.capture_touch;
+        *report_raw_axes = source.report_raw_axes;
+        touches.clone_from(&source.touches);
         stylus_states.clone_from(&source.stylus_states);
 
         tools.clear();
@@ -164,10 +360,13 @@ impl Clone for DataFrame {
                     // Clone what needs to be:
                     internal_id: tool.internal_id.clone(),
                     name: tool.name.clone(),
+                    buttons: tool.buttons.clone(),
                     // Copy the rest:
                     ..*tool
                 }),
         );
+        tool_presence.clone_from(&source.tool_presence);
+        tool_deletions.clone_from(&source.tool_deletions);
 
         raw_tablet_deletions.clone_from(&source.raw_tablet_deletions);
         raw_tablets.clone_from(&source.raw_tablets);
@@ -207,26 +406,54 @@ impl DataFrame {
         let Self {
             himetric_to_logical_pixel: _,
             hwnd: _,
+            coordinate_space: _,
             stylus_states,
             tools,
+            tool_presence,
+            tool_deletions,
             raw_tablet_deletions,
             raw_tablets,
             tablets,
             events,
+            capture_touch: _,
+            report_raw_axes: _,
+            touches,
         } = self;
 
         stylus_states.clear();
+        touches.clear();
         tools.clear();
+        tool_presence.clear();
+        tool_deletions.clear();
         raw_tablet_deletions.clear();
         raw_tablets.clear();
         tablets.clear();
         events.clear();
         self
     }
+    /// Re-query the HIMETRIC to pixel factor, reporting if it changed.
+    /// # Safety
+    /// `hwnd` must be valid.
+    unsafe fn update_scale_factor(&mut self) {
+        let scale = unsafe { fetch_himetric_to_logical_pixel(self.hwnd, self.coordinate_space) };
+        if (scale - self.himetric_to_logical_pixel).abs() > f32::EPSILON {
+            self.himetric_to_logical_pixel = scale;
+            self.events.push(crate::events::raw::Event::System(
+                crate::events::SystemEvent::ScaleFactorChanged,
+            ));
+        }
+    }
     /// Called at the end of each pump to maintain bookkeeping, *after* the clone has occured
     fn frame_end_cleanup(&mut self) {
         self.events.clear();
 
+        // Finish off tools marked last pump, now that the `Removed` events have been seen.
+        for id in self.tool_deletions.drain(..) {
+            self.tools
+                .retain(|tool| *tool.internal_id.unwrap_ink() != id);
+            self.tool_presence.remove(&id);
+        }
+
         // Handle deletions.
         for removal in self.raw_tablet_deletions.drain(..) {
             let tcid = self.raw_tablets.remove(removal).tcid();
@@ -234,11 +461,33 @@ impl DataFrame {
             self.tablets
                 .retain(|tab| *tab.internal_id.unwrap_ink() != ID::Tablet(tcid));
         }
+
+        // Retire tools that were left behind by a removed tablet. The `Removed` event goes out
+        // with the next pump, and the tool is destroyed the pump after that.
+        for (id, presence) in &mut self.tool_presence {
+            if self.stylus_states.contains_key(id) {
+                presence.idle_pumps = 0;
+                continue;
+            }
+            presence.idle_pumps = presence.idle_pumps.saturating_add(1);
+            let tablet_alive = self
+                .raw_tablets
+                .iter()
+                .any(|tablet| tablet.tcid() == presence.tcid);
+            if !tablet_alive && presence.idle_pumps == TOOL_RETENTION_PUMPS {
+                self.tool_deletions.push(*id);
+                self.events.push(crate::events::raw::Event::Tool {
+                    tool: *id,
+                    event: crate::events::raw::ToolEvent::Removed,
+                });
+            }
+        }
     }
     /// From the given collection of tools, find the tool under `cid` or insert a newly populated one.
     /// (can't take a self param due to borrowing crimes.)
     fn get_or_insert_tool<'tool>(
         tools: &'tool mut Vec<crate::tool::Tool>,
+        events: &mut Vec<crate::events::raw::Event<ID>>,
         rts: &tablet_pc::IRealTimeStylus,
         cid: u32,
     ) -> WinResult<&'tool mut crate::tool::Tool> {
@@ -255,11 +504,15 @@ impl DataFrame {
             // Each cursor represents *one end* of a stylus, with implications of it being hardware unique ID (note from future:
             // this implication is false). Problem is, tip and eraser have *different* hardware ids with no way to re-correlate them.
             // Sadness!
-            // We can also query the number of buttons! However, tip and eraser are also considered buttons with no way
-            // to differentiate them, so that's not much use.
+            // We can also query the number of buttons! However, tip and eraser are also considered buttons, only told
+            // apart by name, so the count is only as good as our guess at those.
 
             let cursor = rts.GetStylusForId(cid)?;
             let cursor_id = cursor.Id().ok();
+            let buttons = cursor
+                .Buttons()
+                .ok()
+                .map(|buttons| Self::cursor_buttons(&buttons));
 
             let tool = crate::tool::Tool {
                 internal_id: ID::Stylus { cid, cursor_id }.into(),
@@ -277,11 +530,80 @@ impl DataFrame {
                     Err(_) => None,
                 },
                 axes: crate::axis::FullInfo::default(),
+                button_count: buttons.as_ref().map(|buttons| {
+                    let count = buttons
+                        .iter()
+                        .filter(|button| {
+                            !matches!(
+                                button.role,
+                                crate::tool::ButtonRole::Primary | crate::tool::ButtonRole::Eraser
+                            )
+                        })
+                        .count();
+                    u8::try_from(count).unwrap_or(u8::MAX)
+                }),
+                buttons: buttons.unwrap_or_default(),
+                // No packets come in while out of range.
+                reports_out_of_proximity: false,
+                tip_threshold: None,
             };
+            events.push(crate::events::raw::Event::Tool {
+                tool: ID::Stylus { cid, cursor_id },
+                event: crate::events::raw::ToolEvent::Added,
+            });
             tools.push(tool);
             Ok(tools.last_mut().unwrap())
         }
     }
+    /// Read out the buttons of a cursor, skipping any that fail to.
+    fn cursor_buttons(buttons: &tablet_pc::IInkCursorButtons) -> Vec<crate::tool::ButtonInfo> {
+        // Safety: Plain COM calls on a valid interface.
+        unsafe {
+            let count = buttons.Count().unwrap_or(0);
+            (0..count)
+                .filter_map(|idx| {
+                    let button = buttons.Item(&windows::core::VARIANT::from(idx)).ok()?;
+                    // These are GUIDs in string form, the same as given to `StylusButton{Down, Up}` as binary.
+                    let guid = parse_guid(&button.Id().ok()?.to_string())?;
+                    let id = crate::tool::ButtonID(ButtonID(guid).into());
+                    let name = button.Name().ok().as_ref().map(ToString::to_string);
+                    Some(crate::tool::ButtonInfo {
+                        id,
+                        role: name
+                            .as_deref()
+                            .and_then(button_role)
+                            .unwrap_or(crate::tool::ButtonRole::Unknown(id)),
+                        name,
+                    })
+                })
+                .collect()
+        }
+    }
+    /// Note that the given tool was seen on the given tablet, reviving it if it was retired.
+    /// (can't take a self param due to borrowing crimes.)
+    fn note_presence(
+        tool_presence: &mut std::collections::BTreeMap<ID, ToolPresence>,
+        tool_deletions: &mut Vec<ID>,
+        events: &mut Vec<crate::events::raw::Event<ID>>,
+        id: ID,
+        tcid: u32,
+    ) {
+        tool_presence.insert(
+            id,
+            ToolPresence {
+                tcid,
+                idle_pumps: 0,
+            },
+        );
+        // Retired, but came back before it was destroyed. Undo!
+        if let Some(pos) = tool_deletions.iter().position(|&del| del == id) {
+            tool_deletions.swap_remove(pos);
+            events.push(crate::events::raw::Event::Tool {
+                tool: id,
+                event: crate::events::raw::ToolEvent::Added,
+            });
+        }
+    }
     fn get_tool(&self, cid: u32) -> Option<&crate::tool::Tool> {
         self.tools
             .iter()
@@ -298,26 +620,33 @@ impl DataFrame {
     ) -> &mut RawTabletSlot {
         // Attempt to query how to parse a tablet's packets. if this fails, we
         // *must still make a tablet out of it*, just a dummy one!
-        let (raw_tablet, tablet) =
-            if let Ok((interpreter, info)) = unsafe { packet::make_interpreter(rts, tcid) } {
-                (
-                    RawTabletSlot::Concrete(RawTablet {
-                        interpreter,
-                        axes: info,
-                        tcid,
-                    }),
-                    Some(crate::tablet::Tablet {
-                        internal_id: ID::Tablet(tcid).into(),
-                        name: unsafe { tablet.Name() }
-                            .ok()
-                            .as_ref()
-                            .map(ToString::to_string),
-                        usb_id: None,
-                    }),
-                )
-            } else {
-                (RawTabletSlot::Dummy { tcid }, None)
-            };
+        let (raw_tablet, tablet) = if let Ok((interpreter, info, area)) =
+            unsafe { packet::make_interpreter(rts, tcid, self.himetric_to_logical_pixel) }
+        {
+            (
+                RawTabletSlot::Concrete(RawTablet {
+                    interpreter,
+                    axes: info,
+                    tcid,
+                    touch: self.capture_touch && unsafe { is_touch_digitizer(tablet) },
+                }),
+                Some(crate::tablet::Tablet {
+                    internal_id: ID::Tablet(tcid).into(),
+                    name: unsafe { tablet.Name() }
+                        .ok()
+                        .as_ref()
+                        .map(ToString::to_string),
+                    usb_id: unsafe { usb_id(tablet) },
+                    // Not exposed by `IInkTablet`.
+                    report_rate: None,
+                    rotation: None,
+                    physical_size: area.map(|area| area.size),
+                    resolution: area.map(|area| area.resolution),
+                }),
+            )
+        } else {
+            (RawTabletSlot::Dummy { tcid }, None)
+        };
 
         if let Some(tablet) = tablet {
             self.tablets.push(tablet);
@@ -376,6 +705,7 @@ impl DataFrame {
             Err(())
         }
     }
+    #[allow(clippy::too_many_lines)]
     fn handle_packets(
         &mut self,
         rts: &tablet_pc::IRealTimeStylus,
@@ -388,25 +718,32 @@ impl DataFrame {
     ) {
         let tablet_id = ID::Tablet(stylus_info.tcid);
 
+        // Fingers are no tools, divert them before one gets made.
+        if self.try_handle_touch(stylus_info, num_packets, props, phase) {
+            return;
+        }
+
         // Find the relevant tool
-        let Ok(tool) = Self::get_or_insert_tool(&mut self.tools, rts, stylus_info.cid) else {
+        let Ok(tool) =
+            Self::get_or_insert_tool(&mut self.tools, &mut self.events, rts, stylus_info.cid)
+        else {
             // Failed to get stylus, nothing else for us to do.
             return;
         };
+        Self::note_presence(
+            &mut self.tool_presence,
+            &mut self.tool_deletions,
+            &mut self.events,
+            *tool.internal_id.unwrap_ink(),
+            stylus_info.tcid,
+        );
 
         // Find the relevant tablet
-        let tablet = self
-            .raw_tablets
-            .iter()
-            .enumerate()
-            .filter_map(|(physical_idx, tab)| {
-                // Filter for tablets that are *not* already deleted.
-                self.raw_tablet_deletions
-                    .binary_search(&physical_idx)
-                    .is_err()
-                    .then_some(tab)
-            })
-            .find(|&tab| tab.tcid() == stylus_info.tcid);
+        let tablet = Self::find_live_tablet(
+            &self.raw_tablets,
+            &self.raw_tablet_deletions,
+            stylus_info.tcid,
+        );
 
         let Some(RawTabletSlot::Concrete(tablet)) = tablet else {
             // Either not found or a dummy (bad tablet) slot.
@@ -423,7 +760,13 @@ impl DataFrame {
             .contains_key(tool.internal_id.unwrap_ink())
         {
             use crate::axis::Union;
-            tool.axes = tool.axes.union(&tablet.axes);
+            let mut axes = tablet.axes;
+            if tool.tool_type == Some(crate::tool::Type::Eraser) {
+                // Earned separately, see `track_eraser_axes`.
+                axes.tilt = None;
+                axes.roll = None;
+            }
+            tool.axes = tool.axes.union(&axes);
         }
 
         // Emit events.
@@ -442,7 +785,10 @@ impl DataFrame {
                     event: match phase {
                         StylusPhase::InAir => match from {
                             // Going from nowhere to in-air is an In event
-                            None => crate::events::raw::ToolEvent::In { tablet: tablet_id },
+                            None => crate::events::raw::ToolEvent::In {
+                                tablet: tablet_id,
+                                window: Some(crate::WindowId::MAIN),
+                            },
                             Some(_) => crate::events::raw::ToolEvent::Up,
                         },
                         StylusPhase::Touched => match from {
@@ -450,7 +796,10 @@ impl DataFrame {
                             None => {
                                 self.events.push(crate::events::raw::Event::Tool {
                                     tool: stylus_id,
-                                    event: crate::events::raw::ToolEvent::In { tablet: tablet_id },
+                                    event: crate::events::raw::ToolEvent::In {
+                                        tablet: tablet_id,
+                                        window: Some(crate::WindowId::MAIN),
+                                    },
                                 });
                                 crate::events::raw::ToolEvent::Down
                             }
@@ -489,20 +838,30 @@ impl DataFrame {
             );
 
             // Bail on any error.
-            while let Some(Ok(packet)) = packets.next() {
+            while let Some(Ok(mut packet)) = packets.next() {
                 // The status bits *seemed* useful but are actually redundant with all the other events
                 // we have.
                 // packet.status;
 
-                self.events.push(crate::events::raw::Event::Tool {
-                    tool: stylus_id,
-                    event: crate::events::raw::ToolEvent::Pose(packet.pose),
-                });
-
-                self.events.push(crate::events::raw::Event::Tool {
-                    tool: stylus_id,
-                    event: crate::events::raw::ToolEvent::Frame(packet.timestamp),
-                });
+                use crate::events::raw::ToolEvent;
+                if tool.tool_type == Some(crate::tool::Type::Eraser) {
+                    track_eraser_axes(tool, &tablet.axes, &mut packet.pose);
+                }
+                let raw = self
+                    .report_raw_axes
+                    .then(|| ToolEvent::RawPose(Box::new(packet.raw)));
+                let extras =
+                    (!packet.extras.is_empty()).then_some(ToolEvent::Extras(packet.extras));
+                self.events.extend(
+                    std::iter::once(ToolEvent::Pose(packet.pose))
+                        .chain(extras)
+                        .chain(raw)
+                        .chain(std::iter::once(ToolEvent::Frame(packet.timestamp)))
+                        .map(|event| crate::events::raw::Event::Tool {
+                            tool: stylus_id,
+                            event,
+                        }),
+                );
                 // We took care of the framing now.
                 needs_frame = false;
             }
@@ -516,6 +875,125 @@ impl DataFrame {
             });
         }
     }
+    /// Find the slot of a tablet that is *not* already deleted.
+    /// (can't take a self param due to borrowing crimes.)
+    fn find_live_tablet<'tablet>(
+        raw_tablets: &'tablet [RawTabletSlot],
+        raw_tablet_deletions: &[usize],
+        tcid: u32,
+    ) -> Option<&'tablet RawTabletSlot> {
+        raw_tablets
+            .iter()
+            .enumerate()
+            .filter_map(|(physical_idx, tab)| {
+                raw_tablet_deletions
+                    .binary_search(&physical_idx)
+                    .is_err()
+                    .then_some(tab)
+            })
+            .find(|&tab| tab.tcid() == tcid)
+    }
+    fn live_tablet(&self, tcid: u32) -> Option<&RawTabletSlot> {
+        Self::find_live_tablet(&self.raw_tablets, &self.raw_tablet_deletions, tcid)
+    }
+    /// Like [`Self::handle_packets`], for a finger on a touch digitizer. Returns false, doing nothing, if the
+    /// packets aren't from one.
+    fn try_handle_touch(
+        &mut self,
+        stylus_info: tablet_pc::StylusInfo,
+        num_packets: u32,
+        props: &[i32],
+        phase: StylusPhase,
+    ) -> bool {
+        use crate::events::raw::Event;
+        use crate::touch::{ContactID, Finger, TouchEvent};
+        if !self
+            .live_tablet(stylus_info.tcid)
+            .is_some_and(RawTabletSlot::is_touch)
+        {
+            return false;
+        }
+        let cid = stylus_info.cid;
+        let contact = ContactID(u64::from(cid));
+        // Fingers hovering (on the rare hardware that can tell) aren't touching anything.
+        if phase == StylusPhase::InAir {
+            self.lift_touch(cid);
+            return true;
+        }
+        let Ok(num_packets @ 1..) = usize::try_from(num_packets) else {
+            return true;
+        };
+        let Some(RawTabletSlot::Concrete(tablet)) = Self::find_live_tablet(
+            &self.raw_tablets,
+            &self.raw_tablet_deletions,
+            stylus_info.tcid,
+        ) else {
+            return true;
+        };
+        let mut packets = packet::Iter::new(
+            &tablet.interpreter,
+            self.himetric_to_logical_pixel,
+            props,
+            props.len() / num_packets,
+        );
+        while let Some(Ok(packet)) = packets.next() {
+            let finger = Finger {
+                contact,
+                position: packet.pose.position,
+                contact_size: packet.pose.contact_size,
+                window: Some(crate::WindowId::MAIN),
+            };
+            let event = if self.touches.insert(cid) {
+                TouchEvent::Down(finger)
+            } else {
+                TouchEvent::Move(finger)
+            };
+            self.events.push(Event::Touch(event));
+            self.events
+                .push(Event::Touch(TouchEvent::Frame(packet.timestamp)));
+        }
+        true
+    }
+    /// Report the given finger as lifted, if it was down.
+    fn lift_touch(&mut self, cid: u32) {
+        use crate::events::raw::Event;
+        use crate::touch::{ContactID, TouchEvent};
+        if self.touches.remove(&cid) {
+            self.events
+                .push(Event::Touch(TouchEvent::Up(ContactID(u64::from(cid)))));
+            self.events.push(Event::Touch(TouchEvent::Frame(None)));
+        }
+    }
+}
+
+/// Whether the tablet is a touchscreen or touchpad, rather than a pen digitizer.
+/// # Safety
+/// Plain COM calls, the tablet must be valid.
+unsafe fn is_touch_digitizer(tablet: &tablet_pc::IInkTablet) -> bool {
+    use core::Interface;
+    tablet
+        .cast::<tablet_pc::IInkTablet2>()
+        .and_then(|tablet| unsafe { tablet.DeviceKind() })
+        .is_ok_and(|kind| kind == tablet_pc::TDK_Touch)
+}
+
+/// Fish the vendor and product ID out of the tablet's plug and play ID, e.g. `HID\VID_056A&PID_0357&...`.
+/// Devices other than USB and bluetooth HID ones don't have them.
+/// # Safety
+/// Plain COM calls, the tablet must be valid.
+unsafe fn usb_id(tablet: &tablet_pc::IInkTablet) -> Option<crate::tablet::UsbId> {
+    let pnp = unsafe { tablet.PlugAndPlayId() }
+        .ok()?
+        .to_string()
+        .to_ascii_uppercase();
+    let hex_after = |key: &str| {
+        let start = pnp.find(key)? + key.len();
+        u16::from_str_radix(pnp.get(start..start + 4)?, 16).ok()
+    };
+    Some(crate::tablet::UsbId {
+        vid: hex_after("VID_")?,
+        pid: hex_after("PID_")?,
+    })
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -563,6 +1041,11 @@ struct Plugin {
     poisoned: sync::Arc<sync::atomic::AtomicBool>,
     shared_frame: sync::Arc<sync::Mutex<DataFrame>>,
     marshaler: std::rc::Rc<std::cell::OnceCell<com::Marshal::IMarshal>>,
+    /// Shared with the owning [`Manager`]'s `debug_log`, to be [dumped](crate::Manager::debug_dump) from there.
+    debug_log: Option<sync::Arc<sync::Mutex<crate::util::RingLog>>>,
+    /// Whether `EnumerationComplete` has gone out. The RTS is re-enabled after every poison recovery, but the
+    /// client is promised that event only once.
+    announced: sync::atomic::AtomicBool,
 }
 impl Plugin {
     /// Create an object to set the poison flag, requesting a reset from the outside through the [`Manager`]
@@ -573,6 +1056,14 @@ impl Plugin {
     fn poison_on_drop(&self) -> WinResult<Poison<'_>> {
         self.poison_bail().map(|()| Poison::new(&self.poisoned))
     }
+    /// Note down a callback in the debug history, if it's being kept. The message is only made if so.
+    fn log(&self, message: impl FnOnce() -> String) {
+        if let Some(log) = &self.debug_log {
+            log.lock()
+                .unwrap_or_else(sync::PoisonError::into_inner)
+                .push(message());
+        }
+    }
     /// Checks that the internal state is not poisoned. Returns `E_FAIL` if it is poisoned, which implies the internal
     /// state is potentially inconsistent.
     ///
@@ -587,6 +1078,48 @@ impl Plugin {
     }
 }
 
+/// Keeps the constructing thread inside a COM apartment for as long as the [`Manager`] lives, balancing
+/// the `CoInitializeEx` call if we were the ones to make it.
+///
+/// The RTS needs *some* apartment on the calling thread, but doesn't care which. UI threads typically already
+/// live in an STA (winit and friends set this up), whereas a dedicated input thread usually has none at all.
+/// We join whatever is present, and otherwise enter the MTA ourselves.
+struct ComApartment {
+    /// Whether we incremented this thread's COM refcount and need to `CoUninitialize`.
+    initialized: bool,
+    /// Uninitialization must happen on the same thread as initialization.
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+impl ComApartment {
+    /// Ensure the current thread is in an apartment.
+    fn enter() -> WinResult<Self> {
+        // Safety: No preconditions, and every success is balanced by `Drop`.
+        let result = unsafe { com::CoInitializeEx(None, com::COINIT_MULTITHREADED) };
+        let initialized = if result == RPC_E_CHANGED_MODE {
+            // Already in an STA. That's perfectly fine by us, use it as-is and don't touch the refcount.
+            false
+        } else {
+            // S_OK and S_FALSE (already in the MTA) both need balancing.
+            result.ok()?;
+            true
+        };
+        Ok(Self {
+            initialized,
+            _not_send: std::marker::PhantomData,
+        })
+    }
+}
+impl Drop for ComApartment {
+    fn drop(&mut self) {
+        if self.initialized {
+            // Safety: balances the successful `CoInitializeEx` in `enter`, on the same thread (we're `!Send`).
+            unsafe { com::CoUninitialize() };
+        }
+    }
+}
+
+// A few settings and bits of cursor/apartment bookkeeping, each its own flag.
+#[allow(clippy::struct_excessive_bools)]
 pub struct Manager {
     /// Invariant: valid for the lifetime of Self.
     hwnd: HWND,
@@ -598,6 +1131,25 @@ pub struct Manager {
     shared_frame: sync::Arc<sync::Mutex<DataFrame>>,
     /// Cloned local copy of the shared state after a frame.
     local_frame: Option<DataFrame>,
+    /// OS cursor position in the requested space, sampled at the last pump.
+    cursor_position: Option<[f32; 2]>,
+    /// See [`crate::Builder::coordinate_space`].
+    coordinate_space: CoordinateSpace,
+    /// Whether the window was in the foreground at the last pump. `None` before the first.
+    focused: Option<bool>,
+    /// See [`crate::Manager::hide_cursor_while_down`].
+    hide_cursor_while_down: bool,
+    /// Whether we've hidden the cursor, and thus owe `ShowCursor` a balancing call.
+    cursor_hidden: bool,
+    /// Raw callback history, written from the plugin, if requested.
+    debug_log: Option<sync::Arc<sync::Mutex<crate::util::RingLog>>>,
+    /// As set from [`crate::Builder::emulate_tool_from_mouse`].
+    all_tablets_mode: bool,
+    /// Whether `_apartment` joined the MTA, rather than finding an STA.
+    multithreaded_apartment: bool,
+    /// `_apartment` MUST BE LAST IN DECLARATION ORDER! Every COM object above must be released
+    /// before the thread potentially leaves its apartment.
+    _apartment: ComApartment,
 }
 
 impl Manager {
@@ -606,11 +1158,16 @@ impl Manager {
     /// * The given `HWND` must be valid as long as the returned `Manager` is alive.
     /// * Only *one* manager may exist for this `HWND`. Claims Ink for the entire window rectangle - No other Ink collection
     ///   APIs should be enabled on this window while the returned manager exists.
-    #[allow(clippy::needless_pass_by_value)]
+    ///
+    /// The `HWND` may be owned by a different thread than the calling one. The calling thread is joined to
+    /// the multithreaded apartment if it is not already part of one.
+    #[allow(clippy::too_many_lines)]
     pub(crate) unsafe fn build_hwnd(
-        opts: crate::builder::Builder,
+        opts: &crate::builder::Builder,
         hwnd: std::num::NonZeroIsize,
     ) -> WinResult<Self> {
+        // Must come before any other COM usage, and outlive it all.
+        let apartment = ComApartment::enter()?;
         // Safety: Uhh..
         unsafe {
             let hwnd = HWND(hwnd.get());
@@ -626,7 +1183,7 @@ impl Manager {
             // Many settings must have the rts disabled
             rts.SetEnabled(false)?;
             // Request all our supported axes
-            rts.SetDesiredPacketDescription(packet::DESIRED_PACKET_DESCRIPTIONS)?;
+            rts.SetDesiredPacketDescription(&packet::desired_packet_descriptions(opts.axes))?;
             // Safety: Must survive as long as `rts`. deferred to this fn's contract.
             rts.SetHWND(hwnd_handle)?;
 
@@ -635,13 +1192,24 @@ impl Manager {
                 raw_tablets: vec![],
                 tablets: vec![],
                 tools: vec![],
+                tool_presence: std::collections::BTreeMap::new(),
+                tool_deletions: vec![],
                 stylus_states: std::collections::BTreeMap::new(),
                 events: vec![],
                 hwnd,
-                himetric_to_logical_pixel: fetch_himetric_to_logical_pixel(hwnd),
+                himetric_to_logical_pixel: fetch_himetric_to_logical_pixel(
+                    hwnd,
+                    opts.coordinate_space,
+                ),
+                coordinate_space: opts.coordinate_space,
+                capture_touch: opts.capture_touch,
+                report_raw_axes: opts.report_raw_axes,
+                touches: std::collections::BTreeSet::new(),
             }));
 
             let poisoned = sync::Arc::new(sync::atomic::AtomicBool::new(false));
+            let debug_log = crate::util::RingLog::new(opts.debug_history)
+                .map(|log| sync::Arc::new(sync::Mutex::new(log)));
 
             // Rc to lazily set the marshaler once we have it - struct needs to be made in order to create a marshaler,
             // but struct also needs to have the marshaler inside of it! We don't need thread safety,
@@ -653,6 +1221,8 @@ impl Manager {
                 poisoned: poisoned.clone(),
                 shared_frame: shared_frame.clone(),
                 marshaler: inner_marshaler.clone(),
+                debug_log: debug_log.clone(),
+                announced: sync::atomic::AtomicBool::new(false),
             });
 
             // Create a concretely typed marshaler, insert it into the plugin so that it may
@@ -692,9 +1262,30 @@ impl Manager {
             {
                 let crate::builder::Builder {
                     emulate_tool_from_mouse,
+                    // Handled crate-side.
+                    auto_range_pressure: _,
+                    normalize_pressure_to_tip: _,
+                    synthesize_timestamps: _,
+                    distance_curve: _,
+                    pressure_curve: _,
+                    out_on_focus_loss: _,
+                    fingerprint_tools: _,
+                    quantize: _,
+                    smoothing: _,
+                    pointer_lock: _,
+                    stuck_down_timeout: _,
+                    suppress_duplicate_poses: _,
+                    null_fallback: _,
+                    // Applied to the packet description above, the rest is crate-side.
+                    axes: _,
+                    // Taken care of above.
+                    debug_history: _,
+                    capture_touch: _,
+                    report_raw_axes: _,
+                    coordinate_space: _,
                 } = opts;
 
-                rts.SetAllTabletsMode(emulate_tool_from_mouse)?;
+                rts.SetAllTabletsMode(*emulate_tool_from_mouse)?;
             }
 
             // We're ready, startup async event collection!
@@ -706,9 +1297,59 @@ impl Manager {
                 poisoned,
                 shared_frame,
                 local_frame: None,
+                cursor_position: None,
+                coordinate_space: opts.coordinate_space,
+                focused: None,
+                hide_cursor_while_down: false,
+                cursor_hidden: false,
+                debug_log,
+                all_tablets_mode: opts.emulate_tool_from_mouse,
+                multithreaded_apartment: apartment.initialized,
+                _apartment: apartment,
             })
         }
     }
+    /// Show or hide the cursor, if not already.
+    fn set_cursor_hidden(&mut self, hidden: bool) {
+        if self.cursor_hidden != hidden {
+            self.cursor_hidden = hidden;
+            // Safety: No preconditions. The display counter is per-thread, and we're `!Send`, so this stays balanced.
+            unsafe {
+                windows::Win32::UI::WindowsAndMessaging::ShowCursor(!hidden);
+            }
+        }
+    }
+    /// The OS cursor position, in the requested space. `None` outside of the window's client area, unless in
+    /// screen space.
+    fn sample_cursor(&self) -> Option<[f32; 2]> {
+        use windows::Win32::{Graphics::Gdi::ScreenToClient, UI::WindowsAndMessaging as wm};
+        let mut point = windows::Win32::Foundation::POINT::default();
+        // Safety: Outparam pointer is valid.
+        unsafe { wm::GetCursorPos(std::ptr::addr_of_mut!(point)) }.ok()?;
+        if self.coordinate_space != CoordinateSpace::Screen {
+            let mut client = RECT::default();
+            // Safety: `hwnd` is valid as per `Self` invariant. Outparam pointers are valid.
+            let converted = unsafe {
+                ScreenToClient(self.hwnd, std::ptr::addr_of_mut!(point)).as_bool()
+                    && wm::GetClientRect(self.hwnd, std::ptr::addr_of_mut!(client)).is_ok()
+            };
+            // Client coordinates, so the rect starts at the origin.
+            let inside = (client.left..client.right).contains(&point.x)
+                && (client.top..client.bottom).contains(&point.y);
+            if !converted || !inside {
+                return None;
+            }
+        }
+        // Rounding is ok - pixel coordinates are nowhere near the precision limit of f32.
+        #[allow(clippy::cast_precision_loss)]
+        let mut position = [point.x as f32, point.y as f32];
+        if self.coordinate_space == CoordinateSpace::Logical {
+            // Safety: `hwnd` is valid as per `Self` invariant.
+            let scale = LOGICAL_DPI / unsafe { fetch_dpi(self.hwnd) };
+            position = position.map(|coord| coord * scale);
+        }
+        Some(position)
+    }
     /// Attempt to recover a poisoned plugin.
     pub fn handle_poison(&mut self) -> Result<(), ()> {
         unsafe {
@@ -716,7 +1357,8 @@ impl Manager {
             self.rts.ClearStylusQueues().map_err(|_| ())?;
         }
 
-        let scale_factor = unsafe { fetch_himetric_to_logical_pixel(self.hwnd) };
+        let scale_factor =
+            unsafe { fetch_himetric_to_logical_pixel(self.hwnd, self.coordinate_space) };
         // Make shared state consistent.
         self.shared_frame
             .lock()
@@ -757,6 +1399,7 @@ impl Drop for Manager {
             let _ = self.rts.SetEnabled(false);
             let _ = self.rts.RemoveAllStylusAsyncPlugins();
         }
+        self.set_cursor_hidden(false);
     }
 }
 
@@ -769,6 +1412,9 @@ impl super::PlatformImpl for Manager {
             // We clone since the user can borrow this data for unbounded amount of time before next frame,
             // and we don't want to lock out the callbacks from writing new data.
             if let Ok(mut lock) = self.shared_frame.lock() {
+                // `UpdateMapping` only hears of some DPI changes, so check up on it too.
+                // Safety: `hwnd` is valid as per `Self` invariant.
+                unsafe { lock.update_scale_factor() };
                 if let Some(local_frame) = self.local_frame.as_mut() {
                     // Last frame exists, clone_from to reuse allocs
                     local_frame.clone_from(&lock);
@@ -783,9 +1429,148 @@ impl super::PlatformImpl for Manager {
                 self.local_frame = None;
             }
 
+            // Client origin on the screen, where everything is relative to it.
+            let mut origin = windows::Win32::Foundation::POINT::default();
+            // Safety: `hwnd` is valid as per `Self` invariant. Outparam pointer is valid.
+            let has_origin = self.coordinate_space == CoordinateSpace::Screen
+                && unsafe {
+                    windows::Win32::Graphics::Gdi::ClientToScreen(
+                        self.hwnd,
+                        std::ptr::addr_of_mut!(origin),
+                    )
+                    .as_bool()
+                };
+            // Rounding is ok - pixel coordinates are nowhere near the precision limit of f32.
+            #[allow(clippy::cast_precision_loss)]
+            if let (true, Some(local_frame)) = (has_origin, self.local_frame.as_mut()) {
+                offset_positions(&mut local_frame.events, [origin.x as f32, origin.y as f32]);
+            }
+
+            // Sample the cursor as close as possible to the packets we just took.
+            self.cursor_position = self.sample_cursor();
+
+            // There's no focus notification for us without hooking the window, sample it instead.
+            // Safety: `hwnd` is valid as per `Self` invariant.
+            let focused = unsafe {
+                use windows::Win32::UI::WindowsAndMessaging::{
+                    GetAncestor, GetForegroundWindow, GA_ROOT,
+                };
+                GetForegroundWindow() == GetAncestor(self.hwnd, GA_ROOT)
+            };
+            // Without a frame there's nowhere to report it this pump, so it's only taken as seen once it has been -
+            // it goes out with the next frame instead of not at all.
+            if self.focused != Some(focused) {
+                if let Some(local_frame) = self.local_frame.as_mut() {
+                    self.focused = Some(focused);
+                    local_frame
+                        .events
+                        .push(crate::events::raw::Event::WindowFocus { focused });
+                }
+            }
+
+            if self.hide_cursor_while_down || self.cursor_hidden {
+                let mut hidden = self.cursor_hidden;
+                for event in self.local_frame.iter().flat_map(|frame| &frame.events) {
+                    if let crate::events::raw::Event::Tool { event, .. } = event {
+                        match event {
+                            crate::events::raw::ToolEvent::Down => hidden = true,
+                            crate::events::raw::ToolEvent::Up
+                            | crate::events::raw::ToolEvent::Out => {
+                                hidden = false;
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                self.set_cursor_hidden(hidden && self.hide_cursor_while_down);
+            }
+
             Ok(())
         }
     }
+    fn cursor_position(&self) -> Option<[f32; 2]> {
+        self.cursor_position
+    }
+    fn hide_cursor_while_down(&mut self, hide: bool) {
+        self.hide_cursor_while_down = hide;
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn set_hit_region(&mut self, region: Option<crate::region::Rect>) {
+        // The region is in the requested space, whereas the RTS wants client pixels.
+        let to_client = |point: [f32; 2]| -> [f32; 2] {
+            match self.coordinate_space {
+                CoordinateSpace::Native | CoordinateSpace::Physical => point,
+                CoordinateSpace::Logical => {
+                    // Safety: `hwnd` is valid as per `Self` invariant.
+                    let scale = unsafe { fetch_dpi(self.hwnd) } / LOGICAL_DPI;
+                    point.map(|coord| coord * scale)
+                }
+                CoordinateSpace::Screen => {
+                    let mut origin = windows::Win32::Foundation::POINT::default();
+                    // Safety: `hwnd` is valid as per `Self` invariant. Outparam pointer is valid.
+                    // On failure the origin stays zero, which is no worse than not converting at all.
+                    let _ = unsafe {
+                        windows::Win32::Graphics::Gdi::ClientToScreen(
+                            self.hwnd,
+                            std::ptr::addr_of_mut!(origin),
+                        )
+                    };
+                    [point[0] - origin.x as f32, point[1] - origin.y as f32]
+                }
+            }
+        };
+        // All zeros is the whole window. Rounded outwards, as the crate makes the final call anyhow.
+        let rect = region.map_or_else(RECT::default, |region| {
+            let (min, max) = (to_client(region.min), to_client(region.max));
+            RECT {
+                left: min[0].floor() as i32,
+                top: min[1].floor() as i32,
+                right: max[0].ceil() as i32,
+                bottom: max[1].ceil() as i32,
+            }
+        });
+        // Safety: rect is valid for the duration of the call.
+        // Failure is no great loss, it only means the system does work we'll discard.
+        let _ = unsafe { self.rts.SetWindowInputRectangle(std::ptr::addr_of!(rect)) };
+    }
+    fn add_window(
+        &mut self,
+        _: raw_window_handle::RawWindowHandle,
+        _: crate::WindowId,
+    ) -> Result<(), crate::AddWindowError> {
+        Err(crate::AddWindowError::Unsupported)
+    }
+    fn remove_window(&mut self, _: crate::WindowId) {}
+    fn set_feedback(
+        &mut self,
+        _: super::FeedbackTarget<'_>,
+        _: &str,
+    ) -> Result<(), crate::FeedbackError> {
+        // Pads aren't reported at all.
+        Err(crate::FeedbackError::Unsupported)
+    }
+    fn protocol_versions(&self) -> Option<crate::ProtocolVersions> {
+        None
+    }
+    fn backend_info(&self) -> crate::BackendInfo {
+        crate::BackendInfo::Ink(crate::InkInfo {
+            multithreaded_apartment: self.multithreaded_apartment,
+            all_tablets_mode: self.all_tablets_mode,
+        })
+    }
+    fn accessibility(&self) -> crate::Accessibility {
+        crate::Accessibility {
+            cursor_size: cursor_size(),
+            high_contrast: high_contrast(),
+        }
+    }
+    fn debug_dump(&self) -> Option<String> {
+        self.debug_log.as_ref().map(|log| {
+            log.lock()
+                .unwrap_or_else(sync::PoisonError::into_inner)
+                .dump()
+        })
+    }
     fn timestamp_granularity(&self) -> Option<std::time::Duration> {
         // Tablets optionally report, which *seems* to be in milliseconds. There is no unit enumeration for Time,
         // and the `GUID_PACKETPROPERTY_GUID_TIMER_TICK` is only described as `The time the packet was generated`
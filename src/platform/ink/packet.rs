@@ -26,12 +26,45 @@ pub const DESIRED_PACKET_DESCRIPTIONS: &[core::GUID] = &[
     tablet_pc::GUID_PACKETPROPERTY_GUID_BUTTON_PRESSURE,
     tablet_pc::GUID_PACKETPROPERTY_GUID_WIDTH,
     tablet_pc::GUID_PACKETPROPERTY_GUID_HEIGHT,
+    // Not modeled by `Pose`, these go into `ExtraAxes` untouched.
+    tablet_pc::GUID_PACKETPROPERTY_GUID_ALTITUDE_ORIENTATION,
+    tablet_pc::GUID_PACKETPROPERTY_GUID_AZIMUTH_ORIENTATION,
+    tablet_pc::GUID_PACKETPROPERTY_GUID_TANGENT_PRESSURE,
+    tablet_pc::GUID_PACKETPROPERTY_GUID_PITCH_ROTATION,
+    tablet_pc::GUID_PACKETPROPERTY_GUID_ROLL_ROTATION,
+    tablet_pc::GUID_PACKETPROPERTY_GUID_YAW_ROTATION,
     tablet_pc::GUID_PACKETPROPERTY_GUID_TIMER_TICK,
     // Packet status always reported last regardless of it's index into this list, but still must be requested.
     // Guaranteed by the Ink API to be supported.
     tablet_pc::GUID_PACKETPROPERTY_GUID_PACKET_STATUS,
 ];
 
+/// [`DESIRED_PACKET_DESCRIPTIONS`], less those of axes not in the given set.
+pub fn desired_packet_descriptions(axes: axis::AvailableAxes) -> Vec<core::GUID> {
+    use axis::AvailableAxes;
+    DESIRED_PACKET_DESCRIPTIONS
+        .iter()
+        .copied()
+        .filter(|&guid| {
+            let needs = match guid {
+                tablet_pc::GUID_PACKETPROPERTY_GUID_NORMAL_PRESSURE => AvailableAxes::PRESSURE,
+                tablet_pc::GUID_PACKETPROPERTY_GUID_X_TILT_ORIENTATION
+                | tablet_pc::GUID_PACKETPROPERTY_GUID_Y_TILT_ORIENTATION => AvailableAxes::TILT,
+                tablet_pc::GUID_PACKETPROPERTY_GUID_Z => AvailableAxes::DISTANCE,
+                tablet_pc::GUID_PACKETPROPERTY_GUID_TWIST_ORIENTATION => AvailableAxes::ROLL,
+                tablet_pc::GUID_PACKETPROPERTY_GUID_BUTTON_PRESSURE => {
+                    AvailableAxes::BUTTON_PRESSURE
+                }
+                tablet_pc::GUID_PACKETPROPERTY_GUID_WIDTH
+                | tablet_pc::GUID_PACKETPROPERTY_GUID_HEIGHT => AvailableAxes::CONTACT_SIZE,
+                // Position, timer, and status aren't optional.
+                _ => return true,
+            };
+            axes.contains(needs)
+        })
+        .collect()
+}
+
 trait SlicePop {
     type Item;
     fn pop_front(&mut self) -> Option<Self::Item>;
@@ -75,6 +108,14 @@ impl Scaler {
         #[allow(clippy::cast_possible_truncation)]
         NicheF32::new_some(data as f32).ok_or(FilterError::Value)
     }
+    /// Describe the given value and this transform, for [`axis::RawPose`].
+    pub fn raw(&self, value: i32) -> axis::RawValue {
+        axis::RawValue {
+            value,
+            bias: self.bias,
+            scale: self.multiply,
+        }
+    }
 }
 
 /// Describes the state of an optional packet property.
@@ -100,6 +141,17 @@ impl Tristate<Scaler> {
             Self::Ok(scale) => scale.read_from(from),
         }
     }
+    /// Like [`Self::read_from`], noting down the value as it was into `raw` if reported.
+    pub fn read_raw_from(
+        &self,
+        from: &mut &[i32],
+        raw: &mut Option<axis::RawValue>,
+    ) -> Result<NicheF32, FilterError> {
+        if let (Self::Ok(scale), Some(&value)) = (self, from.first()) {
+            *raw = Some(scale.raw(value));
+        }
+        self.read_from(from)
+    }
 }
 impl<T> Tristate<T> {
     /// Returns a new Tristate with the [`Tristate::Ok`] variant transformed through the
@@ -138,6 +190,8 @@ pub struct Interpreter {
     pub twist: Tristate<Scaler>,
     pub button_pressure: Tristate<Scaler>,
     pub contact_size: [Tristate<Scaler>; 2],
+    /// Properties passed on as [`axis::ExtraAxes`], in packet order.
+    pub extras: Vec<core::GUID>,
     /// odd one out - always millis with no scale nor bias.
     /// true for included, false for not.
     pub timer: bool,
@@ -152,16 +206,34 @@ pub enum InterpretError {
 }
 
 impl Interpreter {
-    /// Consume the slice of properties according to these filters, producing a `Pose` and an optional timestamp.
+    /// Consume the slice of properties according to these filters, producing a `Pose`, the raw values it was made
+    /// from, any extra axes, and an optional timestamp.
+    #[allow(clippy::type_complexity)]
     pub fn consume(
         &self,
         himetric_to_logical_pixels: f32,
         mut props: &[i32],
-    ) -> Result<(axis::Pose, Option<crate::events::FrameTimestamp>), InterpretError> {
+    ) -> Result<
+        (
+            axis::Pose,
+            axis::RawPose,
+            axis::ExtraAxes,
+            Option<crate::events::FrameTimestamp>,
+        ),
+        InterpretError,
+    > {
         // ======= ORDER IS IMPORTANT!! ========
         // If you change me, make sure to change `DESIRED_PACKET_DESCRIPTIONS` :3
 
-        let pose = axis::Pose {
+        let mut raw = axis::RawPose {
+            position: [props.first(), props.get(1)].map(|value| {
+                value.map(|&value| {
+                    axis::RawValue::scaled(value, f64::from(himetric_to_logical_pixels))
+                })
+            }),
+            ..Default::default()
+        };
+        let mut pose = axis::Pose {
             #[allow(clippy::cast_precision_loss)]
             position: [
                 *(props
@@ -175,43 +247,67 @@ impl Interpreter {
                     as f32
                     * himetric_to_logical_pixels,
             ],
-            pressure: self.normal_pressure.read_from(&mut props)?,
+            pressure: self
+                .normal_pressure
+                .read_raw_from(&mut props, &mut raw.pressure)?,
             tilt: match (
-                self.tilt[0].read_from(&mut props)?.get(),
-                self.tilt[1].read_from(&mut props)?.get(),
+                self.tilt[0]
+                    .read_raw_from(&mut props, &mut raw.tilt[0])?
+                    .get(),
+                self.tilt[1]
+                    .read_raw_from(&mut props, &mut raw.tilt[1])?
+                    .get(),
             ) {
                 (None, None) => None,
                 (Some(x), None) => Some([x, 0.0]),
                 (None, Some(y)) => Some([0.0, y]),
                 (Some(x), Some(y)) => Some([x, y]),
             },
-            distance: self.z.read_from(&mut props)?,
-            roll: self.twist.read_from(&mut props)?,
-            button_pressure: self.button_pressure.read_from(&mut props)?,
+            distance: self.z.read_raw_from(&mut props, &mut raw.distance)?,
+            // Filled in below, can't refer to `distance` here.
+            raw_distance: NicheF32::NONE,
+            roll: self.twist.read_raw_from(&mut props, &mut raw.roll)?,
+            button_pressure: self
+                .button_pressure
+                .read_raw_from(&mut props, &mut raw.button_pressure)?,
             contact_size: match (
-                self.contact_size[0].read_from(&mut props)?.get(),
-                self.contact_size[1].read_from(&mut props)?.get(),
+                self.contact_size[0]
+                    .read_raw_from(&mut props, &mut raw.contact_size[0])?
+                    .get(),
+                self.contact_size[1]
+                    .read_raw_from(&mut props, &mut raw.contact_size[1])?
+                    .get(),
             ) {
                 (None, None) => None,
-                (Some(x), None) => Some([x, 0.0]),
-                (None, Some(y)) => Some([0.0, y]),
+                // A lone dimension, as reported by e.g. some chisel erasers. Unlike tilt, zero isn't a sensible
+                // neutral for the other - take the contact to be as tall as it is wide.
+                (Some(x), None) => Some([x, x]),
+                (None, Some(y)) => Some([y, y]),
                 (Some(x), Some(y)) => Some([x, y]),
             },
             slider: NicheF32::NONE,
             wheel: None,
         };
+        pose.raw_distance = pose.distance;
+        let mut extras = axis::ExtraAxes::new();
+        for guid in &self.extras {
+            let &value = props.pop_front().ok_or(FilterError::NotEnoughData)?;
+            // Exactly as reported, as promised. Precision loss only past 2^24, far beyond any real axis.
+            #[allow(clippy::cast_precision_loss)]
+            extras.insert(axis::ExtraAxisKey::Guid(guid.to_u128()), value as f32);
+        }
         let timer = if self.timer {
             let &timer = props.pop_front().ok_or(FilterError::NotEnoughData)?;
 
             let timer = u64::try_from(timer).map_err(|_| FilterError::Value)?;
-            Some(crate::events::FrameTimestamp(
+            Some(crate::events::FrameTimestamp::hardware(
                 std::time::Duration::from_millis(timer),
             ))
         } else {
             None
         };
         if props.is_empty() {
-            Ok((pose, timer))
+            Ok((pose, raw, extras, timer))
         } else {
             // There's data left on the tail. This means we parsed it wrong, and things are probably
             // definitely borked about the stuff that's been parsed into `pose`, so err out.
@@ -231,9 +327,11 @@ bitflags::bitflags! {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Packet {
     pub pose: axis::Pose,
+    pub raw: axis::RawPose,
+    pub extras: axis::ExtraAxes,
     pub timestamp: Option<events::FrameTimestamp>,
     pub status: StatusWord,
 }
@@ -277,8 +375,10 @@ impl<'a> Iterator for Iter<'a> {
         let packet = &packet[..packet.len() - 1];
 
         match self.filters.consume(self.himetric_to_logical_pixel, packet) {
-            Ok((pose, timestamp)) => Some(Ok(Packet {
+            Ok((pose, raw, extras, timestamp)) => Some(Ok(Packet {
                 pose,
+                raw,
+                extras,
                 timestamp,
                 status: StatusWord::from_bits_truncate(status),
             })),
@@ -320,6 +420,46 @@ pub fn calc_granularity(metrics: tablet_pc::PROPERTY_METRICS) -> Option<axis::Gr
     std::num::NonZeroU32::new(granularity).map(axis::Granularity)
 }
 
+/// Calculates the dots per logical pixel of a position axis. None if less than one.
+pub fn position_granularity(
+    metrics: tablet_pc::PROPERTY_METRICS,
+    himetric_to_logical_pixel: f32,
+) -> Option<axis::PositionGranularity> {
+    // Packets are in HIMETRIC regardless of the hardware, so that's the finest it gets.
+    const HIMETRIC_PER_CM: f64 = 1000.0;
+    let dots_per_cm = linear_scale_factor(metrics.Units)
+        .map_or(HIMETRIC_PER_CM, |cm_per_unit| {
+            f64::from(metrics.fResolution) / cm_per_unit
+        })
+        .min(HIMETRIC_PER_CM);
+    let pixels_per_cm = f64::from(himetric_to_logical_pixel) * HIMETRIC_PER_CM;
+    let dots_per_pixel = dots_per_cm / pixels_per_cm;
+    // Saturating as, NaN becomes zero.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    std::num::NonZeroU32::new(dots_per_pixel as u32).map(axis::PositionGranularity)
+}
+
+/// The physical extent of a tablet's active area.
+#[derive(Clone, Copy, Debug)]
+pub struct PhysicalArea {
+    /// Width and height in millimeters.
+    pub size: [f32; 2],
+    /// X and Y dots per millimeter.
+    pub resolution: [f32; 2],
+}
+
+/// Calculates the size in millimeters and dots per millimeter of a position axis. None if it's not in a unit of
+/// length.
+pub fn physical_extent(metrics: tablet_pc::PROPERTY_METRICS) -> Option<(f32, f32)> {
+    let mm_per_unit = linear_scale_factor(metrics.Units)? * 10.0;
+    let dots_per_mm = f64::from(metrics.fResolution) / mm_per_unit;
+    let size = f64::from(metrics.nLogicalMax.abs_diff(metrics.nLogicalMin)) / dots_per_mm;
+    #[allow(clippy::cast_possible_truncation)]
+    let extent = (size as f32, dots_per_mm as f32);
+    // Zero, negative, or NaN resolutions are meaningless.
+    (size.is_finite() && size > 0.0 && dots_per_mm > 0.0).then_some(extent)
+}
+
 /// Attempt to squash the range down, regardless of unit.
 /// Returns [`Tristate::Malformed`] if an arithmetic error occurs and the normalization cannot
 /// be performed.
@@ -519,7 +659,8 @@ mod util {
 pub unsafe fn make_interpreter(
     rts: &tablet_pc::IRealTimeStylus,
     tcid: u32,
-) -> WinResult<(Interpreter, axis::FullInfo)> {
+    himetric_to_logical_pixel: f32,
+) -> WinResult<(Interpreter, axis::FullInfo, Option<PhysicalArea>)> {
     use crate::axis::Union;
     let properties = unsafe {
         let mut num_properties = 0;
@@ -582,12 +723,26 @@ pub unsafe fn make_interpreter(
         twist: Tristate::NotIncluded,
         button_pressure: Tristate::NotIncluded,
         contact_size: [Tristate::NotIncluded; 2],
+        extras: Vec::new(),
         timer: false,
     };
     let mut info = axis::FullInfo::default();
+    for (position, prop) in info.position.iter_mut().zip(&properties[..2]) {
+        position.granularity =
+            position_granularity(prop.PropertyMetrics, himetric_to_logical_pixel);
+    }
+    let area = match (
+        physical_extent(properties[0].PropertyMetrics),
+        physical_extent(properties[1].PropertyMetrics),
+    ) {
+        (Some((width, x_res)), Some((height, y_res))) => Some(PhysicalArea {
+            size: [width, height],
+            resolution: [x_res, y_res],
+        }),
+        _ => None,
+    };
 
     // Cut out first two and last one, those are X,Y, .., STATUS which we have no need to query.
-    // TODO: X,Y granularity calculation. Bleh, left as None for now.
     for prop in &properties[2..properties.len() - 1] {
         match prop.guid {
             tablet_pc::GUID_PACKETPROPERTY_GUID_NORMAL_PRESSURE => {
@@ -672,6 +827,14 @@ pub unsafe fn make_interpreter(
                     };
                 }
             }
+            tablet_pc::GUID_PACKETPROPERTY_GUID_ALTITUDE_ORIENTATION
+            | tablet_pc::GUID_PACKETPROPERTY_GUID_AZIMUTH_ORIENTATION
+            | tablet_pc::GUID_PACKETPROPERTY_GUID_TANGENT_PRESSURE
+            | tablet_pc::GUID_PACKETPROPERTY_GUID_PITCH_ROTATION
+            | tablet_pc::GUID_PACKETPROPERTY_GUID_ROLL_ROTATION
+            | tablet_pc::GUID_PACKETPROPERTY_GUID_YAW_ROTATION => {
+                interpreter.extras.push(prop.guid);
+            }
             tablet_pc::GUID_PACKETPROPERTY_GUID_TIMER_TICK => {
                 interpreter.timer = true;
             }
@@ -681,5 +844,5 @@ pub unsafe fn make_interpreter(
         }
     }
 
-    Ok((interpreter, info))
+    Ok((interpreter, info, area))
 }
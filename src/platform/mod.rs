@@ -1,6 +1,10 @@
 // Conditionally include each backend...
+#[cfg(custom_backend)]
+pub(crate) mod custom;
 #[cfg(ink_rts)]
 pub(crate) mod ink;
+// ...except this one, which is always around.
+pub(crate) mod null;
 #[cfg(wl_tablet)]
 pub(crate) mod wl;
 
@@ -14,6 +18,8 @@ pub(crate) enum InternalID {
     Wayland(wl::ID),
     #[cfg(ink_rts)]
     Ink(ink::ID),
+    #[cfg(custom_backend)]
+    Custom(custom::ID),
 }
 impl std::fmt::Debug for InternalID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -65,6 +71,17 @@ impl InternalID {
             _ => Self::unwrap_failure(),
         }
     }
+    #[cfg(custom_backend)]
+    #[inline]
+    #[allow(dead_code)]
+    pub(crate) fn unwrap_custom(&self) -> &custom::ID {
+        #[allow(unreachable_patterns)]
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        match self {
+            Self::Custom(id) => id,
+            _ => Self::unwrap_failure(),
+        }
+    }
 }
 #[cfg(wl_tablet)]
 impl From<wl::ID> for InternalID {
@@ -78,6 +95,12 @@ impl From<ink::ID> for InternalID {
         Self::Ink(value)
     }
 }
+#[cfg(custom_backend)]
+impl From<custom::ID> for InternalID {
+    fn from(value: custom::ID) -> Self {
+        Self::Custom(value)
+    }
+}
 /// Holds any one of the internal platform IDs.
 /// Since these are always sealed away as an implementation detail, we can always
 /// assume they're the right type since they can never be moved between `Manager`s.
@@ -88,6 +111,8 @@ pub(crate) enum ButtonID {
     Wayland(wl::ButtonID),
     #[cfg(ink_rts)]
     Ink(ink::ButtonID),
+    #[cfg(custom_backend)]
+    Custom(custom::ButtonID),
 }
 impl std::fmt::Debug for ButtonID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -139,6 +164,28 @@ impl ButtonID {
             _ => Self::unwrap_failure(),
         }
     }
+    #[cfg(custom_backend)]
+    #[inline]
+    #[allow(dead_code)]
+    pub(crate) fn unwrap_custom(&self) -> &custom::ButtonID {
+        #[allow(unreachable_patterns)]
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        match self {
+            Self::Custom(id) => id,
+            _ => Self::unwrap_failure(),
+        }
+    }
+}
+impl ButtonID {
+    /// Best-effort role from the ID alone, for backends where the ID itself carries meaning.
+    pub(crate) fn role(self) -> Option<crate::tool::ButtonRole> {
+        #[allow(unreachable_patterns)]
+        match self {
+            #[cfg(wl_tablet)]
+            Self::Wayland(code) => wl::button_role(code),
+            _ => None,
+        }
+    }
 }
 #[cfg(wl_tablet)]
 impl From<wl::ButtonID> for ButtonID {
@@ -152,12 +199,22 @@ impl From<ink::ButtonID> for ButtonID {
         Self::Ink(value)
     }
 }
+#[cfg(custom_backend)]
+impl From<custom::ButtonID> for ButtonID {
+    fn from(value: custom::ButtonID) -> Self {
+        Self::Custom(value)
+    }
+}
 
 pub(crate) enum RawEventsIter<'a> {
     #[cfg(wl_tablet)]
     Wayland(std::slice::Iter<'a, crate::events::raw::Event<wl::ID>>),
     #[cfg(ink_rts)]
     Ink(std::slice::Iter<'a, crate::events::raw::Event<ink::ID>>),
+    #[cfg(custom_backend)]
+    Custom(std::slice::Iter<'a, crate::events::raw::Event<custom::ID>>),
+    /// Never anything to iterate.
+    Null,
 }
 impl Iterator for RawEventsIter<'_> {
     type Item = crate::events::raw::Event<InternalID>;
@@ -168,8 +225,39 @@ impl Iterator for RawEventsIter<'_> {
             Self::Wayland(wl) => wl.next().cloned().map(crate::events::raw::Event::id_into),
             #[cfg(ink_rts)]
             Self::Ink(ink) => ink.next().cloned().map(crate::events::raw::Event::id_into),
+            #[cfg(custom_backend)]
+            Self::Custom(custom) => custom
+                .next()
+                .cloned()
+                .map(crate::events::raw::Event::id_into),
+            Self::Null => None,
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            #[cfg(wl_tablet)]
+            Self::Wayland(wl) => wl.size_hint(),
+            #[cfg(ink_rts)]
+            Self::Ink(ink) => ink.size_hint(),
+            #[cfg(custom_backend)]
+            Self::Custom(custom) => custom.size_hint(),
+            Self::Null => (0, Some(0)),
+        }
+    }
+}
+impl ExactSizeIterator for RawEventsIter<'_> {}
+
+/// A pad control to describe, see [`PlatformImpl::set_feedback`].
+#[derive(Clone, Copy, Debug)]
+// Unread by backends without pads.
+#[allow(dead_code)]
+pub(crate) enum FeedbackTarget<'a> {
+    Button {
+        pad: &'a InternalID,
+        button_idx: u32,
+    },
+    Ring(&'a InternalID),
+    Strip(&'a InternalID),
 }
 
 /// Trait that all platforms implement, giving the main `Manager` higher-level access to the black box.
@@ -177,8 +265,44 @@ impl Iterator for RawEventsIter<'_> {
 pub(crate) trait PlatformImpl {
     #[allow(clippy::missing_errors_doc)]
     fn pump(&mut self) -> Result<(), crate::PumpError>;
+    /// OS cursor position as of the last pump, in the same space as [`crate::axis::Pose::position`].
+    #[must_use]
+    fn cursor_position(&self) -> Option<[f32; 2]>;
     #[must_use]
     fn timestamp_granularity(&self) -> Option<std::time::Duration>;
+    /// Negotiated protocol versions, for backends with such a concept.
+    #[must_use]
+    fn protocol_versions(&self) -> Option<crate::ProtocolVersions>;
+    /// See [`crate::Manager::backend_info`].
+    #[must_use]
+    fn backend_info(&self) -> crate::BackendInfo;
+    /// See [`crate::Manager::accessibility`].
+    #[must_use]
+    fn accessibility(&self) -> crate::Accessibility;
+    /// See [`crate::Manager::hide_cursor_while_down`].
+    fn hide_cursor_while_down(&mut self, hide: bool);
+    /// See [`crate::Manager::set_hit_region`]. The crate filters events regardless, this is only a chance for the
+    /// platform to skip the work to begin with.
+    fn set_hit_region(&mut self, region: Option<crate::region::Rect>);
+    /// See [`crate::Manager::add_window`], the caller of which vouches for the window outliving the manager.
+    #[allow(clippy::missing_errors_doc)]
+    fn add_window(
+        &mut self,
+        window: raw_window_handle::RawWindowHandle,
+        id: crate::WindowId,
+    ) -> Result<(), crate::AddWindowError>;
+    /// See [`crate::Manager::remove_window`]. Never given [`crate::WindowId::MAIN`].
+    fn remove_window(&mut self, id: crate::WindowId);
+    /// See [`crate::Manager::set_button_feedback`].
+    #[allow(clippy::missing_errors_doc)]
+    fn set_feedback(
+        &mut self,
+        target: FeedbackTarget<'_>,
+        description: &str,
+    ) -> Result<(), crate::FeedbackError>;
+    /// Recent raw platform messages, if retained.
+    #[must_use]
+    fn debug_dump(&self) -> Option<String>;
     #[must_use]
     fn pads(&self) -> &[crate::pad::Pad];
     #[must_use]
@@ -193,9 +317,27 @@ pub(crate) trait PlatformImpl {
 /// Enum cause why not, (almost?) always has one variant and is thus compiles away to the inner type transparently.
 /// Even empty enum is OK, since everything involving it becomes essentially `match ! {}` which is sound :D
 #[enum_dispatch::enum_dispatch(PlatformImpl)]
+// Custom backends are boxed and tiny, but there's only ever one of these around anyway.
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum PlatformManager {
     #[cfg(wl_tablet)]
     Wayland(wl::Manager),
     #[cfg(ink_rts)]
     Ink(ink::Manager),
+    #[cfg(custom_backend)]
+    Custom(custom::Manager),
+    Null(null::Manager),
+}
+
+/// Which public backend kind the given manager implements.
+pub(crate) fn backend_of(manager: &PlatformManager) -> crate::Backend {
+    match manager {
+        #[cfg(wl_tablet)]
+        PlatformManager::Wayland(_) => crate::Backend::WaylandTabletUnstableV2,
+        #[cfg(ink_rts)]
+        PlatformManager::Ink(_) => crate::Backend::WindowsInkRealTimeStylus,
+        #[cfg(custom_backend)]
+        PlatformManager::Custom(_) => crate::Backend::Custom,
+        PlatformManager::Null(_) => crate::Backend::Null,
+    }
 }
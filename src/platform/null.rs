@@ -0,0 +1,58 @@
+//! A backend of nothing at all, for when no real one fits. See [`crate::Builder::null_fallback`].
+
+/// No devices, no events, no problem.
+pub struct Manager;
+
+impl super::PlatformImpl for Manager {
+    fn pump(&mut self) -> Result<(), crate::PumpError> {
+        Ok(())
+    }
+    fn cursor_position(&self) -> Option<[f32; 2]> {
+        None
+    }
+    fn timestamp_granularity(&self) -> Option<std::time::Duration> {
+        None
+    }
+    fn protocol_versions(&self) -> Option<crate::ProtocolVersions> {
+        None
+    }
+    fn backend_info(&self) -> crate::BackendInfo {
+        crate::BackendInfo::Null
+    }
+    fn accessibility(&self) -> crate::Accessibility {
+        crate::Accessibility::default()
+    }
+    fn hide_cursor_while_down(&mut self, _: bool) {}
+    fn set_hit_region(&mut self, _: Option<crate::region::Rect>) {}
+    fn add_window(
+        &mut self,
+        _: raw_window_handle::RawWindowHandle,
+        _: crate::WindowId,
+    ) -> Result<(), crate::AddWindowError> {
+        Err(crate::AddWindowError::Unsupported)
+    }
+    fn remove_window(&mut self, _: crate::WindowId) {}
+    fn set_feedback(
+        &mut self,
+        _: super::FeedbackTarget<'_>,
+        _: &str,
+    ) -> Result<(), crate::FeedbackError> {
+        // There's nothing to describe.
+        Err(crate::FeedbackError::UnknownControl)
+    }
+    fn debug_dump(&self) -> Option<String> {
+        None
+    }
+    fn pads(&self) -> &[crate::pad::Pad] {
+        &[]
+    }
+    fn tools(&self) -> &[crate::tool::Tool] {
+        &[]
+    }
+    fn tablets(&self) -> &[crate::tablet::Tablet] {
+        &[]
+    }
+    fn raw_events(&self) -> super::RawEventsIter<'_> {
+        super::RawEventsIter::Null
+    }
+}
@@ -4,12 +4,26 @@
 //! (compiling for a wayland target + has deps, or is building docs).
 use crate::{
     events::raw as raw_events,
-    pad::{Group, Ring, Strip, TouchSource},
+    pad::{Dial, Group, Ring, Strip, TouchSource},
 };
 pub type ID = wayland_backend::client::ObjectId;
 pub type ButtonID = u32;
+/// Guess the role of a tool button from its evdev code, as in `linux/input-event-codes.h`.
+pub(crate) fn button_role(code: ButtonID) -> Option<crate::tool::ButtonRole> {
+    use crate::tool::ButtonRole;
+    const BTN_LEFT: u32 = 0x110;
+    const BTN_TOUCH: u32 = 0x14a;
+    const BTN_STYLUS: u32 = 0x14b;
+    const BTN_STYLUS2: u32 = 0x14c;
+    match code {
+        BTN_LEFT | BTN_TOUCH => Some(ButtonRole::Primary),
+        BTN_STYLUS => Some(ButtonRole::Barrel1),
+        BTN_STYLUS2 => Some(ButtonRole::Barrel2),
+        _ => None,
+    }
+}
 use wayland_client::{
-    protocol::{wl_registry, wl_seat},
+    protocol::{wl_callback, wl_keyboard, wl_registry, wl_seat, wl_surface, wl_touch},
     Connection, Dispatch, Proxy, QueueHandle,
 };
 use wayland_protocols::wp::tablet::zv2::client as wl_tablet;
@@ -26,7 +40,9 @@ use crate::{
 use super::InternalID;
 pub struct Manager {
     _display: wayland_client::protocol::wl_display::WlDisplay,
-    _conn: wayland_client::Connection,
+    conn: wayland_client::Connection,
+    /// Set once the connection is found dead, after which it is never touched again.
+    lost: Option<wayland_client::backend::WaylandError>,
     queue: wayland_client::EventQueue<TabletState>,
     _qh: wayland_client::QueueHandle<TabletState>,
     state: TabletState,
@@ -34,44 +50,226 @@ pub struct Manager {
 
 mod pad_impl;
 mod tool_impl;
+#[cfg(feature = "wayland-udev")]
+mod udev;
 
 impl Manager {
-    /// Creates a tablet manager with from the given pointer to `wl_display`.
+    /// Creates a tablet manager with from the given pointer to `wl_display`, reporting the events of the given
+    /// `wl_surface` if any, or else those of every surface.
     /// # Safety
-    /// The given display pointer must be valid as long as the returned `Manager` is alive.
+    /// The given display and surface pointers must be valid as long as the returned `Manager` is alive.
     pub(crate) unsafe fn build_wayland_display(
-        _: crate::builder::Builder,
+        opts: &crate::builder::Builder,
         wl_display: *mut (),
+        wl_surface: Option<*mut ()>,
     ) -> Manager {
         // Safety - deferred to this fn's contract
         let backend =
             unsafe { wayland_backend::client::Backend::from_foreign_display(wl_display.cast()) };
+        // Safety - deferred to this fn's contract. A surface of the wrong type is just as good as none.
+        let surface = wl_surface.and_then(|surface| {
+            unsafe {
+                wayland_backend::client::ObjectId::from_ptr(
+                    wl_surface::WlSurface::interface(),
+                    surface.cast(),
+                )
+            }
+            .ok()
+        });
         let conn = wayland_client::Connection::from_backend(backend);
         let display = conn.display();
         let queue = conn.new_event_queue();
         let qh = queue.handle();
         // Allow the manager impl to sift through and capture extention handles
         display.get_registry(&qh, ());
+        // Find out when the globals are done being announced, to eventually report `EnumerationComplete`.
+        display.sync(&qh, EnumerationSync::Globals);
         Manager {
             _display: display,
-            _conn: conn,
+            conn,
+            lost: None,
             queue,
             _qh: qh,
-            state: TabletState::default(),
+            state: TabletState {
+                debug_log: crate::util::RingLog::new(opts.debug_history),
+                capture_touch: opts.capture_touch,
+                report_raw_axes: opts.report_raw_axes,
+                surface,
+                ..Default::default()
+            },
+        }
+    }
+}
+impl Manager {
+    /// Fail if the connection has died, such as from a compositor crash.
+    fn check_connection(&mut self) -> Result<(), crate::PumpError> {
+        if self.lost.is_none() {
+            // The host toolkit does the reading, so errors are usually found by it first. Both share the
+            // underlying `wl_display`, which `flush` checks for errors before anything else. A full socket buffer
+            // is just a busy connection though, and the backend doesn't count it as fatal either.
+            self.lost = self
+                .conn
+                .flush()
+                .err()
+                .filter(|error| {
+                    !matches!(
+                        error,
+                        wayland_client::backend::WaylandError::Io(io)
+                            if io.kind() == std::io::ErrorKind::WouldBlock
+                    )
+                })
+                .or_else(|| self.conn.backend().last_error());
+        }
+        match &self.lost {
+            Some(error) => Err(crate::PumpError::WaylandConnectionLost(error.clone())),
+            None => Ok(()),
         }
     }
 }
 impl super::PlatformImpl for Manager {
     #[allow(clippy::missing_errors_doc)]
     fn pump(&mut self) -> Result<(), crate::PumpError> {
+        self.check_connection()?;
         self.state.cleanup_start();
-        self.queue.dispatch_pending(&mut self.state)?;
+        let dispatched = self.queue.dispatch_pending(&mut self.state);
+        // A failed dispatch is most likely due to the connection going down, which deserves the more specific error.
+        self.check_connection()?;
+        dispatched?;
         Ok(())
     }
+    fn cursor_position(&self) -> Option<[f32; 2]> {
+        // The compositor draws the cursor from the very same tool events we see,
+        // so there's nothing else to compare against.
+        None
+    }
     fn timestamp_granularity(&self) -> Option<std::time::Duration> {
         // Wayland always reports, and with millisecond granularity.
         Some(std::time::Duration::from_millis(1))
     }
+    fn protocol_versions(&self) -> Option<crate::ProtocolVersions> {
+        Some(self.state.versions)
+    }
+    fn backend_info(&self) -> crate::BackendInfo {
+        let versions = self.state.versions;
+        crate::BackendInfo::Wayland(crate::WaylandInfo {
+            versions,
+            strips: versions.tablet_manager.is_some(),
+            dials: versions
+                .tablet_manager
+                .is_some_and(|version| version.bound >= 2),
+            skipped_messages: self.state.skipped_messages,
+        })
+    }
+    fn accessibility(&self) -> crate::Accessibility {
+        // No protocol for this, but it's what the compositor and every toolkit go by.
+        let cursor_size = std::env::var("XCURSOR_SIZE")
+            .ok()
+            .and_then(|size| size.trim().parse().ok())
+            .filter(|&size| size > 0);
+        crate::Accessibility {
+            cursor_size,
+            high_contrast: None,
+        }
+    }
+    fn hide_cursor_while_down(&mut self, hide: bool) {
+        self.state.hide_cursor_while_down = hide;
+    }
+    fn set_hit_region(&mut self, _: Option<crate::region::Rect>) {
+        // The protocol has no say in it, events arrive for the whole surface.
+    }
+    fn add_window(
+        &mut self,
+        window: raw_window_handle::RawWindowHandle,
+        id: crate::WindowId,
+    ) -> Result<(), crate::AddWindowError> {
+        let raw_window_handle::RawWindowHandle::Wayland(window) = window else {
+            return Err(crate::AddWindowError::ForeignWindow);
+        };
+        // Safety: the surface outlives us, as per `crate::Manager::add_window`'s contract.
+        let surface = unsafe {
+            wayland_backend::client::ObjectId::from_ptr(
+                wl_surface::WlSurface::interface(),
+                window.surface.as_ptr().cast(),
+            )
+        }
+        .map_err(|_| crate::AddWindowError::ForeignWindow)?;
+        self.state.windows.push((surface, id));
+        Ok(())
+    }
+    fn remove_window(&mut self, id: crate::WindowId) {
+        // The surface is no longer ours, so anything further on it is ignored like any other stranger's.
+        self.state.windows.retain(|(_, window)| *window != id);
+    }
+    fn set_feedback(
+        &mut self,
+        target: super::FeedbackTarget<'_>,
+        description: &str,
+    ) -> Result<(), crate::FeedbackError> {
+        use super::FeedbackTarget;
+        use crate::FeedbackError;
+        let state = &self.state;
+        let serial_of = |group: &ID| {
+            state
+                .mode_serials
+                .get(group)
+                .copied()
+                .ok_or(FeedbackError::NoModeYet)
+        };
+        match target {
+            FeedbackTarget::Button { pad, button_idx } => {
+                let pad = pad.unwrap_wl();
+                let group = state
+                    .pads
+                    .iter()
+                    .find(|p| HasWlId::id(*p) == pad)
+                    .and_then(|p| {
+                        p.groups
+                            .iter()
+                            .find(|g| g.buttons.binary_search(&button_idx).is_ok())
+                    })
+                    .ok_or(FeedbackError::UnknownControl)?;
+                let serial = serial_of(HasWlId::id(group))?;
+                wl_tablet::zwp_tablet_pad_v2::ZwpTabletPadV2::from_id(&self.conn, pad.clone())
+                    .map_err(|_| FeedbackError::UnknownControl)?
+                    .set_feedback(button_idx, description.to_owned(), serial);
+            }
+            FeedbackTarget::Ring(ring) => {
+                let ring = ring.unwrap_wl();
+                let group = state
+                    .ring_associations
+                    .get(ring)
+                    .ok_or(FeedbackError::UnknownControl)?;
+                let serial = serial_of(group)?;
+                wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2::from_id(
+                    &self.conn,
+                    ring.clone(),
+                )
+                .map_err(|_| FeedbackError::UnknownControl)?
+                .set_feedback(description.to_owned(), serial);
+            }
+            FeedbackTarget::Strip(strip) => {
+                let strip = strip.unwrap_wl();
+                let group = state
+                    .strip_associations
+                    .get(strip)
+                    .ok_or(FeedbackError::UnknownControl)?;
+                let serial = serial_of(group)?;
+                wl_tablet::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2::from_id(
+                    &self.conn,
+                    strip.clone(),
+                )
+                .map_err(|_| FeedbackError::UnknownControl)?
+                .set_feedback(description.to_owned(), serial);
+            }
+        }
+        Ok(())
+    }
+    fn debug_dump(&self) -> Option<String> {
+        self.state
+            .debug_log
+            .as_ref()
+            .map(crate::util::RingLog::dump)
+    }
     fn pads(&self) -> &[crate::pad::Pad] {
         &self.state.pads
     }
@@ -107,6 +305,12 @@ impl HasWlId for Tool {
             wacom_id: None,
             tool_type: None,
             axes: crate::axis::FullInfo::default(),
+            // Not reported by the protocol.
+            buttons: Vec::new(),
+            button_count: None,
+            // Nor is anything outside of proximity.
+            reports_out_of_proximity: false,
+            tip_threshold: None,
         }
     }
     fn id(&self) -> &ID {
@@ -125,6 +329,12 @@ impl HasWlId for Tablet {
             internal_id: id.into(),
             name: None,
             usb_id: None,
+            // Not a part of the protocol.
+            report_rate: None,
+            rotation: None,
+            // Filled from udev by `Path`, if enabled.
+            physical_size: None,
+            resolution: None,
         }
     }
     fn id(&self) -> &ID {
@@ -149,6 +359,8 @@ impl HasWlId for Pad {
             // the constructor for this value is never sent.
             total_buttons: 0,
             groups: Vec::new(),
+            // Known once it enters a tablet.
+            tablet: None,
         }
     }
     fn id(&self) -> &ID {
@@ -168,8 +380,10 @@ impl HasWlId for Group {
             buttons: Vec::new(),
             rings: Vec::new(),
             strips: Vec::new(),
+            dials: Vec::new(),
             feedback: None,
             mode_count: None,
+            mode_indicators: None,
         }
     }
     fn id(&self) -> &ID {
@@ -187,6 +401,8 @@ impl HasWlId for Ring {
         Ring {
             internal_id: id.into(),
             granularity: None,
+            // Assigned by the owning group.
+            index_in_group: 0,
         }
     }
     fn id(&self) -> &ID {
@@ -204,6 +420,8 @@ impl HasWlId for Strip {
         Strip {
             internal_id: id.into(),
             granularity: None,
+            // Assigned by the owning group.
+            index_in_group: 0,
         }
     }
     fn id(&self) -> &ID {
@@ -254,7 +472,7 @@ impl<T: HasWlId> PartialVec<T> {
 /// only one or none of these events may happen per tool per frame.
 #[derive(PartialEq, Eq)]
 enum FrameState {
-    In(ID),
+    In(ID, Option<crate::WindowId>),
     Out,
     Down,
     Up,
@@ -271,11 +489,33 @@ struct FrameInProgress {
     roll: Option<f32>,
     wheel: Option<(f32, i32)>,
     slider: Option<f32>,
+    // The same values as they came in, for `Builder::report_raw_axes`.
+    raw: crate::axis::RawPose,
     // Stream of button events that happened during this frame.
     // By the nature of frames, these are considered to have happened at the same time, but order is still preserved.
     buttons: smallvec::SmallVec<[(u32, bool); 1]>,
 }
 
+/// Highest `wl_seat` version we care for. We need nothing from it, save for `release` from v5 and touch shapes
+/// from v6.
+const SEAT_VERSION: u32 = 6;
+/// Highest `zwp_tablet_manager_v2` version we know how to speak. v2 brings pad dials.
+const TABLET_MANAGER_VERSION: u32 = 2;
+
+/// Which stage of the initial device burst a `wl_display::sync` is waiting on.
+/// Since the compositor handles requests in order, a sync's `done` arrives only after
+/// everything sent in response to the requests before it.
+enum EnumerationSync {
+    /// Waiting for the registry to announce every global, during which the tablet seat is requested.
+    Globals,
+    /// Waiting for the tablet seat to announce every device.
+    Devices,
+}
+
+/// A `wl_display::sync` sent right after acquiring the keyboard. By its `done`, the compositor
+/// would've told us if we had focus, so no news means we don't.
+struct FocusSync;
+
 enum ConstructID {
     Tablet(ID),
     Pad(ID),
@@ -284,10 +524,30 @@ enum ConstructID {
 
 #[derive(Default)]
 struct TabletState {
-    // Internal goobers
-    seat: Option<wl_seat::WlSeat>,
-    manager: Option<wl_tablet::zwp_tablet_manager_v2::ZwpTabletManagerV2>,
+    // The surface of the window we were made for. Events over other surfaces are none of our business, such that
+    // each window of an app may have its own manager. `None` if unknown, in which case every surface is ours.
+    surface: Option<wayland_backend::client::ObjectId>,
+    // Further surfaces, from `Manager::add_window`.
+    windows: Vec<(wayland_backend::client::ObjectId, crate::WindowId)>,
+    // Tools in proximity of surfaces other than ours, ignored until they leave.
+    elsewhere: std::collections::HashSet<ID>,
+    // Internal goobers, along with the registry names of their globals
+    seat: Option<(u32, wl_seat::WlSeat)>,
+    manager: Option<(u32, wl_tablet::zwp_tablet_manager_v2::ZwpTabletManagerV2)>,
     tablet_seat: Option<wl_tablet::zwp_tablet_seat_v2::ZwpTabletSeatV2>,
+    // Only used for tracking focus.
+    keyboard: Option<wl_keyboard::WlKeyboard>,
+    // `None` until known.
+    focused: Option<bool>,
+    // See `Builder::capture_touch`. The touch object is only bound if set.
+    capture_touch: bool,
+    // See `Builder::report_raw_axes`.
+    report_raw_axes: bool,
+    touch: Option<wl_touch::WlTouch>,
+    // Fingers currently down, by their wayland ID.
+    fingers: std::collections::HashMap<i32, crate::touch::Finger>,
+    // Timestamp of the touch frame in progress, if any of its events had one.
+    touch_time: Option<u32>,
     // Space for in-progress constructor executions.
     partial_tablets: PartialVec<Tablet>,
     partial_tools: PartialVec<Tool>,
@@ -297,20 +557,129 @@ struct TabletState {
     tablets: Vec<Tablet>,
     tools: Vec<Tool>,
     pads: Vec<Pad>,
+    // Events of pads still under construction, held back until they've been announced as `Added`.
+    early_pad_events: Vec<(ID, raw_events::PadEvent<ID>)>,
     // Things to destroy:
     destroy_next_frame: Vec<ConstructID>,
-    // Associations for which pad group each ring and strip are connected
-    // `{ring or strip} -> group`
+    // Associations for which pad group each ring, strip, and dial are connected
+    // `{ring, strip, or dial} -> group`
     ring_associations: std::collections::HashMap<ID, ID>,
     strip_associations: std::collections::HashMap<ID, ID>,
+    dial_associations: std::collections::HashMap<ID, ID>,
     // Associations for which pad each group is connected
     // `group -> pad`
     group_associations: std::collections::HashMap<ID, ID>,
     // Partial and complete event tracking.
     frames_in_progress: Vec<FrameInProgress>,
     events: Vec<crate::events::raw::Event<ID>>,
+    // Raw message history, if requested.
+    debug_log: Option<crate::util::RingLog>,
+    // Versions of the globals in use. Events added in later versions must be gated on these.
+    versions: crate::ProtocolVersions,
+    // See `Manager::hide_cursor_while_down`.
+    hide_cursor_while_down: bool,
+    // Serial of each in-proximity tool's `proximity_in`, which setting its cursor requires.
+    proximity_serials: std::collections::HashMap<ID, u32>,
+    // Serial of each group's latest `mode_switch`, which setting feedback requires.
+    mode_serials: std::collections::HashMap<ID, u32>,
+    // See `WaylandInfo::skipped_messages`.
+    skipped_messages: u64,
 }
 impl TabletState {
+    /// Note down a message, if debug history is enabled.
+    fn log_message(&mut self, proxy: &impl Proxy, event: &impl std::fmt::Debug) {
+        if let Some(log) = &mut self.debug_log {
+            log.push(format!("{}: {event:?}", proxy.id()));
+        }
+    }
+    /// The last message made no sense and is being skipped, rather than trusted or panicked over.
+    fn skip_message(&mut self, why: &str) {
+        self.skipped_messages += 1;
+        if let Some(log) = &mut self.debug_log {
+            log.push(format!("(skipped: {why})"));
+        }
+    }
+    /// Whether the given tablet has been announced and not since removed.
+    fn knows_tablet(&self, tablet: &ID) -> bool {
+        self.tablets.iter().any(|t| HasWlId::id(t) == tablet)
+    }
+    /// Report a change in focus, if it is one.
+    fn set_focus(&mut self, focused: bool) {
+        if self.focused != Some(focused) {
+            self.focused = Some(focused);
+            self.events.push(raw_events::Event::WindowFocus { focused });
+        }
+    }
+    /// Whether events over the given surface are to be reported.
+    fn is_ours(&self, surface: &wl_surface::WlSurface) -> bool {
+        self.window_of(surface).is_some()
+    }
+    /// Which of our windows the surface is, `Some(None)` if it's ours but we can't tell which.
+    #[allow(clippy::option_option)]
+    fn window_of(&self, surface: &wl_surface::WlSurface) -> Option<Option<crate::WindowId>> {
+        let surface = surface.id();
+        if let Some((_, window)) = self.windows.iter().find(|(ours, _)| *ours == surface) {
+            return Some(Some(*window));
+        }
+        match &self.surface {
+            Some(ours) => (*ours == surface).then_some(Some(crate::WindowId::MAIN)),
+            None => Some(None),
+        }
+    }
+    fn release_keyboard(&mut self) {
+        if let Some(keyboard) = self.keyboard.take() {
+            if keyboard.version() >= 3 {
+                keyboard.release();
+            }
+        }
+    }
+    /// Release the touch object, lifting any fingers that were down on it.
+    fn release_touch(&mut self) {
+        if let Some(touch) = self.touch.take() {
+            if touch.version() >= 3 {
+                touch.release();
+            }
+        }
+        if !self.fingers.is_empty() {
+            self.fingers.clear();
+            self.events
+                .push(raw_events::Event::Touch(crate::touch::TouchEvent::Cancel));
+        }
+    }
+    /// Report an event of a pad, holding it back if the pad is yet to be announced such that clients never hear of
+    /// a pad that's not in `pads()`.
+    fn push_pad_event(&mut self, pad: ID, event: raw_events::PadEvent<ID>) {
+        if self.pads.iter().any(|p| HasWlId::id(p) == &pad) {
+            self.events.push(raw_events::Event::Pad { pad, event });
+        } else if self
+            .partial_pads
+            .constructing
+            .iter()
+            .any(|p| HasWlId::id(p) == &pad)
+        {
+            self.early_pad_events.push((pad, event));
+        } else {
+            self.skip_message("event for an unknown pad");
+        }
+    }
+    /// Announce a freshly built pad, followed by whatever it got up to in the meantime.
+    fn pad_done(&mut self, pad: Pad) {
+        let pad_id = HasWlId::id(&pad).clone();
+        self.pads.push(pad);
+        self.events.push(raw_events::Event::Pad {
+            pad: pad_id.clone(),
+            event: raw_events::PadEvent::Added,
+        });
+        let (early, rest) = std::mem::take(&mut self.early_pad_events)
+            .into_iter()
+            .partition(|(early, _)| early == &pad_id);
+        self.early_pad_events = rest;
+        self.events.extend(
+            early
+                .into_iter()
+                .map(|(pad, event)| raw_events::Event::Pad { pad, event }),
+        );
+    }
     fn destroy_tool(&mut self, tool: ID) {
         self.partial_tools.destroy(&tool);
         // Defer destruction, that way `Removed` events can still refer by reference.
@@ -318,11 +687,19 @@ impl TabletState {
     }
     fn destroy_tablet(&mut self, tablet: ID) {
         self.partial_tablets.destroy(&tablet);
+        // The server should already have had the pads leave, but don't leave them dangling if not.
+        let removed = crate::InternalID::from(tablet.clone());
+        for pad in &mut self.pads {
+            if pad.tablet.as_ref() == Some(&removed) {
+                pad.tablet = None;
+            }
+        }
         // Defer destruction, that way `Removed` events can still refer by reference.
         self.destroy_next_frame.push(ConstructID::Tablet(tablet));
     }
     fn destroy_pad(&mut self, pad: ID) {
         self.partial_pads.destroy(&pad);
+        self.early_pad_events.retain(|(early, _)| early != &pad);
         // Defer destruction, that way `Removed` events can still refer by reference.
         self.destroy_next_frame.push(ConstructID::Pad(pad));
     }
@@ -361,6 +738,7 @@ impl TabletState {
                 roll: None,
                 wheel: None,
                 slider: None,
+                raw: crate::axis::RawPose::default(),
                 buttons: smallvec::SmallVec::new(),
             });
             self.frames_in_progress.last_mut().unwrap()
@@ -379,12 +757,15 @@ impl TabletState {
 
             // emit ins and downs first...
             match frame.state_transition {
-                Some(FrameState::In(ref tablet)) => self.events.push(raw_events::Event::Tool {
-                    tool: tool.clone(),
-                    event: raw_events::ToolEvent::In {
-                        tablet: tablet.clone(),
-                    },
-                }),
+                Some(FrameState::In(ref tablet, window)) => {
+                    self.events.push(raw_events::Event::Tool {
+                        tool: tool.clone(),
+                        event: raw_events::ToolEvent::In {
+                            tablet: tablet.clone(),
+                            window,
+                        },
+                    });
+                }
                 Some(FrameState::Down) => self.events.push(raw_events::Event::Tool {
                     tool: tool.clone(),
                     event: raw_events::ToolEvent::Down,
@@ -399,10 +780,12 @@ impl TabletState {
             if let Some(position) = frame.position.filter(|[x, y]| !x.is_nan() && !y.is_nan()) {
                 // Filter to prevent NaN's. This is not currently an invariant we guarantee since I can't figure out how
                 // to ergonomically express it at the type level, but the legwork is already done:
+                // Try to make the Option into Niche'd option. If NaN, fail back to None.
+                let distance = frame.distance.try_into().unwrap_or(NicheF32::NONE);
                 let pose = Pose {
                     position,
-                    // Try to make the Option into Niche'd option. If NaN, fail back to None.
-                    distance: frame.distance.try_into().unwrap_or(NicheF32::NONE),
+                    distance,
+                    raw_distance: distance,
                     pressure: frame.pressure.try_into().unwrap_or(NicheF32::NONE),
                     roll: frame.roll.try_into().unwrap_or(NicheF32::NONE),
                     slider: frame.slider.try_into().unwrap_or(NicheF32::NONE),
@@ -416,6 +799,12 @@ impl TabletState {
                     tool: tool.clone(),
                     event: raw_events::ToolEvent::Pose(pose),
                 });
+                if self.report_raw_axes {
+                    self.events.push(raw_events::Event::Tool {
+                        tool: tool.clone(),
+                        event: raw_events::ToolEvent::RawPose(Box::new(frame.raw)),
+                    });
+                }
             }
             // Emit buttons...
             for &(button_id, pressed) in &frame.buttons {
@@ -457,7 +846,7 @@ impl TabletState {
         // Emit frame. This may be an empty frame if above was None, that's alright!
         self.events.push(raw_events::Event::Tool {
             tool: tool.clone(),
-            event: raw_events::ToolEvent::Frame(Some(FrameTimestamp(
+            event: raw_events::ToolEvent::Frame(Some(FrameTimestamp::hardware(
                 std::time::Duration::from_millis(u64::from(millis)),
             ))),
         });
@@ -471,10 +860,71 @@ impl TabletState {
         if self.tablet_seat.is_some() {
             return;
         }
-        if let Some((seat, tablet)) = self.seat.as_ref().zip(self.manager.as_ref()) {
+        if let Some(((_, seat), (_, tablet))) = self.seat.as_ref().zip(self.manager.as_ref()) {
             self.tablet_seat = Some(tablet.get_tablet_seat(seat, qh, ()));
         }
     }
+    /// Release the tablet seat, emitting `Removed` for every device it advertised. The devices
+    /// are inert from here on, and will be re-announced if a tablet seat is acquired anew.
+    fn release_tablet_seat(&mut self) {
+        let Some(tablet_seat) = self.tablet_seat.take() else {
+            return;
+        };
+        tablet_seat.destroy();
+
+        // Skip those who already got a `Removed` this frame.
+        let pending = |this: &Self, id: &ID| {
+            this.destroy_next_frame.iter().any(|destroy| match destroy {
+                ConstructID::Pad(other) | ConstructID::Tablet(other) | ConstructID::Tool(other) => {
+                    other == id
+                }
+            })
+        };
+        // Remove dependents before the tablets they refer to.
+        let tools: Vec<ID> = self.tools.iter().map(|t| HasWlId::id(t).clone()).collect();
+        for tool in tools {
+            if !pending(self, &tool) {
+                self.destroy_tool(tool.clone());
+                self.events.push(raw_events::Event::Tool {
+                    tool,
+                    event: raw_events::ToolEvent::Removed,
+                });
+            }
+        }
+        let pads: Vec<ID> = self.pads.iter().map(|p| HasWlId::id(p).clone()).collect();
+        for pad in pads {
+            if !pending(self, &pad) {
+                self.destroy_pad(pad.clone());
+                self.events.push(raw_events::Event::Pad {
+                    pad,
+                    event: raw_events::PadEvent::Removed,
+                });
+            }
+        }
+        let tablets: Vec<ID> = self
+            .tablets
+            .iter()
+            .map(|t| HasWlId::id(t).clone())
+            .collect();
+        for tablet in tablets {
+            if !pending(self, &tablet) {
+                self.destroy_tablet(tablet.clone());
+                self.events.push(raw_events::Event::Tablet {
+                    tablet,
+                    event: raw_events::TabletEvent::Removed,
+                });
+            }
+        }
+        // Anything still under construction will never finish.
+        self.partial_tablets = PartialVec::default();
+        self.partial_tools = PartialVec::default();
+        self.partial_pads = PartialVec::default();
+        self.partial_groups = PartialVec::default();
+        self.ring_associations.clear();
+        self.strip_associations.clear();
+        self.dial_associations.clear();
+        self.group_associations.clear();
+    }
 }
 
 impl Dispatch<wl_registry::WlRegistry, ()> for TabletState {
@@ -486,39 +936,247 @@ impl Dispatch<wl_registry::WlRegistry, ()> for TabletState {
         _: &Connection,
         qh: &QueueHandle<Self>,
     ) {
-        if let wl_registry::Event::Global {
-            name,
-            interface,
-            version,
-        } = event
-        {
-            match &interface[..] {
-                "wl_seat" => {
-                    this.seat = Some(registry.bind(name, version, qh, ()));
+        this.log_message(registry, &event);
+        match event {
+            wl_registry::Event::Global {
+                name,
+                interface,
+                version,
+            } => match &interface[..] {
+                // Only the first seat is of interest.
+                "wl_seat" if this.seat.is_none() => {
+                    let bound = version.min(SEAT_VERSION);
+                    this.versions.seat = Some(crate::ProtocolVersion {
+                        advertised: version,
+                        bound,
+                    });
+                    this.seat = Some((name, registry.bind(name, bound, qh, ())));
                     // Need a seat and a tablet manager to bind tablet seat.
                     this.try_acquire_tablet_seat(qh);
                 }
-                "zwp_tablet_manager_v2" => {
-                    this.manager = Some(registry.bind(name, version, qh, ()));
+                "zwp_tablet_manager_v2" if this.manager.is_none() => {
+                    let bound = version.min(TABLET_MANAGER_VERSION);
+                    this.versions.tablet_manager = Some(crate::ProtocolVersion {
+                        advertised: version,
+                        bound,
+                    });
+                    this.manager = Some((name, registry.bind(name, bound, qh, ())));
                     // Need a seat and a tablet manager to bind tablet seat.
                     this.try_acquire_tablet_seat(qh);
                 }
                 _ => (),
+            },
+            // Either half of the tablet seat going away takes every device with it. Should the global
+            // come back (e.g. a restarted compositor re-exporting it), it is re-acquired above.
+            wl_registry::Event::GlobalRemove { name } => {
+                if this
+                    .seat
+                    .as_ref()
+                    .is_some_and(|(seat_name, _)| *seat_name == name)
+                {
+                    this.release_tablet_seat();
+                    this.release_keyboard();
+                    this.release_touch();
+                    this.versions.seat = None;
+                    if let Some((_, seat)) = this.seat.take() {
+                        if seat.version() >= 5 {
+                            seat.release();
+                        }
+                    }
+                } else if this
+                    .manager
+                    .as_ref()
+                    .is_some_and(|(manager_name, _)| *manager_name == name)
+                {
+                    this.release_tablet_seat();
+                    this.versions.tablet_manager = None;
+                    if let Some((_, manager)) = this.manager.take() {
+                        manager.destroy();
+                    }
+                }
+            }
+            // ne
+            _ => (),
+        }
+    }
+}
+impl Dispatch<wl_callback::WlCallback, EnumerationSync> for TabletState {
+    fn event(
+        this: &mut Self,
+        callback: &wl_callback::WlCallback,
+        event: wl_callback::Event,
+        stage: &EnumerationSync,
+        conn: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        this.log_message(callback, &event);
+        let wl_callback::Event::Done { .. } = event else {
+            return;
+        };
+        match stage {
+            // Tablet seat was requested while handling the globals, wait for it to describe its devices.
+            EnumerationSync::Globals if this.tablet_seat.is_some() => {
+                conn.display().sync(qh, EnumerationSync::Devices);
+            }
+            // No tablet seat (yet?), so there's nothing to enumerate.
+            EnumerationSync::Globals | EnumerationSync::Devices => {
+                this.events.push(raw_events::Event::EnumerationComplete);
             }
         }
     }
 }
 impl Dispatch<wl_seat::WlSeat, ()> for TabletState {
     fn event(
-        _: &mut Self,
-        _: &wl_seat::WlSeat,
-        _: wl_seat::Event,
+        this: &mut Self,
+        seat: &wl_seat::WlSeat,
+        event: wl_seat::Event,
         (): &(),
+        conn: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        this.log_message(seat, &event);
+        // We need to acquire the seat for binding a tablet to it, and otherwise only care for
+        // the keyboard to track focus with, and touch if asked for.
+        // Notably, tablets are not a seat capability - devices come and go through the
+        // tablet seat itself.
+        let wl_seat::Event::Capabilities {
+            capabilities: wayland_client::WEnum::Value(capabilities),
+        } = event
+        else {
+            return;
+        };
+        let has_keyboard = capabilities.contains(wl_seat::Capability::Keyboard);
+        if has_keyboard && this.keyboard.is_none() {
+            this.keyboard = Some(seat.get_keyboard(qh, ()));
+            conn.display().sync(qh, FocusSync);
+        } else if !has_keyboard && this.keyboard.is_some() {
+            this.release_keyboard();
+            this.set_focus(false);
+        }
+        let has_touch = capabilities.contains(wl_seat::Capability::Touch);
+        if this.capture_touch && has_touch && this.touch.is_none() {
+            this.touch = Some(seat.get_touch(qh, ()));
+        } else if !has_touch && this.touch.is_some() {
+            this.release_touch();
+        }
+    }
+}
+/// Wayland touch IDs only need to stay unique while down, so reinterpreting the bits is fine.
+#[allow(clippy::cast_sign_loss)]
+fn contact(id: i32) -> crate::touch::ContactID {
+    crate::touch::ContactID(u64::from(id as u32))
+}
+impl Dispatch<wl_touch::WlTouch, ()> for TabletState {
+    fn event(
+        this: &mut Self,
+        touch: &wl_touch::WlTouch,
+        event: wl_touch::Event,
+        (): &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        use crate::touch::{Finger, TouchEvent};
+        this.log_message(touch, &event);
+        let event = match event {
+            wl_touch::Event::Down {
+                time,
+                id,
+                x,
+                y,
+                surface,
+                ..
+            } => {
+                this.touch_time = Some(time);
+                // Fingers down elsewhere stay unknown to us, so the rest of their events are ignored too.
+                let Some(window) = this.window_of(&surface) else {
+                    return;
+                };
+                #[allow(clippy::cast_possible_truncation)]
+                let finger = Finger {
+                    contact: contact(id),
+                    position: [x as f32, y as f32],
+                    contact_size: None,
+                    window,
+                };
+                this.fingers.insert(id, finger);
+                TouchEvent::Down(finger)
+            }
+            wl_touch::Event::Motion { time, id, x, y } => {
+                this.touch_time = Some(time);
+                let Some(finger) = this.fingers.get_mut(&id) else {
+                    return;
+                };
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    finger.position = [x as f32, y as f32];
+                }
+                TouchEvent::Move(*finger)
+            }
+            wl_touch::Event::Shape { id, major, minor } => {
+                let Some(finger) = this.fingers.get_mut(&id) else {
+                    return;
+                };
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    finger.contact_size = Some([major as f32, minor as f32]);
+                }
+                TouchEvent::Move(*finger)
+            }
+            wl_touch::Event::Up { time, id, .. } => {
+                this.touch_time = Some(time);
+                if this.fingers.remove(&id).is_none() {
+                    return;
+                }
+                TouchEvent::Up(contact(id))
+            }
+            wl_touch::Event::Frame => TouchEvent::Frame(this.touch_time.take().map(|millis| {
+                FrameTimestamp::hardware(std::time::Duration::from_millis(u64::from(millis)))
+            })),
+            wl_touch::Event::Cancel => {
+                this.fingers.clear();
+                TouchEvent::Cancel
+            }
+            // Orientation is of no concern, the size is reported regardless of it.
+            _ => return,
+        };
+        this.events.push(raw_events::Event::Touch(event));
+    }
+}
+impl Dispatch<wl_keyboard::WlKeyboard, ()> for TabletState {
+    fn event(
+        this: &mut Self,
+        keyboard: &wl_keyboard::WlKeyboard,
+        event: wl_keyboard::Event,
+        (): &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        this.log_message(keyboard, &event);
+        // Keys and keymaps are of no concern (the keymap fd closes on drop).
+        match event {
+            wl_keyboard::Event::Enter { surface, .. } if this.is_ours(&surface) => {
+                this.set_focus(true);
+            }
+            wl_keyboard::Event::Leave { surface, .. } if this.is_ours(&surface) => {
+                this.set_focus(false);
+            }
+            _ => (),
+        }
+    }
+}
+impl Dispatch<wl_callback::WlCallback, FocusSync> for TabletState {
+    fn event(
+        this: &mut Self,
+        callback: &wl_callback::WlCallback,
+        event: wl_callback::Event,
+        _: &FocusSync,
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
-        // We need to acquire the seat for binding a tablet to it, but we do not
-        // care what the seat says D:
+        this.log_message(callback, &event);
+        if this.focused.is_none() {
+            this.set_focus(false);
+        }
     }
 }
 impl Dispatch<wl_tablet::zwp_tablet_manager_v2::ZwpTabletManagerV2, ()> for TabletState {
@@ -535,14 +1193,15 @@ impl Dispatch<wl_tablet::zwp_tablet_manager_v2::ZwpTabletManagerV2, ()> for Tabl
 }
 impl Dispatch<wl_tablet::zwp_tablet_seat_v2::ZwpTabletSeatV2, ()> for TabletState {
     fn event(
-        _: &mut Self,
-        _seat: &wl_tablet::zwp_tablet_seat_v2::ZwpTabletSeatV2,
+        this: &mut Self,
+        seat: &wl_tablet::zwp_tablet_seat_v2::ZwpTabletSeatV2,
         event: wl_tablet::zwp_tablet_seat_v2::Event,
         (): &(),
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
         use wl_tablet::zwp_tablet_seat_v2::Event;
+        this.log_message(seat, &event);
         // We handle these each "lazily" - the first init message triggers device addition logic.
         #[allow(clippy::match_same_arms)]
         match event {
@@ -573,6 +1232,7 @@ impl Dispatch<wl_tablet::zwp_tablet_v2::ZwpTabletV2, ()> for TabletState {
         _: &QueueHandle<Self>,
     ) {
         use wl_tablet::zwp_tablet_v2::Event;
+        this.log_message(tablet, &event);
         #[allow(clippy::match_same_arms)]
         match event {
             // ======= Constructor databurst =========
@@ -595,6 +1255,15 @@ impl Dispatch<wl_tablet::zwp_tablet_v2::ZwpTabletV2, ()> for TabletState {
             Event::Name { name } => {
                 this.partial_tablets.get_or_insert_ctor(tablet.id()).name = Some(name);
             }
+            // May be sent several times, for each of the device's nodes. Any will do.
+            #[cfg(feature = "wayland-udev")]
+            Event::Path { path } => {
+                let ctor = this.partial_tablets.get_or_insert_ctor(tablet.id());
+                if ctor.physical_size.is_none() {
+                    ctor.physical_size = udev::physical_size(&path);
+                }
+            }
+            #[cfg(not(feature = "wayland-udev"))]
             Event::Path { .. } => (),
             Event::Removed => {
                 this.destroy_tablet(tablet.id());
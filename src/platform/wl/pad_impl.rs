@@ -1,7 +1,7 @@
 //! Dispatch impls for pad-related events
 
 use super::{
-    raw_events, wl_tablet, Connection, Dispatch, FrameTimestamp, Group, HasWlId, Proxy,
+    raw_events, wl_tablet, Connection, Dial, Dispatch, FrameTimestamp, Group, HasWlId, Proxy,
     QueueHandle, Ring, Strip, TabletState, TouchSource,
 };
 
@@ -15,6 +15,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_v2::ZwpTabletPadV2, ()> for TabletState
         _: &QueueHandle<Self>,
     ) {
         use wl_tablet::zwp_tablet_pad_v2::Event;
+        this.log_message(pad, &event);
         #[allow(clippy::match_same_arms)]
         match event {
             // ======= Constructor databurst =========
@@ -32,15 +33,16 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_v2::ZwpTabletPadV2, ()> for TabletState
             Event::Done => {
                 let pad_id = pad.id();
                 if let Some(Ok(pad)) = this.partial_pads.done(&pad_id) {
-                    this.pads.push(pad);
-                    this.events.push(raw_events::Event::Pad {
-                        pad: pad_id,
-                        event: raw_events::PadEvent::Added,
-                    });
+                    this.pad_done(pad);
                 }
             }
             Event::Removed => {
+                let announced = this.pads.iter().any(|p| HasWlId::id(p) == &pad.id());
                 this.destroy_pad(pad.id());
+                // Never made it to the client, nothing to take back.
+                if !announced {
+                    return;
+                }
                 this.events.push(raw_events::Event::Pad {
                     pad: pad.id(),
                     event: raw_events::PadEvent::Removed,
@@ -52,34 +54,54 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_v2::ZwpTabletPadV2, ()> for TabletState
                 state,
                 time: _, //use me!
             } => {
-                let pressed = matches!(
-                    state,
-                    wayland_client::WEnum::Value(
-                        wl_tablet::zwp_tablet_pad_v2::ButtonState::Pressed
-                    )
-                );
+                use wl_tablet::zwp_tablet_pad_v2::ButtonState;
+                let pressed = match state {
+                    wayland_client::WEnum::Value(ButtonState::Pressed) => true,
+                    wayland_client::WEnum::Value(ButtonState::Released) => false,
+                    _ => {
+                        this.skip_message("unknown pad button state");
+                        return;
+                    }
+                };
                 // Send event
-                this.events.push(raw_events::Event::Pad {
-                    pad: pad.id(),
-                    event: raw_events::PadEvent::Button {
+                this.push_pad_event(
+                    pad.id(),
+                    raw_events::PadEvent::Button {
                         button_idx: button,
                         pressed,
                     },
-                });
+                );
             }
             Event::Enter { tablet, .. } => {
-                this.events.push(raw_events::Event::Pad {
-                    pad: pad.id(),
-                    event: raw_events::PadEvent::Enter {
+                if !this.knows_tablet(&tablet.id()) {
+                    this.skip_message("pad entered an unknown tablet");
+                    return;
+                }
+                if let Some(entered) = this
+                    .pads
+                    .iter_mut()
+                    .chain(&mut this.partial_pads.constructing)
+                    .find(|p| HasWlId::id(*p) == &pad.id())
+                {
+                    entered.tablet = Some(tablet.id().into());
+                }
+                this.push_pad_event(
+                    pad.id(),
+                    raw_events::PadEvent::Enter {
                         tablet: tablet.id(),
                     },
-                });
+                );
             }
             Event::Leave { .. } => {
-                this.events.push(raw_events::Event::Pad {
-                    pad: pad.id(),
-                    event: raw_events::PadEvent::Exit,
-                });
+                if let Some(left) = this
+                    .pads
+                    .iter_mut()
+                    .chain(&mut this.partial_pads.constructing)
+                    .find(|p| HasWlId::id(*p) == &pad.id())
+                {
+                    left.tablet = None;
+                }
+                this.push_pad_event(pad.id(), raw_events::PadEvent::Exit);
             }
             // ne
             _ => (),
@@ -102,6 +124,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
+        this.log_message(group, &event);
         // Everything (aside from the ctor databurst) needs this. Hoist it out for less code duplication...
         let pad_id = this.group_associations.get(&group.id()).cloned();
         #[allow(clippy::match_same_arms)]
@@ -113,6 +136,9 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
                 // This is all just my best guess! Ahh!
                 // Truncates to four byte chunks. That seems like a server error if my interpretation of the arcane
                 // values are correct.
+                if buttons.len() % 4 != 0 {
+                    this.skip_message("pad group button array not a whole number of `u32`s");
+                }
                 let to_u32 = buttons.chunks_exact(4).map(|bytes| {
                     let Ok(bytes): Result<[u8; 4], _> = bytes.try_into() else {
                         // Guaranteed by chunks exact but not shown at a type-level.
@@ -136,17 +162,33 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
             wl_tablet::zwp_tablet_pad_group_v2::Event::Ring { ring } => {
                 this.ring_associations.insert(ring.id(), group.id());
                 let ctor = this.partial_groups.get_or_insert_ctor(group.id());
+                // Announced in the compositor's (libinput's) index order.
+                let index_in_group = ctor.rings.len();
                 ctor.rings.push(Ring {
                     granularity: None,
                     internal_id: ring.id().into(),
+                    index_in_group,
                 });
             }
             wl_tablet::zwp_tablet_pad_group_v2::Event::Strip { strip } => {
                 this.strip_associations.insert(strip.id(), group.id());
                 let ctor = this.partial_groups.get_or_insert_ctor(group.id());
+                // Announced in the compositor's (libinput's) index order.
+                let index_in_group = ctor.strips.len();
                 ctor.strips.push(Strip {
                     granularity: None,
                     internal_id: strip.id().into(),
+                    index_in_group,
+                });
+            }
+            wl_tablet::zwp_tablet_pad_group_v2::Event::Dial { dial } => {
+                this.dial_associations.insert(dial.id(), group.id());
+                let ctor = this.partial_groups.get_or_insert_ctor(group.id());
+                // Announced in the compositor's (libinput's) index order.
+                let index_in_group = ctor.dials.len();
+                ctor.dials.push(Dial {
+                    internal_id: dial.id().into(),
+                    index_in_group,
                 });
             }
             wl_tablet::zwp_tablet_pad_group_v2::Event::Done => {
@@ -179,16 +221,20 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
             wl_tablet::zwp_tablet_pad_group_v2::Event::ModeSwitch {
                 mode,
                 time: _, //use me!
-                ..
+                serial,
             } => {
-                let Some(pad_id) = pad_id else { return };
-                this.events.push(raw_events::Event::Pad {
-                    pad: pad_id,
-                    event: raw_events::PadEvent::Group {
+                this.mode_serials.insert(group.id(), serial);
+                let Some(pad_id) = pad_id else {
+                    this.skip_message("mode switch of a group without a pad");
+                    return;
+                };
+                this.push_pad_event(
+                    pad_id,
+                    raw_events::PadEvent::Group {
                         group: group.id(),
                         event: raw_events::PadGroupEvent::Mode(mode),
                     },
-                });
+                );
             }
             // ne
             _ => (),
@@ -200,6 +246,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
         [
             wl_tablet::zwp_tablet_pad_group_v2::EVT_RING_OPCODE => (wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()),
             wl_tablet::zwp_tablet_pad_group_v2::EVT_STRIP_OPCODE => (wl_tablet::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2, ()),
+            wl_tablet::zwp_tablet_pad_group_v2::EVT_DIAL_OPCODE => (wl_tablet::zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2, ()),
         ]
     );
 }
@@ -212,6 +259,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
+        this.log_message(ring, &event);
         let Some(group) = this.ring_associations.get(&ring.id()).cloned() else {
             return;
         };
@@ -227,16 +275,16 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
                 }
                 let degrees = degrees as f32;
                 let radians = degrees.to_radians();
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
                             event: crate::events::TouchStripEvent::Pose(radians),
                         },
                     },
-                });
+                );
             }
             wl_tablet::zwp_tablet_pad_ring_v2::Event::Source { source } => {
                 // Convert source, falling back to unknown.
@@ -247,42 +295,44 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
                     _ => TouchSource::Unknown,
                 };
 
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
                             event: crate::events::TouchStripEvent::Source(source),
                         },
                     },
-                });
+                );
             }
             wl_tablet::zwp_tablet_pad_ring_v2::Event::Stop => {
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
                             event: crate::events::TouchStripEvent::Up,
                         },
                     },
-                });
+                );
             }
             wl_tablet::zwp_tablet_pad_ring_v2::Event::Frame { time } => {
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
-                            event: crate::events::TouchStripEvent::Frame(Some(FrameTimestamp(
-                                std::time::Duration::from_millis(u64::from(time)),
-                            ))),
+                            event: crate::events::TouchStripEvent::Frame(Some(
+                                FrameTimestamp::hardware(std::time::Duration::from_millis(
+                                    u64::from(time),
+                                )),
+                            )),
                         },
                     },
-                });
+                );
             }
             // ne
             _ => (),
@@ -298,12 +348,13 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2, ()> for T
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
+        this.log_message(strip, &event);
         // BIIGGGG code duplication with Ring, i don't know how to fix that because this all comes from different modules and thus
         // is actually different types......
         let Some(group) = this.strip_associations.get(&strip.id()).cloned() else {
             return;
         };
-        let Some(pad) = this.strip_associations.get(&group).cloned() else {
+        let Some(pad) = this.group_associations.get(&group).cloned() else {
             return;
         };
         #[allow(clippy::match_same_arms)]
@@ -314,16 +365,16 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2, ()> for T
                 let position = u16::try_from(position).unwrap_or(65535);
                 let position = f32::from(position) / 65535.0;
 
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Strip {
                             strip: strip.id(),
                             event: crate::events::TouchStripEvent::Pose(position),
                         },
                     },
-                });
+                );
             }
             wl_tablet::zwp_tablet_pad_strip_v2::Event::Source { source } => {
                 // Convert source, falling back to unknown.
@@ -334,45 +385,88 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2, ()> for T
                     _ => TouchSource::Unknown,
                 };
 
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Strip {
                             strip: strip.id(),
                             event: crate::events::TouchStripEvent::Source(source),
                         },
                     },
-                });
+                );
             }
             wl_tablet::zwp_tablet_pad_strip_v2::Event::Stop => {
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Strip {
                             strip: strip.id(),
                             event: crate::events::TouchStripEvent::Up,
                         },
                     },
-                });
+                );
             }
             wl_tablet::zwp_tablet_pad_strip_v2::Event::Frame { time } => {
-                this.events.push(raw_events::Event::Pad {
+                this.push_pad_event(
                     pad,
-                    event: raw_events::PadEvent::Group {
+                    raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Strip {
                             strip: strip.id(),
-                            event: crate::events::TouchStripEvent::Frame(Some(FrameTimestamp(
-                                std::time::Duration::from_millis(u64::from(time)),
-                            ))),
+                            event: crate::events::TouchStripEvent::Frame(Some(
+                                FrameTimestamp::hardware(std::time::Duration::from_millis(
+                                    u64::from(time),
+                                )),
+                            )),
                         },
                     },
-                });
+                );
             }
             // ne
             _ => (),
         }
     }
 }
+impl Dispatch<wl_tablet::zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2, ()> for TabletState {
+    fn event(
+        this: &mut Self,
+        dial: &wl_tablet::zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2,
+        event: wl_tablet::zwp_tablet_pad_dial_v2::Event,
+        (): &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        this.log_message(dial, &event);
+        let Some(group) = this.dial_associations.get(&dial.id()).cloned() else {
+            return;
+        };
+        let Some(pad) = this.group_associations.get(&group).cloned() else {
+            return;
+        };
+        let event = match event {
+            // Already in 120ths of a detent, as we report them.
+            wl_tablet::zwp_tablet_pad_dial_v2::Event::Delta { value120 } => {
+                crate::events::DialEvent::Delta(value120)
+            }
+            wl_tablet::zwp_tablet_pad_dial_v2::Event::Frame { time } => {
+                crate::events::DialEvent::Frame(Some(FrameTimestamp::hardware(
+                    std::time::Duration::from_millis(u64::from(time)),
+                )))
+            }
+            // ne
+            _ => return,
+        };
+        this.push_pad_event(
+            pad,
+            raw_events::PadEvent::Group {
+                group,
+                event: raw_events::PadGroupEvent::Dial {
+                    dial: dial.id(),
+                    event,
+                },
+            },
+        );
+    }
+}
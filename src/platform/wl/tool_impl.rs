@@ -14,6 +14,21 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
         _: &QueueHandle<Self>,
     ) {
         use wl_tablet::zwp_tablet_tool_v2::Event;
+        this.log_message(tool, &event);
+        // Over another window, of which we hear nothing until it's out again.
+        if this.elsewhere.contains(&tool.id()) {
+            match event {
+                // Never reported coming in, so there's nothing to report going out either.
+                Event::ProximityOut => {
+                    this.elsewhere.remove(&tool.id());
+                    return;
+                }
+                Event::Removed => {
+                    this.elsewhere.remove(&tool.id());
+                }
+                _ => return,
+            }
+        }
         #[allow(clippy::match_same_arms)]
         match event {
             // ======= Constructor databurst =======
@@ -52,6 +67,9 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
                     _ => (),
                 }
             }
+            Event::Capability {
+                capability: wayland_client::WEnum::Unknown(_),
+            } => this.skip_message("unknown tool capability"),
             Event::HardwareIdWacom {
                 hardware_id_hi,
                 hardware_id_lo,
@@ -87,6 +105,9 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
                     _ => (),
                 }
             }
+            Event::Type {
+                tool_type: wayland_client::WEnum::Unknown(_),
+            } => this.skip_message("unknown tool type"),
             Event::Done => {
                 this.events.push(raw_events::Event::Tool {
                     tool: tool.id(),
@@ -104,14 +125,36 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
                 });
             }
             // ======== Interaction data =========
-            Event::ProximityIn { tablet, .. } => {
+            Event::ProximityIn {
+                serial,
+                tablet,
+                surface,
+            } => {
+                let Some(window) = this.window_of(&surface) else {
+                    this.elsewhere.insert(tool.id());
+                    return;
+                };
+                // Treated like another window, such that nothing is reported until it's out again.
+                if !this.knows_tablet(&tablet.id()) {
+                    this.skip_message("tool in proximity of an unknown tablet");
+                    this.elsewhere.insert(tool.id());
+                    return;
+                }
+                this.proximity_serials.insert(tool.id(), serial);
                 this.frame_in_progress(tool.id()).state_transition =
-                    Some(FrameState::In(tablet.id()));
+                    Some(FrameState::In(tablet.id(), window));
             }
             Event::ProximityOut { .. } => {
+                this.proximity_serials.remove(&tool.id());
                 this.frame_in_progress(tool.id()).state_transition = Some(FrameState::Out);
             }
             Event::Down { .. } => {
+                if this.hide_cursor_while_down {
+                    if let Some(&serial) = this.proximity_serials.get(&tool.id()) {
+                        // A null surface hides it.
+                        tool.set_cursor(serial, None, 0, 0);
+                    }
+                }
                 this.frame_in_progress(tool.id()).state_transition = Some(FrameState::Down);
             }
             Event::Up { .. } => {
@@ -119,46 +162,65 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
             }
             #[allow(clippy::cast_possible_truncation)]
             Event::Motion { x, y } => {
+                let frame = this.frame_in_progress(tool.id());
+                frame.raw.position = [Some(fixed(x, 1.0)), Some(fixed(y, 1.0))];
                 let x = x as f32;
                 let y = y as f32;
-                this.frame_in_progress(tool.id()).position = Some([x, y]);
+                frame.position = Some([x, y]);
             }
             #[allow(clippy::cast_possible_truncation)]
             Event::Tilt { tilt_x, tilt_y } => {
+                let frame = this.frame_in_progress(tool.id());
+                let to_radians = std::f64::consts::PI / 180.0;
+                frame.raw.tilt = [
+                    Some(fixed(tilt_x, to_radians)),
+                    Some(fixed(tilt_y, to_radians)),
+                ];
                 let tilt_x = (tilt_x as f32).to_radians();
                 let tilt_y = (tilt_y as f32).to_radians();
-                this.frame_in_progress(tool.id()).tilt = Some([tilt_x, tilt_y]);
+                frame.tilt = Some([tilt_x, tilt_y]);
             }
             Event::Pressure { pressure } => {
+                let frame = this.frame_in_progress(tool.id());
+                frame.raw.pressure = Some(unsigned(pressure));
                 // Saturating-as (guaranteed by the protocol spec to be 0..=65535)
                 let pressure = u16::try_from(pressure).unwrap_or(65535);
                 let pressure = f32::from(pressure) / 65535.0;
-                this.frame_in_progress(tool.id()).pressure = Some(pressure);
+                frame.pressure = Some(pressure);
             }
             Event::Distance { distance } => {
+                let frame = this.frame_in_progress(tool.id());
+                frame.raw.distance = Some(unsigned(distance));
                 // Saturating-as (guaranteed by the protocol spec to be 0..=65535)
                 let distance = u16::try_from(distance).unwrap_or(65535);
                 let distance = f32::from(distance) / 65535.0;
-                this.frame_in_progress(tool.id()).distance = Some(distance);
+                frame.distance = Some(distance);
             }
             #[allow(clippy::cast_possible_truncation)]
             Event::Rotation { degrees } => {
+                let frame = this.frame_in_progress(tool.id());
+                frame.raw.roll = Some(fixed(degrees, std::f64::consts::PI / 180.0));
                 let radians = (degrees as f32).to_radians();
-                this.frame_in_progress(tool.id()).roll = Some(radians);
+                frame.roll = Some(radians);
             }
             Event::Slider { position } => {
+                let frame = this.frame_in_progress(tool.id());
+                frame.raw.slider = Some(crate::axis::RawValue::scaled(position, 1.0 / 65535.0));
                 // Saturating-as (guaranteed by the protocol spec to be 0..=65535)
                 let position = u16::try_from(position).unwrap_or(65535);
                 let position = f32::from(position) / 65535.0;
-                this.frame_in_progress(tool.id()).slider = Some(position);
+                frame.slider = Some(position);
             }
             Event::Button { button, state, .. } => {
-                let pressed = matches!(
-                    state,
-                    wayland_client::WEnum::Value(
-                        wl_tablet::zwp_tablet_tool_v2::ButtonState::Pressed
-                    )
-                );
+                use wl_tablet::zwp_tablet_tool_v2::ButtonState;
+                let pressed = match state {
+                    wayland_client::WEnum::Value(ButtonState::Pressed) => true,
+                    wayland_client::WEnum::Value(ButtonState::Released) => false,
+                    _ => {
+                        this.skip_message("unknown tool button state");
+                        return;
+                    }
+                };
                 this.frame_in_progress(tool.id())
                     .buttons
                     .push((button, pressed));
@@ -172,3 +234,14 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
         }
     }
 }
+
+/// Recover the `wl_fixed` a value was sent as, which `wayland-client` hands out as a float. Exact, as every fixed
+/// is representable.
+#[allow(clippy::cast_possible_truncation)]
+fn fixed(value: f64, scale: f64) -> crate::axis::RawValue {
+    crate::axis::RawValue::scaled((value * 256.0) as i32, scale / 256.0)
+}
+/// A `0..=65535` normalized value.
+fn unsigned(value: u32) -> crate::axis::RawValue {
+    crate::axis::RawValue::scaled(i32::try_from(value).unwrap_or(i32::MAX), 1.0 / 65535.0)
+}
@@ -0,0 +1,30 @@
+//! Looking up what the compositor doesn't tell us from udev's database, by device path.
+//!
+//! Reads the database files directly rather than pulling in libudev - the format has been stable for a decade,
+//! and anything going wrong just means we don't know.
+
+/// Split a Linux `dev_t` into its major and minor numbers, as glibc's `major()`/`minor()` would.
+fn major_minor(dev: u64) -> (u64, u64) {
+    let major = ((dev >> 8) & 0xfff) | ((dev >> 32) & !0xfff);
+    let minor = (dev & 0xff) | ((dev >> 12) & !0xff);
+    (major, minor)
+}
+
+/// Width and height of the device's active area in millimeters, from `ID_INPUT_WIDTH_MM` and `ID_INPUT_HEIGHT_MM`.
+pub(super) fn physical_size(path: &str) -> Option<[f32; 2]> {
+    use std::os::unix::fs::MetadataExt;
+    let (major, minor) = major_minor(std::fs::metadata(path).ok()?.rdev());
+    let data = std::fs::read_to_string(format!("/run/udev/data/c{major}:{minor}")).ok()?;
+
+    let property = |name: &str| {
+        data.lines()
+            .filter_map(|line| line.strip_prefix("E:"))
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+            .and_then(|value| value.trim().parse::<f32>().ok())
+            .filter(|value| value.is_finite() && *value > 0.0)
+    };
+    Some([
+        property("ID_INPUT_WIDTH_MM")?,
+        property("ID_INPUT_HEIGHT_MM")?,
+    ])
+}
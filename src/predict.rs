@@ -0,0 +1,219 @@
+//! Extrapolation of where tools are headed, to hide some of the latency between the hardware and the screen.
+//!
+//! Feed each pump's events to a [`Predictor`], then ask it where a tool will be by the time the frame is actually
+//! shown. Predictions are for drawing tentative ink ahead of the tool only - they should be thrown away once the real
+//! poses arrive, and never be committed to the canvas.
+//!
+//! ```no_run
+//! # fn frame(manager: &mut octotablet::Manager) {
+//! use octotablet::predict::Predictor;
+//! let mut predictor = Predictor::new(std::time::Duration::from_millis(16));
+//! // Each frame...
+//! predictor.observe(manager.pump().unwrap());
+//! for tool in manager.tools() {
+//!     if let Some(pose) = predictor.predict(&tool.id()) {
+//!         // Draw tentative ink up to `pose.position`...
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! # Platform support
+//! Every backend uses the same filter, as neither Wayland nor the `RealTimeStylus` API used for Windows Ink exposes a
+//! prediction of its own.
+
+use crate::{
+    axis::Pose,
+    events::{Event, Events, FrameTimestamp, ToolEvent},
+    tool,
+};
+
+/// Samples further apart than this are from separate motions, and aren't extrapolated across.
+const MAX_GAP: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// How motion is extrapolated, see [`Predictor::with_model`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Model {
+    /// Carry on in a straight line at the latest speed. Steady, but lags behind on curves.
+    #[default]
+    Velocity,
+    /// Account for changes in speed and direction too. Follows curves better, at the cost of overshooting when the
+    /// tool stops abruptly.
+    Acceleration,
+}
+
+/// When a sample was taken. Frames without a timestamp fall back on the time of the pump they came in.
+#[derive(Clone, Copy, Debug)]
+enum When {
+    Frame(FrameTimestamp),
+    Pump(std::time::Instant),
+}
+impl When {
+    /// Seconds from `earlier` to `self`, `None` if the two can't be compared or are too far apart.
+    fn since(self, earlier: Self) -> Option<f32> {
+        let elapsed = match (self, earlier) {
+            // Synthetic and hardware timestamps have different epochs.
+            (Self::Frame(now), Self::Frame(then)) if now.is_synthetic() == then.is_synthetic() => {
+                (now >= then).then(|| now - then)
+            }
+            (Self::Pump(now), Self::Pump(then)) => now.checked_duration_since(then),
+            _ => None,
+        }?;
+        (elapsed <= MAX_GAP).then_some(elapsed.as_secs_f32())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    when: When,
+    position: [f32; 2],
+}
+
+#[derive(Clone, Debug)]
+struct Track {
+    tool: tool::ID,
+    /// The pose of the frame in progress.
+    pending: Option<Pose>,
+    /// The latest whole pose.
+    pose: Option<Pose>,
+    /// The latest few positions, oldest first.
+    samples: smallvec::SmallVec<[Sample; 3]>,
+}
+
+/// Extrapolates the poses of tools a fixed time into the future. See the [module level docs](self).
+#[derive(Clone, Debug)]
+pub struct Predictor {
+    lookahead: std::time::Duration,
+    model: Model,
+    // A handful of tools at most, not worth a map.
+    tracks: Vec<Track>,
+}
+impl Predictor {
+    /// Predict `lookahead` past the latest pose of each tool - usually around the time it takes to get a frame onto
+    /// the screen, one or two refresh intervals. Overshoots grow quickly with longer lookaheads.
+    #[must_use]
+    pub fn new(lookahead: std::time::Duration) -> Self {
+        Self {
+            lookahead,
+            model: Model::default(),
+            tracks: Vec::new(),
+        }
+    }
+    /// Set how motion is extrapolated. Defaults to [`Model::Velocity`].
+    #[must_use]
+    pub fn with_model(self, model: Model) -> Self {
+        Self { model, ..self }
+    }
+    /// Set how far ahead to predict, as given to [`Predictor::new`].
+    pub fn set_lookahead(&mut self, lookahead: std::time::Duration) {
+        self.lookahead = lookahead;
+    }
+    /// Take in a pump's worth of events. Every pump's events should be observed, in order, for the motion to be
+    /// followed correctly.
+    pub fn observe(&mut self, events: Events<'_>) {
+        let pumped_at = events.pumped_at();
+        for event in events {
+            let Event::Tool { tool, event } = event else {
+                continue;
+            };
+            let id = tool.id();
+            let idx = self
+                .tracks
+                .iter()
+                .position(|track| track.tool == id)
+                .unwrap_or_else(|| {
+                    self.tracks.push(Track {
+                        tool: id,
+                        pending: None,
+                        pose: None,
+                        samples: smallvec::SmallVec::new(),
+                    });
+                    self.tracks.len() - 1
+                });
+            let track = &mut self.tracks[idx];
+            match event {
+                ToolEvent::Pose(pose) => track.pending = Some(pose),
+                ToolEvent::Frame(time) => {
+                    let Some(pose) = track.pending.take() else {
+                        continue;
+                    };
+                    let sample = Sample {
+                        when: time.map_or(When::Pump(pumped_at), When::Frame),
+                        position: pose.position,
+                    };
+                    track.pose = Some(pose);
+                    match track
+                        .samples
+                        .last()
+                        .map(|last| sample.when.since(last.when))
+                    {
+                        // Same instant, as with several frames in one pump without timestamps. Replace rather than
+                        // divide by zero.
+                        Some(Some(dt)) if dt <= 0.0 => {
+                            track.samples.pop();
+                            track.samples.push(sample);
+                        }
+                        Some(Some(_)) => {
+                            if track.samples.len() == track.samples.inline_size() {
+                                track.samples.remove(0);
+                            }
+                            track.samples.push(sample);
+                        }
+                        // First sample, or the motion is discontinuous.
+                        Some(None) | None => {
+                            track.samples.clear();
+                            track.samples.push(sample);
+                        }
+                    }
+                }
+                ToolEvent::Out | ToolEvent::TimedOut | ToolEvent::Removed => {
+                    track.pending = None;
+                    track.pose = None;
+                    track.samples.clear();
+                }
+                _ => (),
+            }
+        }
+        // Forget about tools that went away.
+        self.tracks
+            .retain(|track| track.pose.is_some() || track.pending.is_some());
+    }
+    /// Where the given tool is expected to be [lookahead](Predictor::new) from its latest pose. Only the position is
+    /// extrapolated - the other axes are those of the latest pose.
+    ///
+    /// `None` if the tool isn't in proximity. If there's not yet enough motion to go on, this is the latest pose
+    /// as-is.
+    #[must_use]
+    pub fn predict(&self, tool: &tool::ID) -> Option<Pose> {
+        let track = self.tracks.iter().find(|track| track.tool == *tool)?;
+        let mut pose = track.pose?;
+        let t = self.lookahead.as_secs_f32();
+        let velocity = |from: &Sample, to: &Sample| {
+            let dt = to.when.since(from.when)?;
+            Some([
+                (to.position[0] - from.position[0]) / dt,
+                (to.position[1] - from.position[1]) / dt,
+                dt,
+            ])
+        };
+        let [.., before, last] = track.samples.as_slice() else {
+            return Some(pose);
+        };
+        let Some([vx, vy, dt]) = velocity(before, last) else {
+            return Some(pose);
+        };
+        let mut offset = [vx * t, vy * t];
+        if let (Model::Acceleration, [.., first, _, _]) = (self.model, track.samples.as_slice()) {
+            if let Some([ux, uy, earlier_dt]) = velocity(first, before) {
+                // Velocities are measured at the middle of their intervals.
+                let span = 0.5 * (dt + earlier_dt);
+                let half_t2 = 0.5 * t * t;
+                offset[0] += (vx - ux) / span * half_t2;
+                offset[1] += (vy - uy) / span * half_t2;
+            }
+        }
+        pose.position = [pose.position[0] + offset[0], pose.position[1] + offset[1]];
+        Some(pose)
+    }
+}
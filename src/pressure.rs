@@ -0,0 +1,202 @@
+//! Opt-in runtime corrections to reported pressure, applied by the [`Manager`](crate::Manager) during each pump.
+
+use crate::{
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+    util::NicheF32,
+};
+
+/// Lowest ceiling we'll ever adapt to. Any lower, and a tool that has only seen a few featherlight
+/// strokes would slam to full pressure at the slightest touch.
+const MIN_CEILING: f32 = 0.25;
+/// Fraction of the distance towards the observed maximum the ceiling travels after each stroke.
+/// Gradual, so a single weird stroke doesn't throw off the feel mid-drawing.
+const ADAPT_RATE: f32 = 0.2;
+
+/// Per-tool learned state.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ToolRange {
+    /// Pressure which is currently mapped to `1.0`.
+    ceiling: f32,
+    /// Maximum pressure the tool has ever been seen to report.
+    observed_max: f32,
+}
+impl ToolRange {
+    /// A fresh tool - trust what it claims until the first stroke tells us otherwise.
+    const UNCALIBRATED: Self = Self {
+        ceiling: 1.0,
+        observed_max: 0.0,
+    };
+    fn restored(ceiling: f32) -> Self {
+        let ceiling = ceiling.clamp(MIN_CEILING, 1.0);
+        Self {
+            ceiling,
+            observed_max: ceiling,
+        }
+    }
+    fn observe(&mut self, pressure: f32) {
+        self.observed_max = self.observed_max.max(pressure);
+        // Went over the top - there's no sense in waiting to adapt, the ceiling is provably wrong.
+        self.ceiling = self.ceiling.max(pressure);
+    }
+    fn stroke_end(&mut self) {
+        let target = self.observed_max.clamp(MIN_CEILING, 1.0);
+        self.ceiling += (target - self.ceiling) * ADAPT_RATE;
+    }
+    fn apply(self, pressure: f32) -> f32 {
+        (pressure / self.ceiling).clamp(0.0, 1.0)
+    }
+}
+
+/// Tracks the pressure range tools actually report in practice, stretching it to fill `[0, 1]`.
+///
+/// Some hardware claims a range it never reaches in practice, leaving the top of the range unusable.
+#[derive(Default)]
+pub(crate) struct AutoRange {
+    tools: std::collections::HashMap<InternalID, ToolRange>,
+}
+impl AutoRange {
+    /// Observe and rewrite the pressures of a pump's worth of events.
+    pub(crate) fn process(&mut self, events: &mut [RawEvent<InternalID>]) {
+        for event in events {
+            let RawEvent::Tool { tool, event } = event else {
+                continue;
+            };
+            match event {
+                RawTool::Pose(pose) => {
+                    if let Some(pressure) = pose.pressure.get() {
+                        let range = self
+                            .tools
+                            .entry(tool.clone())
+                            .or_insert(ToolRange::UNCALIBRATED);
+                        range.observe(pressure);
+                        // Can't produce NaN - `ceiling` is never zero.
+                        pose.pressure =
+                            NicheF32::new_some(range.apply(pressure)).unwrap_or(NicheF32::NONE);
+                    }
+                }
+                RawTool::Up => {
+                    if let Some(range) = self.tools.get_mut(tool) {
+                        range.stroke_end();
+                    }
+                }
+                RawTool::Removed => {
+                    self.tools.remove(tool);
+                }
+                _ => (),
+            }
+        }
+    }
+    /// The pressure currently mapped to full, for persisting.
+    pub(crate) fn ceiling(&self, tool: &InternalID) -> f32 {
+        self.tools
+            .get(tool)
+            .map_or(ToolRange::UNCALIBRATED.ceiling, |range| range.ceiling)
+    }
+    /// Restore a previously persisted ceiling.
+    pub(crate) fn set_ceiling(&mut self, tool: InternalID, ceiling: f32) {
+        if !ceiling.is_nan() {
+            self.tools.insert(tool, ToolRange::restored(ceiling));
+        }
+    }
+}
+
+/// Highest threshold we'll ever learn. Real ones are a few percent at most, anything above is a hard tap which would
+/// leave lighter strokes stuck at zero until a lighter one came along.
+const MAX_LEARNED_THRESHOLD: f32 = 0.2;
+
+/// Re-normalizes pressure such that `0.0` is the moment the tool goes down, by the platform's
+/// [`Tool::tip_threshold`](crate::tool::Tool::tip_threshold) where known, otherwise by the lowest pressure tools
+/// have been seen to go down at.
+#[derive(Default)]
+pub(crate) struct TipNormalize {
+    /// For tools the platform says nothing about.
+    learned: std::collections::HashMap<InternalID, f32>,
+    /// Each tool's frame in progress, which may span several batches.
+    frames: std::collections::HashMap<InternalID, DownFrame>,
+}
+/// What's been seen of a tool's frame in progress.
+#[derive(Default)]
+struct DownFrame {
+    pressure: Option<f32>,
+    down: bool,
+}
+impl TipNormalize {
+    /// Observe and rewrite the pressures of a pump's worth of events.
+    pub(crate) fn process(
+        &mut self,
+        events: &mut [RawEvent<InternalID>],
+        tools: &[crate::tool::Tool],
+    ) {
+        self.learn(events);
+        for event in events {
+            let RawEvent::Tool { tool, event } = event else {
+                continue;
+            };
+            match event {
+                RawTool::Pose(pose) => {
+                    let (Some(pressure), Some(threshold)) =
+                        (pose.pressure.get(), self.threshold(tool, tools))
+                    else {
+                        continue;
+                    };
+                    // Can't produce NaN - `threshold` is never one.
+                    pose.pressure = NicheF32::new_some(
+                        ((pressure - threshold) / (1.0 - threshold)).clamp(0.0, 1.0),
+                    )
+                    .unwrap_or(NicheF32::NONE);
+                }
+                RawTool::Removed => {
+                    self.learned.remove(tool);
+                    self.frames.remove(tool);
+                }
+                _ => (),
+            }
+        }
+    }
+    /// Take note of the pressure of every frame a tool goes down in.
+    fn learn(&mut self, events: &[RawEvent<InternalID>]) {
+        for event in events {
+            let RawEvent::Tool { tool, event } = event else {
+                continue;
+            };
+            match event {
+                RawTool::Pose(pose) => {
+                    let frame = self.frames.entry(tool.clone()).or_default();
+                    frame.pressure = pose.pressure.get().or(frame.pressure);
+                }
+                RawTool::Down => self.frames.entry(tool.clone()).or_default().down = true,
+                RawTool::Frame(_) => {
+                    let Some(DownFrame {
+                        pressure: Some(pressure),
+                        down: true,
+                    }) = self.frames.remove(tool)
+                    else {
+                        continue;
+                    };
+                    // Some hardware goes down before reporting any pressure, which says nothing of the threshold and
+                    // would pin it at zero for good. NaN is caught here too.
+                    if pressure > 0.0 {
+                        let pressure = pressure.min(MAX_LEARNED_THRESHOLD);
+                        self.learned
+                            .entry(tool.clone())
+                            .and_modify(|threshold| *threshold = threshold.min(pressure))
+                            .or_insert(pressure);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+    /// The pressure currently mapped to `0.0`, if known.
+    pub(crate) fn threshold(&self, tool: &InternalID, tools: &[crate::tool::Tool]) -> Option<f32> {
+        tools
+            .iter()
+            .find(|info| info.internal_id == *tool)
+            .and_then(|info| info.tip_threshold)
+            // Zero would do nothing, and NaN fails the comparison.
+            .filter(|threshold| *threshold > 0.0)
+            .map(|threshold| threshold.min(1.0 - f32::EPSILON))
+            .or_else(|| self.learned.get(tool).copied())
+    }
+}
@@ -0,0 +1,138 @@
+//! Opt-in easing of coarsely quantized axes, applied by the [`Manager`](crate::Manager) during each pump.
+//! See [`Builder::quantize`](crate::builder::Builder::quantize).
+
+use crate::{
+    axis::{FullInfo, Quantization},
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+    tool::Tool,
+};
+
+/// Angular steps at least this large, in radians, are coarse enough to see. Around a fifth of a degree, such that
+/// hardware reporting whole degrees definitely qualifies.
+const COARSE_ANGLE: f32 = 0.0035;
+/// Slider steps at least this large are coarse enough to see, over its range of two.
+const COARSE_SLIDER: f32 = 1.0 / 256.0;
+
+/// Size of a single quantization step of each axis, for those that are coarse.
+#[derive(Clone, Copy, Default)]
+struct Steps {
+    tilt: Option<f32>,
+    roll: Option<f32>,
+    slider: Option<f32>,
+}
+impl Steps {
+    #[allow(clippy::cast_precision_loss)]
+    fn of(info: &FullInfo) -> Self {
+        let step = |span: f32, granularity: Option<crate::axis::Granularity>| {
+            granularity.map(|granularity| span / granularity.0.get() as f32)
+        };
+        Self {
+            tilt: info
+                .tilt
+                .and_then(|tilt| step(tilt.limits?.max - tilt.limits?.min, tilt.granularity))
+                .filter(|&step| step >= COARSE_ANGLE),
+            roll: info
+                .roll
+                .and_then(|roll| step(std::f32::consts::TAU, roll.granularity))
+                .filter(|&step| step >= COARSE_ANGLE),
+            slider: info
+                .slider
+                .and_then(|slider| step(2.0, slider.granularity))
+                .filter(|&step| step >= COARSE_SLIDER),
+        }
+    }
+}
+
+/// Last eased values of a tool.
+#[derive(Clone, Copy, Default)]
+struct Eased {
+    tilt: Option<[f32; 2]>,
+    roll: Option<f32>,
+    slider: Option<f32>,
+}
+
+/// Ease from the last value towards the raw one, never straying more than one `step` from the raw value.
+fn ease(last: Option<f32>, raw: f32, strength: f32, step: f32) -> f32 {
+    match last {
+        Some(last) => raw - ((raw - last) * strength).clamp(-step, step),
+        None => raw,
+    }
+}
+
+pub(crate) struct Dequantizer {
+    strength: Quantization,
+    tools: std::collections::HashMap<InternalID, Eased>,
+}
+impl Dequantizer {
+    pub(crate) fn new(strength: Quantization) -> Self {
+        let clamp = |strength: f32| {
+            if strength > 0.0 {
+                strength.min(1.0)
+            } else {
+                // Also catches NaN.
+                0.0
+            }
+        };
+        Self {
+            strength: Quantization {
+                tilt: clamp(strength.tilt),
+                roll: clamp(strength.roll),
+                slider: clamp(strength.slider),
+            },
+            tools: std::collections::HashMap::new(),
+        }
+    }
+    /// Ease a pump's worth of events in place.
+    pub(crate) fn process(&mut self, events: &mut [RawEvent<InternalID>], tools: &[Tool]) {
+        for event in events {
+            let RawEvent::Tool { tool, event } = event else {
+                continue;
+            };
+            match event {
+                RawTool::Pose(pose) => {
+                    let Some(info) = tools.iter().find(|t| &t.internal_id == tool) else {
+                        continue;
+                    };
+                    let steps = Steps::of(&info.axes);
+                    let eased = self.tools.entry(tool.clone()).or_default();
+                    let strength = self.strength;
+
+                    if let (Some(step), Some([x, y])) = (steps.tilt, pose.tilt) {
+                        let last = eased.tilt;
+                        let tilt = [
+                            ease(last.map(|[x, _]| x), x, strength.tilt, step),
+                            ease(last.map(|[_, y]| y), y, strength.tilt, step),
+                        ];
+                        eased.tilt = Some(tilt);
+                        pose.tilt = Some(tilt);
+                    }
+                    if let (Some(step), Some(roll)) = (steps.roll, pose.roll.get()) {
+                        // Ease the short way around, rather than spinning the long way across the zero point.
+                        let last = eased.roll.map(|last| {
+                            roll - (roll - last + std::f32::consts::PI)
+                                .rem_euclid(std::f32::consts::TAU)
+                                + std::f32::consts::PI
+                        });
+                        let roll =
+                            ease(last, roll, strength.roll, step).rem_euclid(std::f32::consts::TAU);
+                        eased.roll = Some(roll);
+                        pose.roll = crate::util::NicheF32::new_some(roll)
+                            .unwrap_or(crate::util::NicheF32::NONE);
+                    }
+                    if let (Some(step), Some(slider)) = (steps.slider, pose.slider.get()) {
+                        let slider = ease(eased.slider, slider, strength.slider, step);
+                        eased.slider = Some(slider);
+                        pose.slider = crate::util::NicheF32::new_some(slider)
+                            .unwrap_or(crate::util::NicheF32::NONE);
+                    }
+                }
+                // Starting afresh, don't ease from wherever it was last time.
+                RawTool::In { .. } | RawTool::Removed => {
+                    self.tools.remove(tool);
+                }
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Restricting tool events to part of the window, applied by the [`Manager`](crate::Manager) during each pump.
+//! See [`Manager::set_hit_region`](crate::Manager::set_hit_region).
+
+use crate::{
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+};
+
+/// An axis-aligned rectangle, in the same space as [`Pose::position`](crate::axis::Pose::position).
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Rect {
+    /// The top left corner, inclusive.
+    pub min: [f32; 2],
+    /// The bottom right corner, exclusive.
+    pub max: [f32; 2],
+}
+impl Rect {
+    /// Whether the point is within the rectangle.
+    #[must_use]
+    pub fn contains(&self, [x, y]: [f32; 2]) -> bool {
+        (self.min[0]..self.max[0]).contains(&x) && (self.min[1]..self.max[1]).contains(&y)
+    }
+}
+
+/// What we know of each in-proximity tool, as reported by the platform.
+struct Proximity {
+    tablet: InternalID,
+    window: Option<crate::WindowId>,
+    down: bool,
+    /// Whether the client has been told the tool is out, while the platform still has it in.
+    hidden: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct HitRegion {
+    region: Option<Rect>,
+    /// Tools currently in proximity.
+    tools: std::collections::HashMap<InternalID, Proximity>,
+    /// Each tool's frame in progress, held back until it's known which side of the region it ends up on.
+    frames: std::collections::HashMap<InternalID, Vec<RawTool<InternalID>>>,
+}
+impl HitRegion {
+    pub(crate) fn set(&mut self, region: Option<Rect>) {
+        self.region = region;
+    }
+    /// Observe a pump's worth of events, forwarding them into `into` with frames outside the region replaced by
+    /// the tool going out, and back in once it returns.
+    pub(crate) fn process(
+        &mut self,
+        events: impl IntoIterator<Item = RawEvent<InternalID>>,
+        into: &mut Vec<RawEvent<InternalID>>,
+    ) {
+        for event in events {
+            let RawEvent::Tool { tool, event } = event else {
+                into.push(event);
+                continue;
+            };
+            // Without a region, only keep track until whatever's held back or hidden is through.
+            if self.region.is_none()
+                && !self.frames.contains_key(&tool)
+                && !self
+                    .tools
+                    .get(&tool)
+                    .is_some_and(|proximity| proximity.hidden)
+            {
+                self.observe(&tool, &event);
+                into.push(RawEvent::Tool { tool, event });
+                continue;
+            }
+            match event {
+                RawTool::Frame(time) => {
+                    let frame = self.frames.remove(&tool).unwrap_or_default();
+                    self.finish_frame(&tool, frame, time, into);
+                }
+                // Not part of a frame.
+                RawTool::Added | RawTool::Removed => {
+                    if matches!(event, RawTool::Removed) {
+                        self.tools.remove(&tool);
+                        self.frames.remove(&tool);
+                    }
+                    into.push(RawEvent::Tool { tool, event });
+                }
+                event => self.frames.entry(tool).or_default().push(event),
+            }
+        }
+    }
+    /// Follow along with the platform's idea of the tool's proximity.
+    fn observe(&mut self, tool: &InternalID, event: &RawTool<InternalID>) {
+        match event {
+            RawTool::In { tablet, window } => {
+                self.tools.insert(
+                    tool.clone(),
+                    Proximity {
+                        tablet: tablet.clone(),
+                        window: *window,
+                        down: false,
+                        hidden: false,
+                    },
+                );
+            }
+            RawTool::Down | RawTool::Up => {
+                if let Some(proximity) = self.tools.get_mut(tool) {
+                    proximity.down = matches!(event, RawTool::Down);
+                }
+            }
+            RawTool::Out | RawTool::Removed => {
+                self.tools.remove(tool);
+            }
+            _ => (),
+        }
+    }
+    /// Decide the fate of a whole frame.
+    fn finish_frame(
+        &mut self,
+        tool: &InternalID,
+        frame: Vec<RawTool<InternalID>>,
+        time: Option<crate::events::FrameTimestamp>,
+        into: &mut Vec<RawEvent<InternalID>>,
+    ) {
+        let push = |into: &mut Vec<RawEvent<InternalID>>, event| {
+            into.push(RawEvent::Tool {
+                tool: tool.clone(),
+                event,
+            });
+        };
+        // Judged by how the frame found the tool, not by the `Down` or `Up` within it.
+        let (was_hidden, was_down) = self.tools.get(tool).map_or((false, false), |proximity| {
+            (proximity.hidden, proximity.down)
+        });
+        let mut out = false;
+        let mut position = None;
+        for event in &frame {
+            match event {
+                RawTool::Pose(pose) => position = Some(pose.position),
+                RawTool::Out | RawTool::TimedOut => out = true,
+                _ => self.observe(tool, event),
+            }
+        }
+        let Some(proximity) = self.tools.get_mut(tool) else {
+            // Never saw it come in, nothing to judge by.
+            for event in frame {
+                push(into, event);
+            }
+            push(into, RawTool::Frame(time));
+            return;
+        };
+        let inside = match (self.region, position) {
+            (Some(region), Some(position)) => region.contains(position),
+            // Without a new position, it's wherever it was.
+            (Some(_), None) => !was_hidden,
+            (None, _) => true,
+        };
+        if out {
+            self.tools.remove(tool);
+            if was_hidden {
+                // Already told the client it's out.
+                return;
+            }
+        } else if was_hidden {
+            // Strokes begun outside stay outside, come back in only once lifted.
+            if !inside || was_down {
+                return;
+            }
+            proximity.hidden = false;
+            push(
+                into,
+                RawTool::In {
+                    tablet: proximity.tablet.clone(),
+                    window: proximity.window,
+                },
+            );
+        } else if !inside && !was_down {
+            // Strokes begun inside carry on regardless, like a pointer grab.
+            proximity.hidden = true;
+            // The platform's `In` never made it to the client, so neither should an `Out`.
+            if !frame
+                .iter()
+                .any(|event| matches!(event, RawTool::In { .. }))
+            {
+                push(into, RawTool::Out);
+                push(into, RawTool::Frame(time));
+            }
+            return;
+        }
+        for event in frame {
+            push(into, event);
+        }
+        push(into, RawTool::Frame(time));
+    }
+}
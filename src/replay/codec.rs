@@ -0,0 +1,911 @@
+//! The binary format of [`Recording`](super::Recording)s.
+//!
+//! Everything is little-endian, with no padding or alignment. Lists are prefixed by a `u32` length, options by a
+//! `u8` tag, and enums by a `u8` variant index. Any change to the layout must bump [`VERSION`].
+
+use super::ReadError;
+use crate::{
+    axis::{
+        CircularInfo, ExtraAxes, ExtraAxisKey, FullInfo, Granularity, Info, LengthInfo, Limits,
+        NormalizedInfo, Pose, PositionGranularity, PositionInfo, RawPose, RawValue, SliderInfo,
+    },
+    custom::{Event, PadEvent, PadGroupEvent, ToolEvent},
+    events::{DialEvent, FrameTimestamp, SystemEvent, TabletEvent, TouchStripEvent},
+    pad::{Dial, Group, Pad, Ring, Strip, TouchSource},
+    tablet::{Hz, Rotation, Tablet, UsbId},
+    tool::{ButtonID, ButtonInfo, ButtonRole, HardwareID, Tool, Type},
+    touch::{ContactID, Finger, TouchEvent},
+    util::NicheF32,
+};
+use std::io::{Read, Write};
+
+/// Leads every recording.
+pub(super) const MAGIC: &[u8; 8] = b"OCTOREC\0";
+/// Of the layout described here. Recordings of other versions can't be read.
+pub(super) const VERSION: u8 = 1;
+
+type ReadResult<T> = Result<T, ReadError>;
+
+pub(super) trait Codec: Sized {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()>;
+    fn read(r: &mut dyn Read) -> ReadResult<Self>;
+}
+
+macro_rules! codec_le_bytes {
+    ($($ty:ty),*) => {$(
+        impl Codec for $ty {
+            fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+                w.write_all(&self.to_le_bytes())
+            }
+            fn read(r: &mut dyn Read) -> ReadResult<Self> {
+                let mut bytes = [0; std::mem::size_of::<$ty>()];
+                r.read_exact(&mut bytes)?;
+                Ok(Self::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+codec_le_bytes!(u8, u16, u32, u64, u128, i32, f32, f64);
+
+/// Enums without fields, written as their index in the given list.
+macro_rules! codec_unit_enum {
+    ($ty:ty { $($variant:ident),* $(,)? }) => {
+        impl Codec for $ty {
+            fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+                let variants = [$(<$ty>::$variant),*];
+                // Always found, the list is exhaustive.
+                #[allow(clippy::cast_possible_truncation)]
+                let index = variants.iter().position(|variant| variant == self).unwrap_or(0) as u8;
+                index.write(w)
+            }
+            fn read(r: &mut dyn Read) -> ReadResult<Self> {
+                let variants = [$(<$ty>::$variant),*];
+                variants
+                    .get(usize::from(u8::read(r)?))
+                    .copied()
+                    .ok_or(ReadError::Corrupt)
+            }
+        }
+    };
+}
+codec_unit_enum!(Type {
+    Pen,
+    Pencil,
+    Brush,
+    Eraser,
+    Airbrush,
+    Finger,
+    Mouse,
+    Lens,
+    Emulated
+});
+codec_unit_enum!(Rotation {
+    None,
+    Quarter,
+    Half,
+    ThreeQuarters
+});
+codec_unit_enum!(TouchSource { Unknown, Finger });
+codec_unit_enum!(SystemEvent { ScaleFactorChanged });
+
+impl Codec for bool {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        u8::from(*self).write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        match u8::read(r)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ReadError::Corrupt),
+        }
+    }
+}
+/// Write a list length or index.
+fn write_len(len: usize, w: &mut dyn Write) -> std::io::Result<()> {
+    u32::try_from(len)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "list too long"))?
+        .write(w)
+}
+fn read_len(r: &mut dyn Read) -> ReadResult<usize> {
+    usize::try_from(u32::read(r)?).map_err(|_| ReadError::Corrupt)
+}
+impl Codec for String {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_len(self.len(), w)?;
+        w.write_all(self.as_bytes())
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        let len = read_len(r)?;
+        let mut bytes = Vec::new();
+        // Through `take`, so a corrupt length can't have us allocate the world.
+        Read::take(&mut *r, len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(ReadError::Corrupt);
+        }
+        String::from_utf8(bytes).map_err(|_| ReadError::Corrupt)
+    }
+}
+impl<T: Codec> Codec for Option<T> {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            None => 0u8.write(w),
+            Some(value) => {
+                1u8.write(w)?;
+                value.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(if bool::read(r)? {
+            Some(T::read(r)?)
+        } else {
+            None
+        })
+    }
+}
+impl<T: Codec> Codec for Vec<T> {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_len(self.len(), w)?;
+        self.iter().try_for_each(|item| item.write(w))
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        // Not preallocated, for the same reason as strings.
+        (0..read_len(r)?).map(|_| T::read(r)).collect()
+    }
+}
+impl<T: Codec> Codec for [T; 2] {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self[0].write(w)?;
+        self[1].write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok([T::read(r)?, T::read(r)?])
+    }
+}
+impl<A: Codec, B: Codec> Codec for (A, B) {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.0.write(w)?;
+        self.1.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok((A::read(r)?, B::read(r)?))
+    }
+}
+impl Codec for std::time::Duration {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.as_secs().write(w)?;
+        self.subsec_nanos().write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        let (secs, nanos) = (u64::read(r)?, u32::read(r)?);
+        if nanos >= 1_000_000_000 {
+            return Err(ReadError::Corrupt);
+        }
+        Ok(Self::new(secs, nanos))
+    }
+}
+impl Codec for std::num::NonZeroU32 {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.get().write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Self::new(u32::read(r)?).ok_or(ReadError::Corrupt)
+    }
+}
+
+/// Device IDs, as handed out by the [`Recorder`](super::Recorder).
+fn write_id(id: &crate::InternalID, w: &mut dyn Write) -> std::io::Result<()> {
+    id.unwrap_custom().write(w)
+}
+fn read_id(r: &mut dyn Read) -> ReadResult<crate::InternalID> {
+    Ok(u64::read(r)?.into())
+}
+impl Codec for ButtonID {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        // Recorded buttons are always renamed to custom ones.
+        self.0.unwrap_custom().0.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self(
+            crate::platform::custom::ButtonID(u32::read(r)?).into(),
+        ))
+    }
+}
+
+// Axes.
+
+impl Codec for NicheF32 {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        // NaN is `None` already.
+        self.get().unwrap_or(f32::NAN).write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self::new_some(f32::read(r)?).unwrap_or(Self::NONE))
+    }
+}
+impl Codec for Granularity {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.0.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self(Codec::read(r)?))
+    }
+}
+impl Codec for PositionInfo {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.granularity.map(|granularity| granularity.0).write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            granularity: Option::read(r)?.map(PositionGranularity),
+        })
+    }
+}
+/// The infos with nothing but a granularity.
+macro_rules! codec_granularity_info {
+    ($($ty:ty),*) => {$(
+        impl Codec for $ty {
+            fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+                self.granularity.write(w)
+            }
+            fn read(r: &mut dyn Read) -> ReadResult<Self> {
+                Ok(Self {
+                    granularity: Codec::read(r)?,
+                })
+            }
+        }
+    )*};
+}
+codec_granularity_info!(NormalizedInfo, CircularInfo, SliderInfo);
+impl Codec for Info {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.limits
+            .map(|limits| [limits.min, limits.max])
+            .write(w)?;
+        self.granularity.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            limits: Option::<[f32; 2]>::read(r)?.map(|[min, max]| Limits { min, max }),
+            granularity: Codec::read(r)?,
+        })
+    }
+}
+impl Codec for LengthInfo {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Normalized(info) => {
+                0u8.write(w)?;
+                info.write(w)
+            }
+            Self::Centimeters(info) => {
+                1u8.write(w)?;
+                info.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        match u8::read(r)? {
+            0 => Ok(Self::Normalized(Codec::read(r)?)),
+            1 => Ok(Self::Centimeters(Codec::read(r)?)),
+            _ => Err(ReadError::Corrupt),
+        }
+    }
+}
+impl Codec for FullInfo {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.position.write(w)?;
+        self.slider.write(w)?;
+        self.roll.write(w)?;
+        self.pressure.write(w)?;
+        self.button_pressure.write(w)?;
+        self.tilt.write(w)?;
+        self.wheel.write(w)?;
+        self.distance.write(w)?;
+        self.contact_size.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            position: Codec::read(r)?,
+            slider: Codec::read(r)?,
+            roll: Codec::read(r)?,
+            pressure: Codec::read(r)?,
+            button_pressure: Codec::read(r)?,
+            tilt: Codec::read(r)?,
+            wheel: Codec::read(r)?,
+            distance: Codec::read(r)?,
+            contact_size: Codec::read(r)?,
+        })
+    }
+}
+impl Codec for Pose {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.position.write(w)?;
+        self.distance.write(w)?;
+        self.raw_distance.write(w)?;
+        self.pressure.write(w)?;
+        self.button_pressure.write(w)?;
+        self.tilt.write(w)?;
+        self.roll.write(w)?;
+        self.wheel.write(w)?;
+        self.slider.write(w)?;
+        self.contact_size.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            position: Codec::read(r)?,
+            distance: Codec::read(r)?,
+            raw_distance: Codec::read(r)?,
+            pressure: Codec::read(r)?,
+            button_pressure: Codec::read(r)?,
+            tilt: Codec::read(r)?,
+            roll: Codec::read(r)?,
+            wheel: Codec::read(r)?,
+            slider: Codec::read(r)?,
+            contact_size: Codec::read(r)?,
+        })
+    }
+}
+impl Codec for ExtraAxes {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_len(self.len(), w)?;
+        self.iter().try_for_each(|(key, value)| {
+            match key {
+                ExtraAxisKey::Guid(guid) => {
+                    0u8.write(w)?;
+                    guid.write(w)?;
+                }
+                ExtraAxisKey::Name(name) => {
+                    1u8.write(w)?;
+                    name.to_string().write(w)?;
+                }
+            }
+            value.write(w)
+        })
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        let mut extras = Self::new();
+        for _ in 0..read_len(r)? {
+            let key = match u8::read(r)? {
+                0 => ExtraAxisKey::Guid(Codec::read(r)?),
+                1 => ExtraAxisKey::Name(String::read(r)?.into()),
+                _ => return Err(ReadError::Corrupt),
+            };
+            extras.insert(key, Codec::read(r)?);
+        }
+        Ok(extras)
+    }
+}
+impl Codec for RawValue {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.value.write(w)?;
+        self.bias.write(w)?;
+        self.scale.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            value: Codec::read(r)?,
+            bias: Codec::read(r)?,
+            scale: Codec::read(r)?,
+        })
+    }
+}
+impl Codec for RawPose {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.position.write(w)?;
+        self.distance.write(w)?;
+        self.pressure.write(w)?;
+        self.button_pressure.write(w)?;
+        self.tilt.write(w)?;
+        self.roll.write(w)?;
+        self.wheel.write(w)?;
+        self.slider.write(w)?;
+        self.contact_size.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            position: Codec::read(r)?,
+            distance: Codec::read(r)?,
+            pressure: Codec::read(r)?,
+            button_pressure: Codec::read(r)?,
+            tilt: Codec::read(r)?,
+            roll: Codec::read(r)?,
+            wheel: Codec::read(r)?,
+            slider: Codec::read(r)?,
+            contact_size: Codec::read(r)?,
+        })
+    }
+}
+
+// Devices.
+
+impl Codec for Tablet {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_id(&self.internal_id, w)?;
+        self.name.write(w)?;
+        self.usb_id.map(|usb| (usb.vid, usb.pid)).write(w)?;
+        self.report_rate.map(|rate| rate.0).write(w)?;
+        self.rotation.write(w)?;
+        self.physical_size.write(w)?;
+        self.resolution.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            internal_id: read_id(r)?,
+            name: Codec::read(r)?,
+            usb_id: Option::read(r)?.map(|(vid, pid)| UsbId { vid, pid }),
+            report_rate: Option::read(r)?.map(Hz),
+            rotation: Codec::read(r)?,
+            physical_size: Codec::read(r)?,
+            resolution: Codec::read(r)?,
+        })
+    }
+}
+impl Codec for ButtonRole {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Primary => 0u8.write(w),
+            Self::Barrel1 => 1u8.write(w),
+            Self::Barrel2 => 2u8.write(w),
+            Self::Eraser => 3u8.write(w),
+            Self::Unknown(id) => {
+                4u8.write(w)?;
+                id.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Primary,
+            1 => Self::Barrel1,
+            2 => Self::Barrel2,
+            3 => Self::Eraser,
+            4 => Self::Unknown(Codec::read(r)?),
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for ButtonInfo {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.id.write(w)?;
+        self.name.write(w)?;
+        self.role.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            id: Codec::read(r)?,
+            name: Codec::read(r)?,
+            role: Codec::read(r)?,
+        })
+    }
+}
+impl Codec for Tool {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_id(&self.internal_id, w)?;
+        self.name.write(w)?;
+        self.hardware_id.map(|id| id.0).write(w)?;
+        self.wacom_id.write(w)?;
+        self.tool_type.write(w)?;
+        self.axes.write(w)?;
+        self.buttons.write(w)?;
+        self.button_count.write(w)?;
+        self.reports_out_of_proximity.write(w)?;
+        self.tip_threshold.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            internal_id: read_id(r)?,
+            name: Codec::read(r)?,
+            hardware_id: Option::read(r)?.map(HardwareID),
+            wacom_id: Codec::read(r)?,
+            tool_type: Codec::read(r)?,
+            axes: Codec::read(r)?,
+            buttons: Codec::read(r)?,
+            button_count: Codec::read(r)?,
+            reports_out_of_proximity: Codec::read(r)?,
+            tip_threshold: Codec::read(r)?,
+        })
+    }
+}
+/// Rings and strips, which are alike.
+macro_rules! codec_touch_strip {
+    ($($ty:ty),*) => {$(
+        impl Codec for $ty {
+            fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+                write_id(&self.internal_id, w)?;
+                self.granularity.write(w)?;
+                write_len(self.index_in_group, w)
+            }
+            fn read(r: &mut dyn Read) -> ReadResult<Self> {
+                Ok(Self {
+                    internal_id: read_id(r)?,
+                    granularity: Codec::read(r)?,
+                    index_in_group: read_len(r)?,
+                })
+            }
+        }
+    )*};
+}
+codec_touch_strip!(Ring, Strip);
+impl Codec for Dial {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_id(&self.internal_id, w)?;
+        write_len(self.index_in_group, w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            internal_id: read_id(r)?,
+            index_in_group: read_len(r)?,
+        })
+    }
+}
+impl Codec for Group {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_id(&self.internal_id, w)?;
+        self.mode_count.write(w)?;
+        self.mode_indicators.write(w)?;
+        self.buttons.write(w)?;
+        self.rings.write(w)?;
+        self.strips.write(w)?;
+        self.dials.write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            internal_id: read_id(r)?,
+            mode_count: Codec::read(r)?,
+            mode_indicators: Codec::read(r)?,
+            buttons: Codec::read(r)?,
+            rings: Codec::read(r)?,
+            strips: Codec::read(r)?,
+            dials: Codec::read(r)?,
+            // Can't be recorded, and isn't needed for playback.
+            feedback: None,
+        })
+    }
+}
+impl Codec for Pad {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        write_id(&self.internal_id, w)?;
+        self.total_buttons.write(w)?;
+        self.groups.write(w)?;
+        self.tablet
+            .as_ref()
+            .map(crate::InternalID::unwrap_custom)
+            .copied()
+            .write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            internal_id: read_id(r)?,
+            total_buttons: Codec::read(r)?,
+            groups: Codec::read(r)?,
+            tablet: Option::<u64>::read(r)?.map(Into::into),
+        })
+    }
+}
+
+// Events.
+
+impl Codec for FrameTimestamp {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        (*self - Self::epoch()).write(w)?;
+        self.is_synthetic().write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        let time = Codec::read(r)?;
+        Ok(if bool::read(r)? {
+            Self::synthetic(time)
+        } else {
+            Self::hardware(time)
+        })
+    }
+}
+impl Codec for TouchStripEvent {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Pose(pose) => {
+                0u8.write(w)?;
+                pose.write(w)
+            }
+            Self::Source(source) => {
+                1u8.write(w)?;
+                source.write(w)
+            }
+            Self::Frame(time) => {
+                2u8.write(w)?;
+                time.write(w)
+            }
+            Self::Up => 3u8.write(w),
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Pose(Codec::read(r)?),
+            1 => Self::Source(Codec::read(r)?),
+            2 => Self::Frame(Codec::read(r)?),
+            3 => Self::Up,
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for DialEvent {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Delta(delta) => {
+                0u8.write(w)?;
+                delta.write(w)
+            }
+            Self::Frame(time) => {
+                1u8.write(w)?;
+                time.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Delta(Codec::read(r)?),
+            1 => Self::Frame(Codec::read(r)?),
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for Finger {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        self.contact.0.write(w)?;
+        self.position.write(w)?;
+        self.contact_size.write(w)?;
+        self.window.map(|window| window.0).write(w)
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(Self {
+            contact: ContactID(Codec::read(r)?),
+            position: Codec::read(r)?,
+            contact_size: Codec::read(r)?,
+            window: Option::read(r)?.map(crate::WindowId),
+        })
+    }
+}
+impl Codec for TouchEvent {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Down(finger) => {
+                0u8.write(w)?;
+                finger.write(w)
+            }
+            Self::Move(finger) => {
+                1u8.write(w)?;
+                finger.write(w)
+            }
+            Self::Up(contact) => {
+                2u8.write(w)?;
+                contact.0.write(w)
+            }
+            Self::Cancel => 3u8.write(w),
+            Self::Frame(time) => {
+                4u8.write(w)?;
+                time.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Down(Codec::read(r)?),
+            1 => Self::Move(Codec::read(r)?),
+            2 => Self::Up(ContactID(Codec::read(r)?)),
+            3 => Self::Cancel,
+            4 => Self::Frame(Codec::read(r)?),
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for ToolEvent {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Added => 0u8.write(w),
+            Self::Removed => 1u8.write(w),
+            Self::In { tablet } => {
+                2u8.write(w)?;
+                tablet.write(w)
+            }
+            Self::Down => 3u8.write(w),
+            Self::Button { button_id, pressed } => {
+                4u8.write(w)?;
+                button_id.write(w)?;
+                pressed.write(w)
+            }
+            Self::Pose(pose) => {
+                5u8.write(w)?;
+                pose.write(w)
+            }
+            Self::Tracking(pose) => {
+                6u8.write(w)?;
+                pose.write(w)
+            }
+            Self::Extras(extras) => {
+                7u8.write(w)?;
+                extras.write(w)
+            }
+            Self::RawPose(raw) => {
+                8u8.write(w)?;
+                raw.write(w)
+            }
+            Self::Frame(time) => {
+                9u8.write(w)?;
+                time.write(w)
+            }
+            Self::Up => 10u8.write(w),
+            Self::Out => 11u8.write(w),
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Added,
+            1 => Self::Removed,
+            2 => Self::In {
+                tablet: Codec::read(r)?,
+            },
+            3 => Self::Down,
+            4 => Self::Button {
+                button_id: Codec::read(r)?,
+                pressed: Codec::read(r)?,
+            },
+            5 => Self::Pose(Codec::read(r)?),
+            6 => Self::Tracking(Codec::read(r)?),
+            7 => Self::Extras(Codec::read(r)?),
+            8 => Self::RawPose(Box::new(Codec::read(r)?)),
+            9 => Self::Frame(Codec::read(r)?),
+            10 => Self::Up,
+            11 => Self::Out,
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for PadGroupEvent {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Ring { ring, event } => {
+                0u8.write(w)?;
+                ring.write(w)?;
+                event.write(w)
+            }
+            Self::Strip { strip, event } => {
+                1u8.write(w)?;
+                strip.write(w)?;
+                event.write(w)
+            }
+            Self::Dial { dial, event } => {
+                2u8.write(w)?;
+                dial.write(w)?;
+                event.write(w)
+            }
+            Self::Mode(mode) => {
+                3u8.write(w)?;
+                mode.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Ring {
+                ring: Codec::read(r)?,
+                event: Codec::read(r)?,
+            },
+            1 => Self::Strip {
+                strip: Codec::read(r)?,
+                event: Codec::read(r)?,
+            },
+            2 => Self::Dial {
+                dial: Codec::read(r)?,
+                event: Codec::read(r)?,
+            },
+            3 => Self::Mode(Codec::read(r)?),
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for PadEvent {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Added => 0u8.write(w),
+            Self::Removed => 1u8.write(w),
+            Self::Group { group, event } => {
+                2u8.write(w)?;
+                group.write(w)?;
+                event.write(w)
+            }
+            Self::Button {
+                button_idx,
+                pressed,
+            } => {
+                3u8.write(w)?;
+                button_idx.write(w)?;
+                pressed.write(w)
+            }
+            Self::Enter { tablet } => {
+                4u8.write(w)?;
+                tablet.write(w)
+            }
+            Self::Exit => 5u8.write(w),
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Added,
+            1 => Self::Removed,
+            2 => Self::Group {
+                group: Codec::read(r)?,
+                event: Codec::read(r)?,
+            },
+            3 => Self::Button {
+                button_idx: Codec::read(r)?,
+                pressed: Codec::read(r)?,
+            },
+            4 => Self::Enter {
+                tablet: Codec::read(r)?,
+            },
+            5 => Self::Exit,
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
+impl Codec for Event {
+    fn write(&self, w: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Self::Tool { tool, event } => {
+                0u8.write(w)?;
+                tool.write(w)?;
+                event.write(w)
+            }
+            Self::Tablet { tablet, event } => {
+                1u8.write(w)?;
+                tablet.write(w)?;
+                matches!(event, TabletEvent::Removed).write(w)
+            }
+            Self::Pad { pad, event } => {
+                2u8.write(w)?;
+                pad.write(w)?;
+                event.write(w)
+            }
+            Self::EnumerationComplete => 3u8.write(w),
+            Self::WindowFocus { focused } => {
+                4u8.write(w)?;
+                focused.write(w)
+            }
+            Self::Touch(touch) => {
+                5u8.write(w)?;
+                touch.write(w)
+            }
+            Self::System(system) => {
+                6u8.write(w)?;
+                system.write(w)
+            }
+        }
+    }
+    fn read(r: &mut dyn Read) -> ReadResult<Self> {
+        Ok(match u8::read(r)? {
+            0 => Self::Tool {
+                tool: Codec::read(r)?,
+                event: Codec::read(r)?,
+            },
+            1 => Self::Tablet {
+                tablet: Codec::read(r)?,
+                event: if bool::read(r)? {
+                    TabletEvent::Removed
+                } else {
+                    TabletEvent::Added
+                },
+            },
+            2 => Self::Pad {
+                pad: Codec::read(r)?,
+                event: Codec::read(r)?,
+            },
+            3 => Self::EnumerationComplete,
+            4 => Self::WindowFocus {
+                focused: Codec::read(r)?,
+            },
+            5 => Self::Touch(Codec::read(r)?),
+            6 => Self::System(Codec::read(r)?),
+            _ => return Err(ReadError::Corrupt),
+        })
+    }
+}
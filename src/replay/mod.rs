@@ -0,0 +1,680 @@
+//! # Recording and replay
+//!
+//! Record what a [`Manager`] reports with a [`Recorder`], save it to a compact binary file, and play it back later
+//! through a [`Player`] - a [custom backend](crate::custom::CustomBackend) for a manager of its own. Handy for bug
+//! reports about device quirks ("here's what my tablet does"), and for deterministic testing.
+//!
+//! ```no_run
+//! # fn record(manager: &mut octotablet::Manager) -> Result<(), Box<dyn std::error::Error>> {
+//! use octotablet::replay::{Player, Recorder, Recording, Timing};
+//! // Recording, after each pump...
+//! let mut recorder = Recorder::new();
+//! manager.pump()?;
+//! recorder.record(manager);
+//! // ...and when done.
+//! recorder.finish().write_to(std::fs::File::create("tablet.octorec")?)?;
+//!
+//! // Later on, maybe on another machine altogether:
+//! let recording = Recording::read_from(std::fs::File::open("tablet.octorec")?)?;
+//! let mut replayed = octotablet::Builder::default()
+//!     .build_custom(Player::new(recording, Timing::Original));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! What's recorded is what the manager reported, after any of the [`Builder`](crate::Builder)'s processing - replay
+//! with a plain builder so curves and the like aren't applied twice. Devices are snapshotted each time the set of
+//! them changes (see [`Manager::devices_epoch`]), and are given new IDs along the way. Pad
+//! [feedback](crate::pad::Group::feedback) isn't recorded, nor is [`PointerLock`](crate::events::Event::PointerLock),
+//! and [`TimedOut`](crate::events::ToolEvent::TimedOut) is recorded as a plain `Out`.
+
+mod codec;
+
+use crate::{custom, pad::Pad, tablet::Tablet, tool::Tool, Manager};
+use codec::Codec;
+
+/// Reasons a [`Recording`] couldn't be read.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ReadError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The data doesn't start like a recording does.
+    #[error("not a recording")]
+    NotARecording,
+    /// The recording was made by a version of this crate with a different format.
+    #[error("unsupported recording version {0}")]
+    UnsupportedVersion(u8),
+    /// The data is a recording, but a damaged one.
+    #[error("recording is corrupt")]
+    Corrupt,
+}
+
+/// The devices as of a pump.
+struct Devices {
+    tablets: Vec<Tablet>,
+    tools: Vec<Tool>,
+    pads: Vec<Pad>,
+}
+impl Codec for Devices {
+    fn write(&self, w: &mut dyn std::io::Write) -> std::io::Result<()> {
+        self.tablets.write(w)?;
+        self.tools.write(w)?;
+        self.pads.write(w)
+    }
+    fn read(r: &mut dyn std::io::Read) -> Result<Self, ReadError> {
+        Ok(Self {
+            tablets: Codec::read(r)?,
+            tools: Codec::read(r)?,
+            pads: Codec::read(r)?,
+        })
+    }
+}
+
+/// A single recorded pump.
+struct Pump {
+    /// Since the first recorded pump.
+    at: std::time::Duration,
+    /// Present if the devices changed this pump.
+    devices: Option<Devices>,
+    events: Vec<custom::Event>,
+}
+impl Codec for Pump {
+    fn write(&self, w: &mut dyn std::io::Write) -> std::io::Result<()> {
+        self.at.write(w)?;
+        self.devices.write(w)?;
+        self.events.write(w)
+    }
+    fn read(r: &mut dyn std::io::Read) -> Result<Self, ReadError> {
+        Ok(Self {
+            at: Codec::read(r)?,
+            devices: Codec::read(r)?,
+            events: Codec::read(r)?,
+        })
+    }
+}
+
+/// A recorded session, made with a [`Recorder`] or read from a file. See the [module level docs](crate::replay).
+pub struct Recording {
+    pumps: Vec<Pump>,
+}
+impl std::fmt::Debug for Recording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recording")
+            .field("pumps", &self.pumps.len())
+            .field("duration", &self.duration())
+            .finish()
+    }
+}
+impl Recording {
+    /// How long the recording runs for, from the first recorded pump to the last.
+    #[must_use]
+    pub fn duration(&self) -> std::time::Duration {
+        self.pumps
+            .last()
+            .map_or(std::time::Duration::ZERO, |pump| pump.at)
+    }
+    /// Save the recording. The writer is written to in many small pieces, so should be buffered if it's a file or
+    /// socket.
+    ///
+    /// # Errors
+    /// Those of the writer.
+    pub fn write_to(&self, mut writer: impl std::io::Write) -> std::io::Result<()> {
+        writer.write_all(codec::MAGIC)?;
+        codec::VERSION.write(&mut writer)?;
+        self.pumps.write(&mut writer)?;
+        writer.flush()
+    }
+    /// Load a recording saved with [`Recording::write_to`]. The reader is read in many small pieces, so should be
+    /// buffered if it's a file or socket.
+    ///
+    /// # Errors
+    /// See [`ReadError`].
+    pub fn read_from(mut reader: impl std::io::Read) -> Result<Self, ReadError> {
+        let mut magic = [0; codec::MAGIC.len()];
+        reader.read_exact(&mut magic).map_err(|err| {
+            if err.kind() == std::io::ErrorKind::UnexpectedEof {
+                ReadError::NotARecording
+            } else {
+                err.into()
+            }
+        })?;
+        if &magic != codec::MAGIC {
+            return Err(ReadError::NotARecording);
+        }
+        match u8::read(&mut reader)? {
+            codec::VERSION => (),
+            version => return Err(ReadError::UnsupportedVersion(version)),
+        }
+        Ok(Self {
+            pumps: Codec::read(&mut reader)?,
+        })
+    }
+}
+
+/// Renames the manager's IDs to the plain numbers used by custom backends.
+#[derive(Default)]
+struct Renamer {
+    // Linear scans - there's only ever a handful of devices. Shared across all kinds of device, which only need be
+    // unique amongst their own kind.
+    ids: Vec<crate::InternalID>,
+    buttons: Vec<crate::platform::ButtonID>,
+}
+impl Renamer {
+    fn id(&mut self, id: &crate::InternalID) -> u64 {
+        let index = self
+            .ids
+            .iter()
+            .position(|known| known == id)
+            .unwrap_or_else(|| {
+                self.ids.push(id.clone());
+                self.ids.len() - 1
+            });
+        index as u64
+    }
+    fn internal_id(&mut self, id: &crate::InternalID) -> crate::InternalID {
+        self.id(id).into()
+    }
+    fn button(&mut self, id: crate::tool::ButtonID) -> u32 {
+        let index = self
+            .buttons
+            .iter()
+            .position(|known| *known == id.0)
+            .unwrap_or_else(|| {
+                self.buttons.push(id.0);
+                self.buttons.len() - 1
+            });
+        u32::try_from(index).unwrap_or(u32::MAX)
+    }
+    fn button_id(&mut self, id: crate::tool::ButtonID) -> crate::tool::ButtonID {
+        crate::tool::ButtonID(crate::platform::custom::ButtonID(self.button(id)).into())
+    }
+    fn devices(&mut self, manager: &Manager) -> Devices {
+        use crate::tool::ButtonRole;
+        Devices {
+            tablets: manager
+                .tablets()
+                .iter()
+                .map(|tablet| Tablet {
+                    internal_id: self.internal_id(&tablet.internal_id),
+                    ..tablet.clone()
+                })
+                .collect(),
+            tools: manager
+                .tools()
+                .iter()
+                .map(|tool| Tool {
+                    internal_id: self.internal_id(&tool.internal_id),
+                    buttons: tool
+                        .buttons
+                        .iter()
+                        .map(|button| crate::tool::ButtonInfo {
+                            id: self.button_id(button.id),
+                            name: button.name.clone(),
+                            role: match button.role {
+                                ButtonRole::Unknown(id) => ButtonRole::Unknown(self.button_id(id)),
+                                role => role,
+                            },
+                        })
+                        .collect(),
+                    ..tool.clone()
+                })
+                .collect(),
+            pads: manager
+                .pads()
+                .iter()
+                .map(|pad| {
+                    let mut pad = pad.snapshot();
+                    pad.internal_id = self.internal_id(&pad.internal_id);
+                    pad.tablet = pad.tablet.as_ref().map(|tablet| self.internal_id(tablet));
+                    for group in &mut pad.groups {
+                        group.internal_id = self.internal_id(&group.internal_id);
+                        for ring in &mut group.rings {
+                            ring.internal_id = self.internal_id(&ring.internal_id);
+                        }
+                        for strip in &mut group.strips {
+                            strip.internal_id = self.internal_id(&strip.internal_id);
+                        }
+                        for dial in &mut group.dials {
+                            dial.internal_id = self.internal_id(&dial.internal_id);
+                        }
+                    }
+                    pad
+                })
+                .collect(),
+        }
+    }
+    /// Convert an event, or `None` for those that can't be replayed.
+    fn event(&mut self, event: crate::events::OwnedEvent) -> Option<custom::Event> {
+        use crate::events::{OwnedEvent, OwnedPadEvent, OwnedPadGroupEvent, OwnedToolEvent};
+        use custom::{PadEvent, PadGroupEvent, ToolEvent};
+        Some(match event {
+            OwnedEvent::Tool { tool, event } => custom::Event::Tool {
+                tool: self.id(&tool.0),
+                event: match event {
+                    OwnedToolEvent::Added => ToolEvent::Added,
+                    OwnedToolEvent::Removed => ToolEvent::Removed,
+                    OwnedToolEvent::In { tablet, .. } => ToolEvent::In {
+                        tablet: self.id(&tablet.0),
+                    },
+                    OwnedToolEvent::Down => ToolEvent::Down,
+                    OwnedToolEvent::Button { button_id, pressed } => ToolEvent::Button {
+                        button_id: self.button(button_id),
+                        pressed,
+                    },
+                    OwnedToolEvent::Pose(pose) => ToolEvent::Pose(pose),
+                    OwnedToolEvent::Tracking(pose) => ToolEvent::Tracking(pose),
+                    OwnedToolEvent::Extras(extras) => ToolEvent::Extras(extras),
+                    OwnedToolEvent::RawPose(raw) => ToolEvent::RawPose(raw),
+                    OwnedToolEvent::Frame(time) => ToolEvent::Frame(time),
+                    OwnedToolEvent::Up => ToolEvent::Up,
+                    OwnedToolEvent::Out | OwnedToolEvent::TimedOut => ToolEvent::Out,
+                },
+            },
+            OwnedEvent::Tablet { tablet, event } => custom::Event::Tablet {
+                tablet: self.id(&tablet.0),
+                event,
+            },
+            OwnedEvent::Pad { pad, event } => custom::Event::Pad {
+                pad: self.id(&pad.0),
+                event: match event {
+                    OwnedPadEvent::Added => PadEvent::Added,
+                    OwnedPadEvent::Removed => PadEvent::Removed,
+                    OwnedPadEvent::Group { group, event } => PadEvent::Group {
+                        group: self.id(&group.0),
+                        event: match event {
+                            OwnedPadGroupEvent::Ring { ring, event } => PadGroupEvent::Ring {
+                                ring: self.id(&ring.0),
+                                event,
+                            },
+                            OwnedPadGroupEvent::Strip { strip, event } => PadGroupEvent::Strip {
+                                strip: self.id(&strip.0),
+                                event,
+                            },
+                            OwnedPadGroupEvent::Dial { dial, event } => PadGroupEvent::Dial {
+                                dial: self.id(&dial.0),
+                                event,
+                            },
+                            OwnedPadGroupEvent::Mode(mode) => PadGroupEvent::Mode(mode),
+                        },
+                    },
+                    OwnedPadEvent::Button {
+                        button_idx,
+                        pressed,
+                        ..
+                    } => PadEvent::Button {
+                        button_idx,
+                        pressed,
+                    },
+                    OwnedPadEvent::Enter { tablet } => PadEvent::Enter {
+                        tablet: self.id(&tablet.0),
+                    },
+                    OwnedPadEvent::Exit => PadEvent::Exit,
+                },
+            },
+            OwnedEvent::EnumerationComplete => custom::Event::EnumerationComplete,
+            OwnedEvent::WindowFocus { focused } => custom::Event::WindowFocus { focused },
+            OwnedEvent::Touch(touch) => custom::Event::Touch(touch),
+            OwnedEvent::System(system) => custom::Event::System(system),
+            OwnedEvent::PointerLock { .. } => return None,
+        })
+    }
+}
+
+/// Records what a [`Manager`] reports, pump by pump. See the [module level docs](crate::replay).
+#[derive(Default)]
+pub struct Recorder {
+    /// When the first recorded pump happened, and the devices epoch as of the last.
+    started: Option<(std::time::Instant, u64)>,
+    renamer: Renamer,
+    pumps: Vec<Pump>,
+}
+impl std::fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recorder")
+            .field("pumps", &self.pumps.len())
+            .finish_non_exhaustive()
+    }
+}
+impl Recorder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Record the manager's last pump. Call after every successful [`Manager::pump`], as pumps that aren't recorded
+    /// are missing from the recording altogether.
+    ///
+    /// Pumps with nothing to report are skipped, as the recording's timing is kept by those that do.
+    pub fn record(&mut self, manager: &Manager) {
+        let epoch = manager.devices_epoch();
+        let (started, last_epoch) = self.started.get_or_insert((manager.pumped_at, !epoch));
+        let started = *started;
+        let devices_changed = std::mem::replace(last_epoch, epoch) != epoch;
+        let events: Vec<_> = manager
+            .raw_events()
+            .into_iter()
+            .filter_map(|event| self.renamer.event(event))
+            .collect();
+        if events.is_empty() && !devices_changed {
+            return;
+        }
+        self.pumps.push(Pump {
+            at: manager.pumped_at.saturating_duration_since(started),
+            devices: devices_changed.then(|| self.renamer.devices(manager)),
+            events,
+        });
+    }
+    /// Done recording.
+    #[must_use]
+    pub fn finish(self) -> Recording {
+        Recording { pumps: self.pumps }
+    }
+}
+
+/// How a [`Player`] paces the recording.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Timing {
+    /// Each recorded pump is replayed once as much time has passed since the first as had when recording.
+    #[default]
+    Original,
+    /// Each pump replays the next recorded pump, regardless of timing.
+    AsFastAsPossible,
+}
+
+/// A [custom backend](crate::custom::CustomBackend) that plays back a [`Recording`], for handing to
+/// [`Builder::build_custom`](crate::Builder::build_custom). See the [module level docs](crate::replay).
+///
+/// Every pump replays at most one recorded pump, so to keep to the original timing, pump at least as often as the
+/// recording did. Once played out, pumps report nothing more, and the devices stay as they were at the end.
+pub struct Player {
+    pumps: std::vec::IntoIter<Pump>,
+    timing: Timing,
+    /// When playback started, upon the first pump.
+    started: Option<std::time::Instant>,
+    devices: Devices,
+}
+impl std::fmt::Debug for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Player")
+            .field("remaining", &self.pumps.len())
+            .field("timing", &self.timing)
+            .finish_non_exhaustive()
+    }
+}
+impl Player {
+    #[must_use]
+    pub fn new(recording: Recording, timing: Timing) -> Self {
+        Self {
+            pumps: recording.pumps.into_iter(),
+            timing,
+            started: None,
+            devices: Devices {
+                tablets: Vec::new(),
+                tools: Vec::new(),
+                pads: Vec::new(),
+            },
+        }
+    }
+}
+impl custom::CustomBackend for Player {
+    fn pump(&mut self, events: &mut Vec<custom::Event>) -> Result<(), custom::Error> {
+        let started = *self.started.get_or_insert_with(std::time::Instant::now);
+        let due = match (self.timing, self.pumps.as_slice().first()) {
+            (_, None) => false,
+            (Timing::Original, Some(next)) => started.elapsed() >= next.at,
+            (Timing::AsFastAsPossible, Some(_)) => true,
+        };
+        let Some(pump) = due.then(|| self.pumps.next()).flatten() else {
+            return Ok(());
+        };
+        if let Some(devices) = pump.devices {
+            self.devices = devices;
+        }
+        // Keep pads' tablets in step with their events, as those changes don't get a snapshot of their own.
+        for event in &pump.events {
+            if let custom::Event::Pad {
+                pad,
+                event: event @ (custom::PadEvent::Enter { .. } | custom::PadEvent::Exit),
+            } = event
+            {
+                let tablet = match event {
+                    custom::PadEvent::Enter { tablet } => Some(*tablet),
+                    _ => None,
+                };
+                if let Some(pad) = self
+                    .devices
+                    .pads
+                    .iter_mut()
+                    .find(|this| this.internal_id.unwrap_custom() == pad)
+                {
+                    custom::set_pad_tablet(pad, tablet);
+                }
+            }
+        }
+        events.extend(pump.events);
+        Ok(())
+    }
+    fn pads(&self) -> &[Pad] {
+        &self.devices.pads
+    }
+    fn tools(&self) -> &[Tool] {
+        &self.devices.tools
+    }
+    fn tablets(&self) -> &[Tablet] {
+        &self.devices.tablets
+    }
+}
+
+#[cfg(all(test, mock_backend))]
+mod test {
+    use super::{Player, ReadError, Recorder, Recording, Timing};
+    use crate::{
+        axis::{ExtraAxes, ExtraAxisKey, Pose, RawPose, RawValue},
+        custom::{self, Event, PadEvent, PadGroupEvent, ToolEvent},
+        events::{DialEvent, SystemEvent, TouchStripEvent},
+        pad::TouchSource,
+        touch::TouchEvent,
+        util::NicheF32,
+        Builder, Manager,
+    };
+
+    fn builder() -> Builder {
+        Builder::default().capture_touch(true).report_raw_axes(true)
+    }
+    fn pose(position: [f32; 2], pressure: f32) -> Pose {
+        Pose {
+            position,
+            pressure: NicheF32::new_some(pressure).unwrap(),
+            tilt: Some([0.25, -0.5]),
+            ..Default::default()
+        }
+    }
+    fn encoded(recording: &Recording) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        recording.write_to(&mut bytes).unwrap();
+        bytes
+    }
+    /// What each pump reported, for comparing recordings made from different managers.
+    fn describe(recording: &Recording) -> Vec<String> {
+        recording
+            .pumps
+            .iter()
+            .map(|pump| {
+                let devices = pump.devices.as_ref().map(|devices| {
+                    format!(
+                        "{:?} {:?} {:?}",
+                        devices.tablets, devices.tools, devices.pads
+                    )
+                });
+                format!("{devices:?} {:?}", pump.events)
+            })
+            .collect()
+    }
+    fn pump_and_record(manager: &mut Manager, recorder: &mut Recorder) {
+        manager.pump().unwrap();
+        recorder.record(manager);
+    }
+
+    /// A recording of one of each kind of device doing one of most everything, made on the mock backend.
+    #[allow(clippy::too_many_lines)]
+    fn fixture() -> Recording {
+        let (mut manager, controller) = builder().build_mock();
+        let mut recorder = Recorder::new();
+
+        let mut tablet = custom::tablet(1);
+        tablet.name = Some("Fixture tablet".to_owned());
+        tablet.usb_id = Some(crate::tablet::UsbId {
+            vid: 0x056a,
+            pid: 0x0001,
+        });
+        let mut tool = custom::tool(2);
+        tool.name = Some("Fixture pen".to_owned());
+        tool.tool_type = Some(crate::tool::Type::Pen);
+        tool.buttons = vec![custom::button(7, Some("Lower".to_owned()))];
+        tool.button_count = Some(1);
+        tool.axes.pressure = Some(crate::axis::NormalizedInfo::default());
+        let mut group = custom::group(4);
+        group.mode_count = std::num::NonZeroU32::new(2);
+        group.buttons = vec![0, 1];
+        group.rings = vec![custom::ring(5, 0)];
+        group.strips = vec![custom::strip(6, 0)];
+        group.dials = vec![custom::dial(7, 0)];
+        let mut pad = custom::pad(3);
+        pad.total_buttons = 2;
+        pad.groups = vec![group];
+        // The mock doesn't keep this in step with `Enter` and `Exit` like the player does.
+        custom::set_pad_tablet(&mut pad, Some(1));
+        controller.add_tablet(tablet);
+        controller.add_tool(tool);
+        controller.add_pad(pad);
+        controller.extend([
+            Event::EnumerationComplete,
+            Event::WindowFocus { focused: true },
+            Event::Pad {
+                pad: 3,
+                event: PadEvent::Enter { tablet: 1 },
+            },
+        ]);
+        pump_and_record(&mut manager, &mut recorder);
+
+        controller.stroke(2, 1, [pose([1.0, 2.0], 0.25), pose([3.0, 4.0], 0.5)]);
+        let mut extras = ExtraAxes::new();
+        extras.insert(ExtraAxisKey::Guid(0x1234_5678), 0.75);
+        extras.insert(ExtraAxisKey::Name("Fixture axis".into()), -1.0);
+        let tool = |event| Event::Tool { tool: 2, event };
+        let group = |event| Event::Pad {
+            pad: 3,
+            event: PadEvent::Group { group: 4, event },
+        };
+        let time = Some(custom::timestamp(std::time::Duration::from_millis(16)));
+        controller.extend([
+            tool(ToolEvent::In { tablet: 1 }),
+            tool(ToolEvent::Pose(pose([5.0, 6.0], 0.0))),
+            tool(ToolEvent::Extras(extras)),
+            tool(ToolEvent::RawPose(Box::new(RawPose {
+                position: [Some(RawValue::scaled(500, 0.01)), None],
+                pressure: Some(RawValue {
+                    value: 1024,
+                    bias: -12,
+                    scale: 1.0 / 2048.0,
+                }),
+                ..Default::default()
+            }))),
+            tool(ToolEvent::Button {
+                button_id: 7,
+                pressed: true,
+            }),
+            tool(ToolEvent::Frame(time)),
+            tool(ToolEvent::Out),
+            tool(ToolEvent::Frame(None)),
+            group(PadGroupEvent::Ring {
+                ring: 5,
+                event: TouchStripEvent::Pose(1.5),
+            }),
+            group(PadGroupEvent::Ring {
+                ring: 5,
+                event: TouchStripEvent::Source(TouchSource::Finger),
+            }),
+            group(PadGroupEvent::Ring {
+                ring: 5,
+                event: TouchStripEvent::Frame(time),
+            }),
+            group(PadGroupEvent::Strip {
+                strip: 6,
+                event: TouchStripEvent::Up,
+            }),
+            group(PadGroupEvent::Dial {
+                dial: 7,
+                event: DialEvent::Delta(-120),
+            }),
+            group(PadGroupEvent::Dial {
+                dial: 7,
+                event: DialEvent::Frame(None),
+            }),
+            group(PadGroupEvent::Mode(1)),
+            Event::Pad {
+                pad: 3,
+                event: PadEvent::Button {
+                    button_idx: 1,
+                    pressed: true,
+                },
+            },
+            Event::Touch(TouchEvent::Down(custom::finger(8, [10.0, 20.0]))),
+            Event::Touch(TouchEvent::Frame(time)),
+            Event::Touch(TouchEvent::Up(custom::contact(8))),
+            Event::Touch(TouchEvent::Frame(None)),
+            Event::System(SystemEvent::ScaleFactorChanged),
+        ]);
+        pump_and_record(&mut manager, &mut recorder);
+
+        controller.remove_pad(3);
+        controller.remove_tool(2);
+        controller.remove_tablet(1);
+        pump_and_record(&mut manager, &mut recorder);
+        pump_and_record(&mut manager, &mut recorder);
+
+        recorder.finish()
+    }
+
+    #[test]
+    fn round_trip() {
+        let recording = fixture();
+        let bytes = encoded(&recording);
+        let read = Recording::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(describe(&read), describe(&recording));
+        assert_eq!(encoded(&read), bytes);
+    }
+
+    #[test]
+    fn replays_as_recorded() {
+        let recording = fixture();
+        let pumps = recording.pumps.len();
+        let expected = describe(&recording);
+        let mut manager = builder().build_custom(Player::new(recording, Timing::AsFastAsPossible));
+        let mut recorder = Recorder::new();
+        for _ in 0..pumps {
+            pump_and_record(&mut manager, &mut recorder);
+        }
+        assert_eq!(describe(&recorder.finish()), expected);
+    }
+
+    #[test]
+    fn rejects_bad_data() {
+        assert!(matches!(
+            Recording::read_from(&b"octo"[..]),
+            Err(ReadError::NotARecording)
+        ));
+        assert!(matches!(
+            Recording::read_from(&b"definitely not a recording"[..]),
+            Err(ReadError::NotARecording)
+        ));
+        let mut bytes = encoded(&fixture());
+        assert!(Recording::read_from(&bytes[..bytes.len() - 1]).is_err());
+        bytes[super::codec::MAGIC.len()] = super::codec::VERSION.wrapping_add(1);
+        assert!(matches!(
+            Recording::read_from(bytes.as_slice()),
+            Err(ReadError::UnsupportedVersion(_))
+        ));
+    }
+}
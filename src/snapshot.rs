@@ -0,0 +1,30 @@
+//! Shareable copies of the manager's state, for reading from other threads. See [`Manager::snapshot`].
+//!
+//! Engines with separate simulation and render threads can hand each pump's snapshot over to the other side and read
+//! from it there, without locking or otherwise touching the [`Manager`], which never leaves the thread it was built
+//! on.
+
+use crate::{events::Detached, Manager};
+
+/// The devices and events of a single pump, made with [`Manager::snapshot`]. A [`Detached`] behind an [`Arc`], so
+/// cheap to clone - all clones share the same data.
+///
+/// [`Arc`]: std::sync::Arc
+#[derive(Clone, Debug)]
+pub struct Snapshot(std::sync::Arc<Detached>);
+// Sending these elsewhere is the whole point.
+const _: fn() = || {
+    fn assert_send<T: Send + Sync>() {}
+    assert_send::<Snapshot>();
+};
+impl Snapshot {
+    pub(crate) fn of(manager: &Manager) -> Self {
+        Self(std::sync::Arc::new(Detached::of(manager)))
+    }
+}
+impl std::ops::Deref for Snapshot {
+    type Target = Detached;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
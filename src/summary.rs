@@ -0,0 +1,182 @@
+//! The latest known state of every device, for those who'd rather not accumulate events themselves.
+//! See [`Manager::summary`](crate::Manager::summary).
+//!
+//! An immediate-mode GUI can check the summary once a frame and draw accordingly, with no event handling of its own.
+//! Anything that happened and then un-happened within a single pump (a quick click, say) is invisible here, so
+//! events are still the way to go for anything that mustn't be missed.
+
+use crate::{
+    axis::Pose,
+    events::{
+        raw::{
+            Event as RawEvent, PadEvent as RawPad, PadGroupEvent as RawGroup, ToolEvent as RawTool,
+        },
+        TouchStripEvent,
+    },
+    pad,
+    platform::InternalID,
+    tablet, tool, Manager,
+};
+
+/// The latest known state of a [`Tool`](tool::Tool).
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ToolState {
+    /// The tablet the tool is in proximity of, `None` if out.
+    pub tablet: Option<tablet::ID>,
+    /// Whether the tool is [down](crate::events::ToolEvent::Down).
+    pub down: bool,
+    /// The most recent pose. Kept around after the tool goes out, as the last place it was seen, and updated by
+    /// [`Tracking`](crate::events::ToolEvent::Tracking) while out.
+    pub pose: Option<Pose>,
+    /// Buttons currently held, in the order they were pressed.
+    pub buttons: Vec<tool::ButtonID>,
+}
+impl ToolState {
+    /// Whether the tool is in proximity of any tablet.
+    #[must_use]
+    pub fn is_in(&self) -> bool {
+        self.tablet.is_some()
+    }
+}
+
+/// The latest known state of a [`Pad`](pad::Pad).
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct PadState {
+    /// The current mode of each group that has reported one.
+    pub modes: std::collections::HashMap<pad::group::ID, u32>,
+    /// The most recent position of each ring that has reported one, see
+    /// [`TouchStripEvent::Pose`](crate::events::TouchStripEvent::Pose).
+    pub rings: std::collections::HashMap<pad::ring::ID, f32>,
+    /// The most recent position of each strip that has reported one, see
+    /// [`TouchStripEvent::Pose`](crate::events::TouchStripEvent::Pose).
+    pub strips: std::collections::HashMap<pad::strip::ID, f32>,
+    /// Pad buttons currently held, by index, in the order they were pressed.
+    pub buttons: Vec<u32>,
+}
+
+/// The latest known state of every device, as of the last [`Manager::pump`](crate::Manager::pump).
+#[derive(Clone, Copy)]
+pub struct Summary<'manager> {
+    pub(crate) manager: &'manager Manager,
+}
+impl<'manager> Summary<'manager> {
+    /// The state of the given tool, `None` if it's unknown or has been removed.
+    #[must_use]
+    pub fn tool(&self, tool: &tool::ID) -> Option<&'manager ToolState> {
+        self.manager.summary.tools.get(&tool.0)
+    }
+    /// The state of the given pad, `None` if it's unknown or has been removed.
+    #[must_use]
+    pub fn pad(&self, pad: &pad::ID) -> Option<&'manager PadState> {
+        self.manager.summary.pads.get(&pad.0)
+    }
+    /// Every tool alongside its state, in the same order as [`Manager::tools`](crate::Manager::tools).
+    pub fn tools(
+        &self,
+    ) -> impl Iterator<Item = (&'manager tool::Tool, &'manager ToolState)> + 'manager {
+        let summary = &self.manager.summary;
+        self.manager
+            .tools()
+            .iter()
+            .filter_map(|tool| Some((tool, summary.tools.get(&tool.internal_id)?)))
+    }
+    /// Every pad alongside its state, in the same order as [`Manager::pads`](crate::Manager::pads).
+    pub fn pads(
+        &self,
+    ) -> impl Iterator<Item = (&'manager pad::Pad, &'manager PadState)> + 'manager {
+        let summary = &self.manager.summary;
+        self.manager
+            .pads()
+            .iter()
+            .filter_map(|pad| Some((pad, summary.pads.get(&pad.internal_id)?)))
+    }
+    /// The tools currently down, for the common case of only caring about those.
+    pub fn down(
+        &self,
+    ) -> impl Iterator<Item = (&'manager tool::Tool, &'manager ToolState)> + 'manager {
+        self.tools().filter(|(_, state)| state.down)
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Tracker {
+    tools: std::collections::HashMap<InternalID, ToolState>,
+    pads: std::collections::HashMap<InternalID, PadState>,
+}
+impl Tracker {
+    /// Observe a pump's worth of events, after all other processing such that it agrees with what the client sees.
+    pub(crate) fn process(&mut self, events: &[RawEvent<InternalID>]) {
+        for event in events {
+            match event {
+                RawEvent::Tool { tool, event } => {
+                    if let RawTool::Removed = event {
+                        self.tools.remove(tool);
+                        continue;
+                    }
+                    let state = self.tools.entry(tool.clone()).or_default();
+                    match event {
+                        RawTool::In { tablet, .. } => {
+                            state.tablet = Some(tablet::ID(tablet.clone()));
+                        }
+                        RawTool::Out => {
+                            state.tablet = None;
+                            state.down = false;
+                            state.buttons.clear();
+                        }
+                        RawTool::Down => state.down = true,
+                        RawTool::Up => state.down = false,
+                        RawTool::Button { button_id, pressed } => {
+                            let button_id = tool::ButtonID(*button_id);
+                            state.buttons.retain(|held| *held != button_id);
+                            if *pressed {
+                                state.buttons.push(button_id);
+                            }
+                        }
+                        RawTool::Pose(pose) | RawTool::Tracking(pose) => state.pose = Some(*pose),
+                        _ => (),
+                    }
+                }
+                RawEvent::Pad { pad, event } => {
+                    if let RawPad::Removed = event {
+                        self.pads.remove(pad);
+                        continue;
+                    }
+                    let state = self.pads.entry(pad.clone()).or_default();
+                    match event {
+                        RawPad::Group { group, event } => match event {
+                            RawGroup::Mode(mode) => {
+                                state.modes.insert(pad::group::ID(group.clone()), *mode);
+                            }
+                            RawGroup::Ring {
+                                ring,
+                                event: TouchStripEvent::Pose(pose),
+                            } => {
+                                state.rings.insert(pad::ring::ID(ring.clone()), *pose);
+                            }
+                            RawGroup::Strip {
+                                strip,
+                                event: TouchStripEvent::Pose(pose),
+                            } => {
+                                state.strips.insert(pad::strip::ID(strip.clone()), *pose);
+                            }
+                            _ => (),
+                        },
+                        RawPad::Button {
+                            button_idx,
+                            pressed,
+                        } => {
+                            state.buttons.retain(|held| held != button_idx);
+                            if *pressed {
+                                state.buttons.push(*button_idx);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! A machine-readable version of the "Platform support" notes scattered throughout these docs, for generating
+//! "what works on your system" panels and the like.
+//!
+//! ```
+//! use octotablet::support::{Feature, Support};
+//! # let backend = octotablet::Backend::WaylandTabletUnstableV2;
+//! for &feature in Feature::ALL {
+//!     match backend.support(feature) {
+//!         Support::Yes => println!("{feature:?}: yes"),
+//!         Support::Partial(note) => println!("{feature:?}: sort of, {note}"),
+//!         Support::No => println!("{feature:?}: no"),
+//!         _ => println!("{feature:?}: it depends"),
+//!     }
+//! }
+//! ```
+//!
+//! Being plain data, it's easy to check against too:
+//!
+//! ```
+//! use octotablet::{axis::Axis, support::{Feature, Support}, Backend};
+//! assert_eq!(Backend::WindowsInkRealTimeStylus.support(Feature::Pads), Support::No);
+//! assert_eq!(Backend::WaylandTabletUnstableV2.support(Feature::Axis(Axis::Wheel)), Support::Yes);
+//! assert_eq!(Backend::WaylandTabletUnstableV2.support(Feature::ScaleFactorChanges), Support::No);
+//! assert_eq!(Backend::Custom.support(Feature::Tools), Support::BackendDefined);
+//! assert!(Feature::ALL.iter().all(|&feature| !Backend::Null.support(feature).is_available()));
+//! ```
+//!
+//! Crate-side features like [pressure curves](crate::Builder::pressure_curve) work the same everywhere and aren't
+//! listed. Note that this describes what a backend is capable of at best - the hardware in use, compositor, or drivers
+//! may well fall short of it!
+
+use crate::{axis::Axis, Backend};
+
+/// How well a [`Feature`] is supported by a [`Backend`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Support {
+    /// Works as documented.
+    Yes,
+    /// Works, with the given caveat.
+    Partial(&'static str),
+    /// Never available on this backend.
+    No,
+    /// Up to the custom backend in question.
+    BackendDefined,
+}
+impl Support {
+    /// Whether the feature is available at all, giving custom backends the benefit of the doubt.
+    #[must_use]
+    pub fn is_available(self) -> bool {
+        !matches!(self, Self::No)
+    }
+}
+
+/// Something that may or may not work on a given [`Backend`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Feature {
+    /// [`Manager::tablets`](crate::Manager::tablets).
+    Tablets,
+    /// [`Manager::tools`](crate::Manager::tools).
+    Tools,
+    /// [`Manager::pads`](crate::Manager::pads), and with them rings, strips, and modes.
+    Pads,
+    /// [`Pad::tablet`](crate::pad::Pad::tablet).
+    PadTablet,
+    /// [`Group::dials`](crate::pad::Group::dials).
+    PadDials,
+    /// [`Group::mode_indicators`](crate::pad::Group::mode_indicators).
+    PadModeIndicators,
+    /// [`Manager::set_button_feedback`](crate::Manager::set_button_feedback) and friends.
+    PadFeedback,
+    /// The given axis, as advertised by [`Tool::axes`](crate::tool::Tool::axes) and reported by [`Pose`](crate::axis::Pose).
+    Axis(Axis),
+    /// [`Tool::hardware_id`](crate::tool::Tool::hardware_id).
+    ToolHardwareId,
+    /// [`Tool::wacom_id`](crate::tool::Tool::wacom_id), and with it [`Tool::tip_style`](crate::tool::Tool::tip_style).
+    WacomId,
+    /// [`Manager::paired_tool`](crate::Manager::paired_tool).
+    ToolPairing,
+    /// [`Tool::reports_out_of_proximity`](crate::tool::Tool::reports_out_of_proximity).
+    OutOfProximityTracking,
+    /// [`Tool::buttons`](crate::tool::Tool::buttons).
+    ToolButtonList,
+    /// [`Tool::button_count`](crate::tool::Tool::button_count).
+    ToolButtonCount,
+    /// [`Tool::button_role`](crate::tool::Tool::button_role).
+    ToolButtonRoles,
+    /// Hardware [frame timestamps](crate::events::FrameTimestamp).
+    Timestamps,
+    /// [`Manager::cursor_position`](crate::Manager::cursor_position).
+    CursorPosition,
+    /// [`Manager::hide_cursor_while_down`](crate::Manager::hide_cursor_while_down).
+    HideCursorWhileDown,
+    /// [`Manager::protocol_versions`](crate::Manager::protocol_versions).
+    ProtocolVersions,
+    /// [`Manager::accessibility`](crate::Manager::accessibility).
+    Accessibility,
+    /// [`Builder::debug_history`](crate::Builder::debug_history).
+    DebugHistory,
+    /// [`Builder::emulate_tool_from_mouse`](crate::Builder::emulate_tool_from_mouse).
+    MouseEmulation,
+    /// [`Event::WindowFocus`](crate::events::Event::WindowFocus).
+    WindowFocus,
+    /// [`Event::EnumerationComplete`](crate::events::Event::EnumerationComplete).
+    EnumerationComplete,
+    /// [`Builder::capture_touch`](crate::Builder::capture_touch).
+    Touch,
+    /// [`Builder::report_raw_axes`](crate::Builder::report_raw_axes).
+    RawAxes,
+    /// [`SystemEvent::ScaleFactorChanged`](crate::events::SystemEvent::ScaleFactorChanged).
+    ScaleFactorChanges,
+}
+impl Feature {
+    /// Every feature, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::Tablets,
+        Self::Tools,
+        Self::Pads,
+        Self::PadTablet,
+        Self::PadDials,
+        Self::PadModeIndicators,
+        Self::PadFeedback,
+        Self::Axis(Axis::Pressure),
+        Self::Axis(Axis::Tilt),
+        Self::Axis(Axis::Distance),
+        Self::Axis(Axis::Roll),
+        Self::Axis(Axis::Wheel),
+        Self::Axis(Axis::Slider),
+        Self::Axis(Axis::ButtonPressure),
+        Self::Axis(Axis::ContactSize),
+        Self::ToolHardwareId,
+        Self::WacomId,
+        Self::ToolPairing,
+        Self::OutOfProximityTracking,
+        Self::ToolButtonList,
+        Self::ToolButtonCount,
+        Self::ToolButtonRoles,
+        Self::Timestamps,
+        Self::CursorPosition,
+        Self::HideCursorWhileDown,
+        Self::ProtocolVersions,
+        Self::Accessibility,
+        Self::DebugHistory,
+        Self::MouseEmulation,
+        Self::WindowFocus,
+        Self::EnumerationComplete,
+        Self::Touch,
+        Self::RawAxes,
+        Self::ScaleFactorChanges,
+    ];
+}
+
+impl Backend {
+    /// Query how well this backend supports the given feature. See the [`support`](crate::support) module.
+    #[must_use]
+    pub fn support(self, feature: Feature) -> Support {
+        match self {
+            Backend::WaylandTabletUnstableV2 => wayland(feature),
+            Backend::WindowsInkRealTimeStylus => ink(feature),
+            Backend::Custom => custom(feature),
+            Backend::Null => Support::No,
+        }
+    }
+}
+
+// Grouped by topic rather than by answer, for ease of keeping up to date.
+#[allow(clippy::match_same_arms)]
+fn wayland(feature: Feature) -> Support {
+    use Support::{No, Partial, Yes};
+    match feature {
+        Feature::Tablets | Feature::Tools | Feature::Pads | Feature::PadTablet => Yes,
+        Feature::PadFeedback => Partial("shown at the compositor's discretion"),
+        Feature::PadDials => Partial("only from tablet protocol v2"),
+        Feature::PadModeIndicators => No,
+        Feature::Axis(axis) => match axis {
+            Axis::Pressure
+            | Axis::Tilt
+            | Axis::Distance
+            | Axis::Roll
+            | Axis::Wheel
+            | Axis::Slider => Yes,
+            Axis::ButtonPressure | Axis::ContactSize => No,
+        },
+        Feature::ToolHardwareId | Feature::WacomId | Feature::ToolPairing => {
+            Partial("only reported for some hardware")
+        }
+        Feature::ToolButtonList | Feature::ToolButtonCount | Feature::OutOfProximityTracking => No,
+        Feature::ToolButtonRoles => Partial("guessed from evdev codes"),
+        Feature::Timestamps => Partial("only millisecond precision"),
+        Feature::CursorPosition | Feature::MouseEmulation => No,
+        Feature::HideCursorWhileDown => {
+            Partial("stays hidden until the tool leaves proximity or a cursor is set")
+        }
+        Feature::ProtocolVersions | Feature::DebugHistory | Feature::EnumerationComplete => Yes,
+        Feature::WindowFocus => Yes,
+        Feature::Accessibility => Partial("cursor size only"),
+        Feature::Touch => Partial("contact size only from seat v6"),
+        Feature::RawAxes => Partial("as sent by the compositor, not the hardware"),
+        // Positions don't depend on scale, so there's never a change to speak of.
+        Feature::ScaleFactorChanges => No,
+    }
+}
+
+// Grouped by topic rather than by answer, for ease of keeping up to date.
+#[allow(clippy::match_same_arms)]
+fn ink(feature: Feature) -> Support {
+    use Support::{No, Partial, Yes};
+    match feature {
+        Feature::Tablets | Feature::Tools => Yes,
+        Feature::Pads
+        | Feature::PadTablet
+        | Feature::PadDials
+        | Feature::PadModeIndicators
+        | Feature::PadFeedback
+        | Feature::WacomId
+        | Feature::ToolPairing
+        | Feature::OutOfProximityTracking
+        | Feature::ProtocolVersions => No,
+        Feature::Axis(axis) => match axis {
+            Axis::Pressure
+            | Axis::Tilt
+            | Axis::Distance
+            | Axis::Roll
+            | Axis::ButtonPressure
+            | Axis::ContactSize => Yes,
+            Axis::Wheel | Axis::Slider => No,
+        },
+        Feature::ToolHardwareId => Partial("unique per cursor, not necessarily per physical tool"),
+        Feature::ToolButtonList => Partial("the tip and eraser are listed as buttons too"),
+        Feature::ToolButtonCount => {
+            Partial("the tip and eraser are only left out when named as such")
+        }
+        Feature::ToolButtonRoles => Partial("guessed from button names"),
+        Feature::Timestamps => Partial("only if the tablet reports packet timers"),
+        Feature::CursorPosition
+        | Feature::DebugHistory
+        | Feature::MouseEmulation
+        | Feature::EnumerationComplete => Yes,
+        Feature::HideCursorWhileDown => Partial("only on the thread owning the window"),
+        Feature::WindowFocus => Partial("sampled once per pump"),
+        Feature::Accessibility => Yes,
+        Feature::Touch => Partial("only from touch digitizers known to ink"),
+        Feature::RawAxes => Yes,
+        Feature::ScaleFactorChanges => Partial("noticed upon the next pump"),
+    }
+}
+
+fn custom(feature: Feature) -> Support {
+    match feature {
+        Feature::ProtocolVersions | Feature::DebugHistory | Feature::MouseEmulation => Support::No,
+        _ => Support::BackendDefined,
+    }
+}
@@ -17,10 +17,83 @@ pub struct UsbId {
     pub pid: u16,
 }
 
+/// A frequency, in hertz.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hz(pub f32);
+
+/// How far a tablet is physically turned from its natural orientation, clockwise - such as [`Rotation::Half`] for
+/// tablets set up for left-handed use. See [`Tablet::rotation`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum Rotation {
+    #[default]
+    None,
+    Quarter,
+    Half,
+    ThreeQuarters,
+}
+impl Rotation {
+    /// The rotation in radians, clockwise.
+    #[must_use]
+    pub fn radians(self) -> f32 {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        match self {
+            Self::None => 0.0,
+            Self::Quarter => FRAC_PI_2,
+            Self::Half => PI,
+            Self::ThreeQuarters => PI + FRAC_PI_2,
+        }
+    }
+    /// Turn a [ring angle](crate::events::TouchStripEvent::Pose), clockwise from the tablet's own north, into one
+    /// clockwise from the north of the user's view of it - where an on-screen visualization should put it to match
+    /// the finger. The result is within `[0, TAU)`.
+    #[must_use]
+    pub fn ring_angle_on_screen(self, angle: f32) -> f32 {
+        (angle + self.radians()).rem_euclid(std::f32::consts::TAU)
+    }
+}
+
 /// See [module level docs](`crate::tablet`) for details.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Tablet {
     pub(crate) internal_id: crate::InternalID,
     pub name: Option<String>,
+    /// The USB vendor and product ID, if known. Handy for per-device quirks.
+    ///
+    /// # Platform support
+    /// * Wayland - where the compositor reports it.
+    /// * Windows Ink - parsed from the device's plug and play ID, for USB and bluetooth HID devices.
     pub usb_id: Option<UsbId>,
+    /// The native rate at which the hardware reports samples, if known. Useful for sizing prediction
+    /// or smoothing windows. Note that events may still arrive at a different rate, as the OS is free to
+    /// coalesce or drop reports.
+    ///
+    /// # Platform support
+    /// * Always `None` on built-in backends, as no platform's tablet protocol reports it.
+    /// * Custom backends - whatever the backend fills in.
+    pub report_rate: Option<Hz>,
+    /// How the tablet is physically turned, as configured in the driver, if known. Tool positions already account
+    /// for it, but [ring angles](crate::pad::ring::Ring) are relative to the hardware - see
+    /// [`Rotation::ring_angle_on_screen`].
+    ///
+    /// # Platform support
+    /// * Always `None` on built-in backends. On Wayland the compositor applies the rotation without saying so, and
+    ///   on Windows Ink the driver's configuration isn't exposed.
+    /// * Custom backends - whatever the backend fills in.
+    pub rotation: Option<Rotation>,
+    /// Width and height of the active area, in millimeters, if known. Handy for mapping the tablet to the screen at
+    /// a true 1:1 scale.
+    ///
+    /// # Platform support
+    /// * Wayland - only with the `wayland-udev` feature, looked up from udev's database by the device path the
+    ///   compositor reports. Not all compositors report one, and not all devices have udev sizes.
+    /// * Windows Ink - where the digitizer reports its X and Y ranges in a physical unit.
+    pub physical_size: Option<[f32; 2]>,
+    /// X and Y resolution of the active area in dots per millimeter, if known.
+    ///
+    /// # Platform support
+    /// * Wayland - never.
+    /// * Windows Ink - where the digitizer reports its X and Y ranges in a physical unit. Note that positions are
+    ///   still reported in HIMETRIC, so this can be finer than what actually makes it through.
+    pub resolution: Option<[f32; 2]>,
 }
@@ -63,8 +63,38 @@ pub enum Type {
     Emulated,
 }
 
+/// The physical style of a tool's tip, see [`Tool::tip_style`].
+#[derive(Clone, Copy, Debug, strum::AsRefStr, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TipStyle {
+    /// An ordinary plastic nib.
+    Standard,
+    /// An actual ink cartridge, for drawing on paper laid atop the tablet.
+    Ballpoint,
+    /// A flat, angled tip like that of a highlighter, typically alongside [roll](crate::axis::Pose::roll) sensing.
+    Chisel,
+    /// A spring-loaded nib, giving more travel than usual for a brush-like feel.
+    Stroke,
+}
+impl TipStyle {
+    /// Look up a Wacom tool ID. These are only the ones we know of - there are many more out there!
+    fn from_wacom_id(id: u64) -> Option<Self> {
+        // From the tool IDs known to the Linux `wacom` driver.
+        Some(match id {
+            0x802 | 0x822 | 0x842 | 0x852 | 0x823 | 0x813 | 0x8e2 | 0x10_0802 | 0x16_0802
+            | 0x18_0802 | 0x20_0802 | 0x8842 | 0x1_0842 => Self::Standard,
+            0x812 | 0x801 | 0x012 | 0x1_2802 => Self::Ballpoint,
+            // Intuos3 Art Marker, and the Art Pens after it.
+            0x885 | 0x804 | 0x1_0804 | 0x10_0804 => Self::Chisel,
+            0x832 | 0x032 => Self::Stroke,
+            _ => return None,
+        })
+    }
+}
+
 /// Description of the capabilities of a tool.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Tool {
     /// Platform internal ID.
     pub(crate) internal_id: crate::InternalID,
@@ -83,8 +113,178 @@ pub struct Tool {
     pub wacom_id: Option<u64>,
     /// Type of the tool, if known.
     pub tool_type: Option<Type>,
-    /// The capabilities of the axes reported by this device.
+    /// The capabilities of the axes reported by this device. The tip and eraser ends of a pen are separate tools,
+    /// each with their own axes.
+    ///
+    /// # Platform support
+    /// * Wayland - as advertised by the compositor for each end.
+    /// * Windows Ink - the axes of the tablets the tool has visited, as Ink has no notion of per-tool axes. Erasers
+    ///   only gain tilt and roll once they're seen to report them.
     pub axes: axis::FullInfo,
+    /// Buttons the platform reports to be on this tool, in the platform's order, which is suitable for presenting
+    /// button-mapping UIs. [`Button`](crate::events::ToolEvent::Button) events refer to these by [`ButtonInfo::id`],
+    /// though buttons missing from this list may still be reported.
+    ///
+    /// # Platform support
+    /// * Windows Ink - The tip and eraser are considered buttons too, and are listed as such.
+    pub buttons: Vec<ButtonInfo>,
+    /// How many buttons the tool has, not counting the tip or eraser ends, if known. Enough to size a button-mapping
+    /// UI before the user has pressed each one - see [`Tool::buttons`] for which they are, where that's known too.
+    ///
+    /// # Platform support
+    /// * Wayland - never, buttons are only learned of as they're pressed.
+    /// * Windows Ink - counted from [`Tool::buttons`], leaving out those recognized as the tip or eraser. Buttons
+    ///   with names we don't recognize are counted, so this may be one or two too high.
+    pub button_count: Option<u8>,
+    /// Whether the tool keeps reporting its position while [out](crate::events::ToolEvent::Out), as some pucks and
+    /// mice in "mouse mode" do. These are sent as [`Tracking`](crate::events::ToolEvent::Tracking) rather than
+    /// [`Pose`](crate::events::ToolEvent::Pose), so they can't be mistaken for hovering.
+    ///
+    /// # Platform support
+    /// * Wayland and Windows Ink - never, the platform reports nothing outside of proximity.
+    pub reports_out_of_proximity: bool,
+    /// The pressure at which the driver considers the tool [down](crate::events::ToolEvent::Down), in `[0, 1]`,
+    /// such as the "tip feel" configured in tablet settings. See [`Builder::normalize_pressure_to_tip`](crate::Builder::normalize_pressure_to_tip).
+    ///
+    /// # Platform support
+    /// * Wayland and Windows Ink - always `None`, the driver's configuration isn't exposed. The threshold is learned
+    ///   from use instead.
+    /// * Custom backends - whatever the backend fills in.
+    pub tip_threshold: Option<f32>,
+}
+
+/// Description of a button on a tool, see [`Tool::buttons`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ButtonInfo {
+    pub id: ButtonID,
+    /// Human-readable name of the button, such as "Barrel Switch", if known.
+    pub name: Option<String>,
+    /// What the button is for, as best as can be told. See also [`Tool::button_role`].
+    pub role: ButtonRole,
+}
+
+/// The purpose of a tool button, for treating e.g. "the lower barrel button" the same across platforms.
+/// See [`Tool::button_role`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ButtonRole {
+    /// The tip, or the left button of mouse-like tools.
+    Primary,
+    /// The barrel button nearest the tip.
+    Barrel1,
+    /// The barrel button second nearest the tip.
+    Barrel2,
+    /// The eraser end, on platforms that consider it a button.
+    Eraser,
+    /// Couldn't be told, here's the ID instead.
+    Unknown(ButtonID),
 }
 
 crate::util::macro_bits::impl_get_id!(ID for Tool);
+impl Tool {
+    /// The style of the tool's tip, for e.g. defaulting to a brush shape that matches the physical nib.
+    /// Derived from the [`wacom_id`](Self::wacom_id), `None` for tools without one or for those we don't recognize.
+    ///
+    /// Swappable nibs (felt, stroke, ...) on a single pen are indistinguishable to the hardware, so this describes the
+    /// pen more than the nib currently fitted to it.
+    ///
+    /// # Platform support
+    /// * Wayland, and custom backends reporting a `wacom_id`.
+    #[must_use]
+    pub fn tip_style(&self) -> Option<TipStyle> {
+        self.wacom_id.and_then(TipStyle::from_wacom_id)
+    }
+    /// Read the [distance](axis::Pose::distance) of a pose reported by this tool in the given unit, regardless of
+    /// whether the tool reports it normalized or in physical units. `Ok(None)` if the pose has no distance.
+    ///
+    /// Any [`Builder::distance_curve`](crate::Builder::distance_curve) has already been applied, use
+    /// [`axis::Pose::raw_distance`] with [`axis::LengthInfo::convert`] for the hardware's own value.
+    ///
+    /// # Errors
+    /// If the tool doesn't advertise distance, or the conversion isn't possible - for instance, millimeters from a
+    /// normalized distance, which has no known relation to physical units.
+    ///
+    /// # Platform support
+    /// * Wayland - always normalized, so millimeters are never available.
+    /// * Windows Ink - physical where the driver reports a unit, normalized otherwise.
+    pub fn distance_in(
+        &self,
+        pose: &axis::Pose,
+        unit: axis::LengthUnit,
+    ) -> Result<Option<f32>, axis::LengthConversionError> {
+        let info = self.axes.distance.ok_or(axis::UnsupportedAxisError)?;
+        pose.distance
+            .get()
+            .map(|distance| info.convert(distance, unit))
+            .transpose()
+    }
+    /// The role of the given button on this tool, as in [`ToolEvent::Button`](crate::events::ToolEvent::Button).
+    /// This is a best-effort guess, [`ButtonRole::Unknown`] when no guess could be made.
+    ///
+    /// # Platform support
+    /// * Wayland - From the button's evdev code, whether or not it's listed in [`Tool::buttons`].
+    /// * Windows Ink - From the names in [`Tool::buttons`], which in practice are those of the HID usages.
+    /// * Custom backends - As given in [`Tool::buttons`].
+    #[must_use]
+    pub fn button_role(&self, id: ButtonID) -> ButtonRole {
+        self.buttons
+            .iter()
+            .find(|button| button.id == id)
+            .map(|button| button.role)
+            .filter(|role| !matches!(role, ButtonRole::Unknown(_)))
+            .or_else(|| id.0.role())
+            .unwrap_or(ButtonRole::Unknown(id))
+    }
+}
+
+/// A **heuristic**, low-confidence identifier of a tool, built up from how its hardware behaves in use.
+/// See [`Manager::tool_fingerprint`](crate::Manager::tool_fingerprint).
+///
+/// Intended for "best effort" memory of anonymous tools lacking a [`HardwareID`] - for example, to suggest restoring
+/// the presets last used with what is *probably* the same pen. Several pens of the same model will look alike, and
+/// the same pen will look different as the user's habits shift. The fields are public so that fingerprints may be
+/// persisted and compared against later, but their exact interpretation is not guaranteed to be stable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fingerprint {
+    /// Fraction of down samples falling into each successive eighth of the pressure range, summing to one.
+    pub pressure_shape: [f32; 8],
+    /// Mean change in pressure between consecutive down samples.
+    pub pressure_jitter: f32,
+    /// Mean of the tiny movements between consecutive hover samples in logical pixels, largely sensor noise.
+    pub position_jitter: f32,
+    /// How many down samples this was built from.
+    pub samples: u32,
+}
+impl Fingerprint {
+    /// How much weight to give this fingerprint, in `[0, 1)`, growing as more samples are observed.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn confidence(&self) -> f32 {
+        1.0 - (-(self.samples as f32) / 4096.0).exp()
+    }
+    /// How alike two fingerprints are, from `0` (nothing in common) to `1` (indistinguishable).
+    /// Factor in the [`confidence`](Self::confidence) of both before drawing conclusions!
+    #[must_use]
+    pub fn similarity(&self, other: &Self) -> f32 {
+        // Both sum to 1, so the distance between them is in [0, 2].
+        let shape_distance: f32 = self
+            .pressure_shape
+            .iter()
+            .zip(&other.pressure_shape)
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+        let shape = 1.0 - (shape_distance / 2.0).clamp(0.0, 1.0);
+        let ratio = |a: f32, b: f32| {
+            if a.max(b) <= 0.0 {
+                1.0
+            } else {
+                a.min(b) / a.max(b)
+            }
+        };
+        // The shape is the most telling, jitter mostly serves to break ties.
+        shape * 0.6
+            + ratio(self.pressure_jitter, other.pressure_jitter) * 0.2
+            + ratio(self.position_jitter, other.position_jitter) * 0.2
+    }
+}
@@ -0,0 +1,50 @@
+//! # Touch
+//!
+//! Fingers on touch-sensitive tablets (and, depending on the platform, touchscreens), reported only if enabled with
+//! [`Builder::capture_touch`](crate::Builder::capture_touch). Handy for panning and zooming with one hand while
+//! drawing with the other.
+//!
+//! Unlike [tools](crate::tool), fingers aren't devices and aren't listed anywhere - each is known only by a
+//! [`ContactID`] from the moment it touches [`Down`](TouchEvent::Down) until it lifts [`Up`](TouchEvent::Up).
+
+use crate::events::FrameTimestamp;
+
+/// Identifies a finger for the duration of a single contact. Once it lifts, the ID may be reused by a later contact.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ContactID(pub(crate) u64);
+
+/// The state of a finger in contact with the surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Finger {
+    pub contact: ContactID,
+    /// X Y position, in the same space as [`Pose::position`](crate::axis::Pose::position).
+    pub position: [f32; 2],
+    /// The size of the contact ellipse, like [`Pose::contact_size`](crate::axis::Pose::contact_size).
+    ///
+    /// # Platform support
+    /// * Wayland - the major and minor axes of the ellipse, regardless of its orientation. Compositors only
+    ///   sometimes report this.
+    /// * Windows Ink - where reported by the digitizer.
+    pub contact_size: Option<[f32; 2]>,
+    /// The window the finger touched down on, if known. See [`Manager::add_window`](crate::Manager::add_window).
+    pub window: Option<crate::WindowId>,
+}
+
+/// Events for fingers on touch surfaces. Like tool events, these come in frames of simultaneous changes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TouchEvent {
+    /// A new finger touched down.
+    Down(Finger),
+    /// A finger already down moved, or changed shape.
+    Move(Finger),
+    /// The finger with the given contact lifted.
+    Up(ContactID),
+    /// Every finger is lifted, and their contacts are no longer to be trusted - such as when the system takes the
+    /// touches over for a gesture of its own. Undo their effects if possible.
+    Cancel,
+    /// End of a frame. See [`ToolEvent::Frame`](crate::events::ToolEvent::Frame), this timestamp is not necessarily
+    /// coordinated with those of tools.
+    Frame(Option<FrameTimestamp>),
+}
@@ -88,6 +88,112 @@ impl PartialEq<NicheF32> for f32 {
     }
 }
 
+/// Fixed-size history of human-readable descriptions of raw platform messages,
+/// for [`crate::Manager::debug_dump`].
+pub(crate) struct RingLog {
+    capacity: usize,
+    messages: std::collections::VecDeque<String>,
+}
+impl RingLog {
+    /// Create a log of the given capacity, `None` if zero.
+    pub(crate) fn new(capacity: usize) -> Option<Self> {
+        (capacity != 0).then(|| Self {
+            capacity,
+            messages: std::collections::VecDeque::with_capacity(capacity),
+        })
+    }
+    pub(crate) fn push(&mut self, message: String) {
+        if self.messages.len() == self.capacity {
+            let _ = self.messages.pop_front();
+        }
+        self.messages.push_back(message);
+    }
+    /// Oldest first, one message per line.
+    pub(crate) fn dump(&self) -> String {
+        let mut dump = String::new();
+        for message in &self.messages {
+            dump.push_str(message);
+            dump.push('\n');
+        }
+        dump
+    }
+}
+
+/// The thread a [`Manager`](crate::Manager) was built on. Makes the manager `!Send` regardless of backend, and in debug
+/// builds also checks that it stays there, in case someone has been creative with `unsafe impl Send`.
+/// Some backends hold thread-local resources (COM apartments, cursor display counters, ...) that'd be silently
+/// misused otherwise.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ThreadAffinity {
+    #[cfg(debug_assertions)]
+    owner: std::thread::ThreadId,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+impl ThreadAffinity {
+    /// Bind to the calling thread.
+    pub(crate) fn current() -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            owner: std::thread::current().id(),
+            _not_send: std::marker::PhantomData,
+        }
+    }
+    /// Panic if called from any thread other than the bound one. Does nothing in release builds.
+    #[track_caller]
+    pub(crate) fn check(self, what: &str) {
+        #[cfg(debug_assertions)]
+        {
+            let current = std::thread::current();
+            assert!(
+                current.id() == self.owner,
+                "octotablet: `Manager::{what}` called from thread {:?} ({:?}), but the manager was built on thread {:?}. \
+                 The manager must stay on the thread that built it - move the `Builder` instead.",
+                current.name().unwrap_or("<unnamed>"),
+                current.id(),
+                self.owner,
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = what;
+    }
+}
+
+/// A value borrowing from resources its `backing` keeps alive. The value is always dropped first, no matter where
+/// this sits among the fields of its owner - so the order can't be broken by innocently adding a field after it.
+pub(crate) struct Backed<T, B> {
+    value: std::mem::ManuallyDrop<T>,
+    backing: std::mem::ManuallyDrop<B>,
+}
+impl<T, B> Backed<T, B> {
+    pub(crate) fn new(value: T, backing: B) -> Self {
+        Self {
+            value: std::mem::ManuallyDrop::new(value),
+            backing: std::mem::ManuallyDrop::new(backing),
+        }
+    }
+}
+impl<T, B> std::ops::Deref for Backed<T, B> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+impl<T, B> std::ops::DerefMut for Backed<T, B> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+impl<T, B> Drop for Backed<T, B> {
+    fn drop(&mut self) {
+        // Safety: Neither is touched again after this, and the value goes before what it borrows from.
+        // Should the value's drop panic, the backing is leaked rather than freed out from under anything.
+        unsafe {
+            std::mem::ManuallyDrop::drop(&mut self.value);
+            std::mem::ManuallyDrop::drop(&mut self.backing);
+        }
+    }
+}
+
 pub(crate) mod macro_bits {
     /// Implements an public opaque ID,
     /// assuming the struct has a `internal_id` which implements `Into<platform::InternalID>`
@@ -98,7 +204,7 @@ pub(crate) mod macro_bits {
             #[derive(Clone, Hash, PartialEq, Eq)]
             #[allow(clippy::module_name_repetitions)]
             #[repr(transparent)]
-            pub struct $id_name(crate::platform::InternalID);
+            pub struct $id_name(pub(crate) crate::platform::InternalID);
 
             impl ::std::fmt::Debug for $id_name {
                 fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
@@ -119,3 +225,28 @@ pub(crate) mod macro_bits {
     // Weird hacks to allow use from submodules..
     pub(crate) use impl_get_id;
 }
+
+#[cfg(test)]
+mod test {
+    use super::Backed;
+    use std::sync::{Arc, Mutex};
+
+    /// Notes down its name when dropped.
+    struct Canary(&'static str, Arc<Mutex<Vec<&'static str>>>);
+    impl Drop for Canary {
+        fn drop(&mut self) {
+            self.1.lock().unwrap().push(self.0);
+        }
+    }
+
+    #[test]
+    fn backed_drops_value_first() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let backed = Backed::new(Canary("value", log.clone()), Canary("backing", log.clone()));
+        assert_eq!(Arc::strong_count(&log), 3);
+        drop(backed);
+        // Both gone, and neither leaked.
+        assert_eq!(Arc::strong_count(&log), 1);
+        assert_eq!(*log.lock().unwrap(), ["value", "backing"]);
+    }
+}
@@ -0,0 +1,85 @@
+//! Opt-in termination of strokes whose `Up` went missing, applied by the [`Manager`](crate::Manager) as the platform
+//! is pumped. See [`Builder::stuck_down_timeout`](crate::builder::Builder::stuck_down_timeout).
+
+use crate::{
+    events::raw::{Event as RawEvent, ToolEvent as RawTool},
+    platform::InternalID,
+};
+
+pub(crate) struct Watchdog {
+    timeout: std::time::Duration,
+    /// Tools currently down, and when they were last heard from.
+    down: std::collections::HashMap<InternalID, std::time::Instant>,
+    /// Tools we've given up on and sent out on the platform's behalf. Their events are swallowed until the platform
+    /// sends its own `Out`, like those sent out on focus loss.
+    detached: std::collections::HashSet<InternalID>,
+}
+impl Watchdog {
+    pub(crate) fn new(timeout: std::time::Duration) -> Self {
+        Self {
+            timeout,
+            down: std::collections::HashMap::new(),
+            detached: std::collections::HashSet::new(),
+        }
+    }
+    /// Observe freshly pumped events, appending the ends of any strokes that have gone quiet for too long.
+    pub(crate) fn process(
+        &mut self,
+        events: &mut std::collections::VecDeque<RawEvent<InternalID>>,
+        now: std::time::Instant,
+    ) {
+        events.retain(|event| {
+            let RawEvent::Tool { tool, event } = event else {
+                return true;
+            };
+            if self.detached.contains(tool) {
+                return match event {
+                    RawTool::Out => {
+                        self.detached.remove(tool);
+                        false
+                    }
+                    RawTool::Removed => {
+                        self.detached.remove(tool);
+                        true
+                    }
+                    // Already told the client it's out, keep quiet.
+                    _ => false,
+                };
+            }
+            match event {
+                RawTool::Down => {
+                    self.down.insert(tool.clone(), now);
+                }
+                RawTool::Pose(_) => {
+                    if let Some(last) = self.down.get_mut(tool) {
+                        *last = now;
+                    }
+                }
+                RawTool::Up | RawTool::Out | RawTool::Removed => {
+                    self.down.remove(tool);
+                }
+                _ => (),
+            }
+            true
+        });
+        let timeout = self.timeout;
+        self.down.retain(|tool, last| {
+            if now.duration_since(*last) < timeout {
+                return true;
+            }
+            for event in [
+                RawTool::TimedOut,
+                RawTool::Up,
+                RawTool::Out,
+                RawTool::Frame(None),
+            ] {
+                events.push_back(RawEvent::Tool {
+                    tool: tool.clone(),
+                    event,
+                });
+            }
+            self.detached.insert(tool.clone());
+            false
+        });
+    }
+}
@@ -0,0 +1,39 @@
+//! Conveniences for apps using [`winit`](::winit), requiring the `winit` feature. See also the `winit-paint` example.
+//!
+//! This saves the `raw-window-handle` plumbing and polling dance of the examples, but is otherwise nothing special -
+//! everything here can be done by hand with [`Builder::build_shared`](crate::Builder::build_shared) and [`Manager::pump`].
+
+use crate::{builder::BuildError, events::Event, Manager, PumpError};
+
+impl Manager {
+    /// Connect to the tablet API for the given window, with the default configuration.
+    /// For anything more particular, see [`Builder::build_shared`](crate::Builder::build_shared).
+    ///
+    /// The window is kept alive for as long as the manager is, making this safe.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_winit_window(
+        window: &std::sync::Arc<::winit::window::Window>,
+    ) -> Result<Self, BuildError> {
+        crate::Builder::new().build_shared(window)
+    }
+    /// Pump the platform, translating each event with `map` and sending the result to the event loop as a user event.
+    /// Events for which `map` returns `None` are dropped. Since events borrow from the `Manager`, `map` is responsible for
+    /// copying out whatever is needed - typically the relevant IDs and poses.
+    ///
+    /// Returns `Ok(false)` if the event loop has closed, in which case the rest of this pump's events are dropped.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn forward_to_winit<T: 'static>(
+        &mut self,
+        proxy: &::winit::event_loop::EventLoopProxy<T>,
+        mut map: impl FnMut(Event<'_>) -> Option<T>,
+    ) -> Result<bool, PumpError> {
+        for event in self.pump()? {
+            if let Some(user_event) = map(event) {
+                if proxy.send_event(user_event).is_err() {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}